@@ -1,7 +1,9 @@
-/// On-chain board definition for Blockpoly.
+/// Default board definition for Blockpoly. Games read their board from a
+/// BoardConfig account; this is the layout new BoardConfigs start from.
 /// All BPOLY amounts are in micro-units (6 decimals).
 /// 1 BPOLY = 1_000_000 micro-BPOLY.
 use crate::constants::*;
+use crate::state::BoardSpace;
 
 /// Complete definition of a single board space.
 #[derive(Clone)]
@@ -12,7 +14,7 @@ pub struct SpaceData {
     pub group: u8,
     /// Purchase price (0 if not purchasable)
     pub price: u64,
-    /// Base rent (unimproved, no monopoly)
+    /// Base rent (unimproved, no monopoly); the amount charged on a tax space
     pub base_rent: u64,
     /// Rent with 1-4 LPs
     pub lp_rents: [u64; 4],
@@ -63,7 +65,7 @@ pub const BOARD: [SpaceData; 40] = [
     // 4: Gas Fees Tax
     SpaceData {
         index: 4, name: "Gas Fees Tax", space_type: SPACE_TYPE_TAX, group: GROUP_NONE,
-        price: 0, base_rent: bpoly(200), lp_rents: [0; 4], protocol_rent: 0,
+        price: 0, base_rent: GAS_FEE_TAX, lp_rents: [0; 4], protocol_rent: 0,
         mortgage_value: 0, lp_cost: 0, bridge_rents: [0; 4],
     },
     // 5: Wormhole (Bridge)
@@ -309,7 +311,7 @@ pub const BOARD: [SpaceData; 40] = [
     // 38: Protocol Fee (Luxury Tax)
     SpaceData {
         index: 38, name: "Protocol Fee", space_type: SPACE_TYPE_TAX, group: GROUP_NONE,
-        price: 0, base_rent: bpoly(100), lp_rents: [0; 4], protocol_rent: 0,
+        price: 0, base_rent: PROTOCOL_FEE_TAX, lp_rents: [0; 4], protocol_rent: 0,
         mortgage_value: 0, lp_cost: 0, bridge_rents: [0; 4],
     },
     // 39: Solana (Dark Blue)
@@ -322,60 +324,21 @@ pub const BOARD: [SpaceData; 40] = [
     },
 ];
 
-/// Bridge space indices
-pub const BRIDGE_SPACES: [u8; 4] = [5, 15, 25, 35];
-/// Utility space indices
-pub const UTILITY_SPACES: [u8; 2] = [12, 28];
-
-/// Properties per color group
-pub const GROUP_MEMBERS: [[u8; 3]; 10] = [
-    [1, 3, 255],        // Brown (2 props)
-    [6, 8, 9],          // Light Blue (3 props)
-    [11, 13, 14],       // Pink (3 props)
-    [16, 18, 19],       // Orange (3 props)
-    [21, 23, 24],       // Red (3 props)
-    [26, 27, 29],       // Yellow (3 props)
-    [31, 32, 34],       // Green (3 props)
-    [37, 39, 255],      // Dark Blue (2 props)
-    [5, 15, 25],        // Bridge – only 3 of 4 fit; index 35 handled separately
-    [12, 28, 255],      // Utility (2)
-];
-pub const GROUP_SIZES: [u8; 10] = [2, 3, 3, 3, 3, 3, 3, 2, 4, 2];
-
-/// Return all space indices for a color group
-pub fn group_spaces(group: u8) -> &'static [u8] {
-    match group {
-        GROUP_BROWN      => &[1, 3],
-        GROUP_LIGHT_BLUE => &[6, 8, 9],
-        GROUP_PINK       => &[11, 13, 14],
-        GROUP_ORANGE     => &[16, 18, 19],
-        GROUP_RED        => &[21, 23, 24],
-        GROUP_YELLOW     => &[26, 27, 29],
-        GROUP_GREEN      => &[31, 32, 34],
-        GROUP_DARK_BLUE  => &[37, 39],
-        GROUP_BRIDGE     => &[5, 15, 25, 35],
-        GROUP_UTILITY    => &[12, 28],
-        _ => &[],
-    }
-}
-
-/// Find the nearest bridge space ahead of a given position (wraps around)
-pub fn nearest_bridge_ahead(position: u8) -> u8 {
-    for &bridge in BRIDGE_SPACES.iter() {
-        if bridge > position {
-            return bridge;
-        }
-    }
-    BRIDGE_SPACES[0] // wrap around
-}
-
-/// Find the nearest unowned property ahead (used for Airdrop Season card)
-pub fn nearest_property_ahead(position: u8) -> u8 {
-    for i in 1..40u8 {
-        let space = (position + i) % 40;
-        if BOARD[space as usize].space_type == SPACE_TYPE_PROPERTY {
-            return space;
-        }
-    }
-    position
+/// The standard board as BoardConfig spaces; create_board_config starts from this.
+pub fn default_spaces() -> Vec<BoardSpace> {
+    BOARD
+        .iter()
+        .map(|s| BoardSpace {
+            name: s.name.to_string(),
+            space_type: s.space_type,
+            group: s.group,
+            price: s.price,
+            base_rent: s.base_rent,
+            lp_rents: s.lp_rents,
+            protocol_rent: s.protocol_rent,
+            mortgage_value: s.mortgage_value,
+            lp_cost: s.lp_cost,
+            bridge_rents: s.bridge_rents,
+        })
+        .collect()
 }
@@ -0,0 +1,210 @@
+/// Default Alpha Call and Governance Vote decks. Games read their cards from a
+/// CardDeck account; these are the cards new CardDecks start from.
+/// Card IDs are 0-indexed (0 = card 1 in the plan).
+use crate::constants::*;
+use crate::state::{CardDef, CardEffect, CardModifier, EffectModifier, EffectTarget, MarketEffectKind};
+
+pub struct CardData {
+    pub name: &'static str,
+    pub effect: CardEffect,
+}
+
+/// Scale a BPOLY value from display units to micro-units
+const fn bpoly(n: u64) -> u64 {
+    n * 1_000_000
+}
+
+pub const ALPHA_CALL_CARDS: [CardData; 16] = [
+    // 0: collect 200 BPOLY on the way
+    CardData {
+        name: "Advance to Genesis Block",
+        effect: CardEffect::MoveTo { space: SPACE_GENESIS, modifier: CardModifier::None },
+    },
+    // 1: Solana (space 39), +200 if passing Genesis
+    CardData {
+        name: "Advance to Solana",
+        effect: CardEffect::MoveTo { space: 39, modifier: CardModifier::None },
+    },
+    // 2: pay 2× rent if the bridge is owned
+    CardData {
+        name: "Advance to Nearest Bridge",
+        effect: CardEffect::MoveToNearestInGroup {
+            group: GROUP_BRIDGE,
+            modifier: CardModifier::DoubleBridgeRent,
+        },
+    },
+    // 3: Wormhole (space 5)
+    CardData {
+        name: "Advance to Wormhole",
+        effect: CardEffect::MoveTo { space: 5, modifier: CardModifier::None },
+    },
+    // 4
+    CardData {
+        name: "Staking Rewards",
+        effect: CardEffect::CollectFromBank(bpoly(50)),
+    },
+    // 5
+    CardData {
+        name: "Get Out of Rug Pull Free",
+        effect: CardEffect::JailFreeCard,
+    },
+    // 6: the roll that landed here × 4 BPOLY
+    CardData {
+        name: "MEV Bot Attack",
+        effect: CardEffect::PayPerDicePoint(bpoly(4)),
+    },
+    // 7: lose 20% of balance
+    CardData {
+        name: "Market Crash",
+        effect: CardEffect::PayBalanceBps(2_000),
+    },
+    // 8: unimproved rent doubled through the next full round
+    CardData {
+        name: "Bull Run",
+        effect: CardEffect::AddGlobalEffect {
+            kind: MarketEffectKind::BullRun,
+            target: EffectTarget::UnimprovedRent,
+            modifier: EffectModifier::MultiplyBps(20_000),
+            rounds: 2,
+        },
+    },
+    // 9: steal the last rent payment
+    CardData {
+        name: "51% Attack",
+        effect: CardEffect::CollectLastRent,
+    },
+    // 10
+    CardData {
+        name: "Flash Loan",
+        effect: CardEffect::FlashLoan,
+    },
+    // 11: cheapest unimproved, unmortgaged property back to the bank
+    CardData {
+        name: "Protocol Hack",
+        effect: CardEffect::LoseCheapestProperty,
+    },
+    // 12
+    CardData {
+        name: "Go Back 3 Spaces",
+        effect: CardEffect::MoveBack(3),
+    },
+    // 13: straight to the Rug Pull Zone
+    CardData {
+        name: "SEC Investigation",
+        effect: CardEffect::GoToJail,
+    },
+    // 14: most expensive property sold to the bank at 50%
+    CardData {
+        name: "Whale Dump",
+        effect: CardEffect::SellMostExpensiveProperty { bps: 5_000 },
+    },
+    // 15: nearest bank-owned property, bought at 50%
+    CardData {
+        name: "Airdrop Season",
+        effect: CardEffect::MoveToNearestUnowned {
+            space_type: SPACE_TYPE_PROPERTY,
+            modifier: CardModifier::HalfPricePurchase,
+        },
+    },
+];
+
+pub const GOVERNANCE_CARDS: [CardData; 16] = [
+    // 0
+    CardData {
+        name: "Protocol Treasury Release",
+        effect: CardEffect::CollectFromBank(bpoly(200)),
+    },
+    // 1
+    CardData {
+        name: "Validator Node Income",
+        effect: CardEffect::CollectFromBank(bpoly(100)),
+    },
+    // 2
+    CardData {
+        name: "DAO Airdrop",
+        effect: CardEffect::CollectFromEachPlayer(bpoly(10)),
+    },
+    // 3
+    CardData {
+        name: "Get Out of Rug Pull Free",
+        effect: CardEffect::JailFreeCard,
+    },
+    // 4
+    CardData {
+        name: "Smart Contract Exploit Found",
+        effect: CardEffect::GoToJail,
+    },
+    // 5
+    CardData {
+        name: "Gas Fee Rebate",
+        effect: CardEffect::CollectFromBank(bpoly(50)),
+    },
+    // 6: 40 per LP + 115 per Full Protocol
+    CardData {
+        name: "Infrastructure Levy",
+        effect: CardEffect::PayPerBuilding { per_lp: bpoly(40), per_protocol: bpoly(115) },
+    },
+    // 7
+    CardData {
+        name: "Protocol Upgrade Vote",
+        effect: CardEffect::PayBank(bpoly(100)),
+    },
+    // 8: 25 per LP
+    CardData {
+        name: "Liquidity Mining Rewards",
+        effect: CardEffect::CollectPerBuilding { per_lp: bpoly(25), per_protocol: 0 },
+    },
+    // 9
+    CardData {
+        name: "Token Unlock Cliff",
+        effect: CardEffect::PayEachPlayer(bpoly(150)),
+    },
+    // 10: lose a bridge, or collect 50 without one
+    CardData {
+        name: "Bridge Exploited",
+        effect: CardEffect::LoseBridgeOrCollect(bpoly(50)),
+    },
+    // 11
+    CardData {
+        name: "DAO Birthday Vote",
+        effect: CardEffect::CollectFromEachPlayer(bpoly(50)),
+    },
+    // 12: DeFi Summer (space 20), +200 if passing Genesis
+    CardData {
+        name: "Yield Farming Season",
+        effect: CardEffect::MoveTo { space: SPACE_DEFI_SUMMER, modifier: CardModifier::None },
+    },
+    // 13
+    CardData {
+        name: "Regulatory Compliance Fine",
+        effect: CardEffect::PayBank(bpoly(50)),
+    },
+    // 14: 20 per complete colour group
+    CardData {
+        name: "NFT Royalty Income",
+        effect: CardEffect::CollectPerCompleteGroup(bpoly(20)),
+    },
+    // 15: leave the Rug Pull Zone free, or collect 75
+    CardData {
+        name: "Rug Pull Insurance",
+        effect: CardEffect::LeaveJailOrCollect(bpoly(75)),
+    },
+];
+
+fn to_defs(cards: &[CardData]) -> Vec<CardDef> {
+    cards
+        .iter()
+        .map(|c| CardDef {
+            name: c.name.to_string(),
+            effect: c.effect,
+        })
+        .collect()
+}
+
+pub fn default_alpha_call_cards() -> Vec<CardDef> {
+    to_defs(&ALPHA_CALL_CARDS)
+}
+
+pub fn default_governance_cards() -> Vec<CardDef> {
+    to_defs(&GOVERNANCE_CARDS)
+}
@@ -4,10 +4,20 @@ pub const SEED_PLAYER_STATE: &[u8] = b"player_state";
 pub const SEED_PROPERTY_STATE: &[u8] = b"property_state";
 pub const SEED_TRADE_OFFER: &[u8] = b"trade_offer";
 pub const SEED_BANK_VAULT: &[u8] = b"bank_vault";
+/// Blockpoly PDA standing for "no keeper": anyone may settle the game's rolls
+pub const SEED_RANDOMNESS_AUTHORITY: &[u8] = b"randomness_authority";
+pub const SEED_DICE_COMMITMENT: &[u8] = b"dice_commitment";
+pub const SEED_BOARD_CONFIG: &[u8] = b"board_config";
+pub const SEED_CARD_DECK: &[u8] = b"card_deck";
 
-/// Game parameters
+/// Game parameters. Balances, fees and durations below are RuleSet defaults;
+/// handlers read the game's RuleSet, not these constants.
 pub const MAX_PLAYERS: u8 = 8;
 pub const BOARD_SIZE: u8 = 40;
+/// BoardConfig limits
+pub const MAX_SPACE_NAME_LEN: usize = 32;
+pub const MAX_OWNABLE_SPACES: usize = 28;
+pub const MAX_GROUP_SIZE: usize = 4;
 pub const STARTING_BALANCE: u64 = 1_500_000_000; // 1500 BPOLY (6 decimals)
 pub const GENESIS_SALARY: u64 = 200_000_000;     // 200 BPOLY
 pub const BPOLY_DECIMALS: u8 = 6;
@@ -16,7 +26,27 @@ pub const BPOLY_DECIMALS: u8 = 6;
 pub const RUGPULL_MAX_TURNS: u8 = 3;
 pub const RUGPULL_BAIL_AMOUNT: u64 = 50_000_000; // 50 BPOLY
 
-/// Tax amounts
+/// Commit-reveal dice: slots each stage stays open before resolve_dice_timeout
+pub const DICE_COMMIT_TIMEOUT_SLOTS: u64 = 150;  // ~1 minute
+pub const DICE_REVEAL_TIMEOUT_SLOTS: u64 = 150;
+
+/// Oracle dice: slots after the commit before expire_dice_roll may close an unsettled roll
+pub const VRF_TIMEOUT_SLOTS: u64 = 150;
+
+/// What a PlayerState debt is owed for (PlayerState.debt_reason)
+pub const DEBT_REASON_NONE: u8 = 0;
+pub const DEBT_REASON_RENT: u8 = 1;
+pub const DEBT_REASON_TAX: u8 = 2;
+pub const DEBT_REASON_CARD: u8 = 3;
+pub const DEBT_REASON_BAIL: u8 = 4;
+pub const DEBT_REASON_MARKET_EVENT: u8 = 5;
+/// Owed to another player for their card (DAO Airdrop, DAO Birthday Vote);
+/// payable any time, must be settled before the debtor's next roll
+pub const DEBT_REASON_PLAYER_CARD: u8 = 6;
+/// A defaulted Flash Loan plus its penalty, owed before the roll
+pub const DEBT_REASON_FLASH_LOAN: u8 = 7;
+
+/// Tax amounts on the default board
 pub const GAS_FEE_TAX: u64 = 200_000_000;   // space 4: 200 BPOLY
 pub const PROTOCOL_FEE_TAX: u64 = 100_000_000; // space 38: 100 BPOLY
 
@@ -25,18 +55,41 @@ pub const FLASH_LOAN_AMOUNT: u64 = 200_000_000;   // 200 BPOLY
 pub const FLASH_LOAN_REPAY: u64 = 210_000_000;    // 210 BPOLY
 pub const FLASH_LOAN_PENALTY: u64 = 50_000_000;   // 50 BPOLY late penalty
 
-/// Card deck sizes
-pub const ALPHA_CALL_DECK_SIZE: u8 = 16;
-pub const GOVERNANCE_DECK_SIZE: u8 = 16;
+/// CardDeck limits
+pub const MAX_DECK_CARDS: usize = 24;
+pub const MAX_CARD_NAME_LEN: usize = 32;
+
+/// Deck types (CardDrawn.deck, GameState.drawn_card)
+pub const DECK_ALPHA_CALL: u8 = 0;
+pub const DECK_GOVERNANCE: u8 = 1;
 
 /// Auction parameters
 pub const AUCTION_DURATION_TURNS: u32 = 3;
 
+/// Bank building stock at the start of a game (GameState.lps_available,
+/// protocols_available)
+pub const STARTING_LPS: u8 = 32;
+pub const STARTING_PROTOCOLS: u8 = 12;
+
+/// Building kinds (bid_building, BuildingAuction.kind)
+pub const BUILDING_LP: u8 = 0;
+pub const BUILDING_PROTOCOL: u8 = 1;
+
+/// Shortage auctions: bidding closes this long after the last bid, then the
+/// winner has as long again to build before anyone else may
+pub const BUILDING_AUCTION_SLOTS: u64 = 150;   // ~1 minute
+pub const BUILDING_AUCTION_CLAIM_SLOTS: u64 = 150;
+
+/// Market effects active at once (GameState.market_effects)
+pub const MAX_MARKET_EFFECTS: usize = 4;
+
 /// Board space indices
 pub const SPACE_GENESIS: u8 = 0;
+pub const SPACE_GAS_FEE_TAX: u8 = 4;
 pub const SPACE_RUGPULL_ZONE: u8 = 10;
 pub const SPACE_DEFI_SUMMER: u8 = 20;
 pub const SPACE_SEC_INVESTIGATION: u8 = 30;
+pub const SPACE_PROTOCOL_FEE: u8 = 38;
 
 /// Space types
 pub const SPACE_TYPE_PROPERTY: u8 = 0;
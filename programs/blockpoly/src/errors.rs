@@ -42,12 +42,6 @@ pub enum BlockpolyError {
     PlayerBankrupt,
     #[msg("Only the game host can perform this action")]
     HostOnly,
-    #[msg("A flash loan is already active")]
-    FlashLoanAlreadyActive,
-    #[msg("Flash loan is overdue — repay before taking new actions")]
-    FlashLoanOverdue,
-    #[msg("Cannot repay flash loan yet — not enough turns have passed")]
-    FlashLoanTooEarly,
     #[msg("Three consecutive doubles — go to Rug Pull Zone")]
     TripleDoublesGoToJail,
     #[msg("Invalid board space index")]
@@ -88,4 +82,94 @@ pub enum BlockpolyError {
     RecipientNotInGame,
     #[msg("NFT collection address mismatch")]
     InvalidCollection,
+    #[msg("Randomness account is not a valid Switchboard randomness account")]
+    InvalidRandomnessAccount,
+    #[msg("Randomness was not committed in this slot")]
+    RandomnessNotCommitted,
+    #[msg("Randomness has not been revealed yet")]
+    RandomnessNotResolved,
+    #[msg("Only the game's randomness authority can fulfil dice rolls")]
+    UnauthorizedRandomnessAuthority,
+    #[msg("This instruction is not available in the game's dice mode")]
+    WrongDiceMode,
+    #[msg("Signer is not an active player in this game")]
+    NotInGame,
+    #[msg("Dice commitment already submitted")]
+    AlreadyCommitted,
+    #[msg("Dice commitment stage is closed")]
+    CommitStageClosed,
+    #[msg("Dice reveals are not open yet")]
+    RevealNotOpen,
+    #[msg("Dice secret already revealed")]
+    AlreadyRevealed,
+    #[msg("Revealed secret does not match the commitment")]
+    InvalidReveal,
+    #[msg("Dice commitment deadline has not passed")]
+    DiceTimeoutNotReached,
+    #[msg("Every player who missed the deadline must be passed in")]
+    MissingOffenderAccounts,
+    #[msg("Property account does not match the space")]
+    InvalidPropertyAccount,
+    #[msg("Supplied property accounts do not match the color group")]
+    InvalidGroupAccounts,
+    #[msg("Creditor token account does not match the debt")]
+    InvalidCreditorAccount,
+    #[msg("No outstanding debt")]
+    NoDebt,
+    #[msg("Not allowed while a debt is outstanding")]
+    DebtOutstanding,
+    #[msg("House rules are out of range")]
+    InvalidRuleSet,
+    #[msg("Board config does not belong to this game")]
+    InvalidBoardConfig,
+    #[msg("Board config is finalized and can no longer change")]
+    BoardConfigFinalized,
+    #[msg("Board config has not been finalized")]
+    BoardConfigNotFinalized,
+    #[msg("Board space data is invalid")]
+    InvalidBoardSpace,
+    #[msg("Only the board authority can edit this board")]
+    NotBoardAuthority,
+    #[msg("Apply this round's market event before rolling")]
+    MarketEventPending,
+    #[msg("No market event to apply")]
+    NoMarketEvent,
+    #[msg("Supplied accounts do not match the other players")]
+    InvalidOpponentAccounts,
+    #[msg("Supplied property accounts do not match the player's holdings")]
+    InvalidHoldingsAccounts,
+    #[msg("No card has been drawn")]
+    NoCardDrawn,
+    #[msg("Card deck does not belong to this game")]
+    InvalidCardDeck,
+    #[msg("Card deck is finalized and can no longer change")]
+    CardDeckFinalized,
+    #[msg("Card deck has not been finalized")]
+    CardDeckNotFinalized,
+    #[msg("Only the deck authority can edit this deck")]
+    NotDeckAuthority,
+    #[msg("No flash loan to repay")]
+    NoFlashLoan,
+    #[msg("Must sell evenly across all properties in the color group")]
+    UnevenSelling,
+    #[msg("The bank has none of that building left")]
+    BuildingShortage,
+    #[msg("Buildings are only auctioned when the bank is running out")]
+    NoBuildingShortage,
+    #[msg("A building auction is in progress")]
+    BuildingAuctionActive,
+    #[msg("Unknown building kind")]
+    InvalidBuildingKind,
+    #[msg("Property has a Full Protocol; sell it with sell_protocol")]
+    FullProtocolBuilt,
+    #[msg("Property has no Full Protocol")]
+    NoFullProtocol,
+    #[msg("A property holds at most 4 LPs")]
+    InvalidLpCount,
+    #[msg("Randomness is revealed; settle the roll with consume_randomness")]
+    RandomnessRevealed,
+    #[msg("Outbid token account does not belong to the highest bidder")]
+    InvalidOutbidAccount,
+    #[msg("Nobody else could build one; build it without an auction")]
+    UncontestedBuilding,
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::{MarketEffect, MarketEffectKind};
 
 #[event]
 pub struct GameCreated {
@@ -71,6 +72,15 @@ pub struct LPSold {
     pub lp_count: u8,
 }
 
+#[event]
+pub struct ProtocolSold {
+    pub game_id: [u8; 32],
+    pub player: Pubkey,
+    pub space: u8,
+    /// LPs the property is left with
+    pub lp_count: u8,
+}
+
 #[event]
 pub struct CardDrawn {
     pub game_id: [u8; 32],
@@ -91,7 +101,7 @@ pub struct CardResolved {
 pub struct RugPullEntered {
     pub game_id: [u8; 32],
     pub player: Pubkey,
-    pub reason: u8, // 0 = SEC Investigation space, 1 = card, 2 = triple doubles
+    pub reason: u8, // 0 = SEC Investigation space, 1 = card, 2 = triple doubles, 3 = missed or withheld dice
 }
 
 #[event]
@@ -180,9 +190,32 @@ pub struct AuctionWon {
 }
 
 #[event]
-pub struct BullRunActivated {
+pub struct MarketEffectStarted {
+    pub game_id: [u8; 32],
+    pub effect: MarketEffect,
+}
+
+#[event]
+pub struct MarketEffectExpired {
+    pub game_id: [u8; 32],
+    pub kind: MarketEffectKind,
+}
+
+#[event]
+pub struct MarketEventDrawn {
     pub game_id: [u8; 32],
-    pub ends_round: u32,
+    pub event_id: u8,
+    pub round: u32,
+}
+
+#[event]
+pub struct MarketEventApplied {
+    pub game_id: [u8; 32],
+    pub player: Pubkey,
+    pub event_id: u8,
+    pub name: String,
+    /// BPOLY paid (negative) or collected (positive) by the player
+    pub amount: i64,
 }
 
 #[event]
@@ -199,3 +232,98 @@ pub struct VrfRequested {
     pub player: Pubkey,
     pub vrf_account: Pubkey,
 }
+
+#[event]
+pub struct DiceCommitted {
+    pub game_id: [u8; 32],
+    pub player: Pubkey,
+    pub turn_number: u32,
+}
+
+#[event]
+pub struct DiceRevealed {
+    pub game_id: [u8; 32],
+    pub player: Pubkey,
+    pub turn_number: u32,
+}
+
+#[event]
+pub struct DebtIncurred {
+    pub game_id: [u8; 32],
+    pub debtor: Pubkey,
+    /// None = owed to the bank
+    pub creditor: Option<Pubkey>,
+    pub amount: u64,
+    /// DEBT_REASON_* code
+    pub reason: u8,
+}
+
+#[event]
+pub struct DebtSettled {
+    pub game_id: [u8; 32],
+    pub debtor: Pubkey,
+    pub creditor: Option<Pubkey>,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BoardConfigFinalized {
+    pub board_id: [u8; 32],
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct PropertyReturnedToBank {
+    pub game_id: [u8; 32],
+    pub player: Pubkey,
+    pub space: u8,
+    /// Paid to the player for it (Whale Dump), net of any mortgage
+    pub proceeds: u64,
+}
+
+#[event]
+pub struct CardDeckFinalized {
+    pub deck_id: [u8; 32],
+    pub authority: Pubkey,
+    pub alpha_call_cards: u8,
+    pub governance_cards: u8,
+}
+
+#[event]
+pub struct FlashLoanRepaid {
+    pub game_id: [u8; 32],
+    pub player: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BuildingAuctionBid {
+    pub game_id: [u8; 32],
+    pub bidder: Pubkey,
+    /// BUILDING_LP or BUILDING_PROTOCOL
+    pub kind: u8,
+    pub amount: u64,
+    pub end_slot: u64,
+}
+
+#[event]
+pub struct BuildingAuctionWon {
+    pub game_id: [u8; 32],
+    pub winner: Pubkey,
+    pub kind: u8,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DiceRollExpired {
+    pub game_id: [u8; 32],
+    pub player: Pubkey,
+    /// The roller withheld or re-committed their randomness and lost the roll
+    pub forfeited: bool,
+}
+
+#[event]
+pub struct FlashLoanDeclined {
+    pub game_id: [u8; 32],
+    pub player: Pubkey,
+}
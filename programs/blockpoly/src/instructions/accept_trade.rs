@@ -5,6 +5,9 @@ use crate::errors::BlockpolyError;
 use crate::events::TradeCompleted;
 use crate::state::{GameState, GameStatus, PlayerState, PropertyState, TradeOffer};
 
+/// Completes a trade offer. remaining_accounts must hold the PropertyState of
+/// every offered property, then of every requested property, in the offer's
+/// order; each is handed to its new owner.
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32])]
 pub struct AcceptTrade<'info> {
@@ -12,6 +15,7 @@ pub struct AcceptTrade<'info> {
     pub recipient: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [SEED_GAME_STATE, &game_id],
         bump = game_state.bump,
     )]
@@ -41,6 +45,10 @@ pub struct AcceptTrade<'info> {
     )]
     pub trade_offer: Account<'info, TradeOffer>,
 
+    /// CHECK: bank vault PDA, the proposer's delegate for the offered BPOLY
+    #[account(seeds = [SEED_BANK_VAULT, &game_id], bump)]
+    pub bank_vault: UncheckedAccount<'info>,
+
     #[account(mut, address = game_state.bank_bpoly_ata)]
     pub bank_bpoly_ata: Account<'info, TokenAccount>,
 
@@ -63,8 +71,11 @@ pub struct AcceptTrade<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<AcceptTrade>, game_id: [u8; 32]) -> Result<()> {
-    let game = &ctx.accounts.game_state;
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AcceptTrade<'info>>,
+    game_id: [u8; 32],
+) -> Result<()> {
+    let game = &mut ctx.accounts.game_state;
     let trade = &ctx.accounts.trade_offer;
 
     require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
@@ -82,18 +93,21 @@ pub fn handler(ctx: Context<AcceptTrade>, game_id: [u8; 32]) -> Result<()> {
     let offered_jail_free = trade.offered_jail_free;
     let requested_jail_free = trade.requested_jail_free;
 
-    // Transfer BPOLY: proposer → recipient (if offered_bpoly > 0)
+    // Transfer BPOLY: proposer → recipient (if offered_bpoly > 0), signed by
+    // the bank vault the proposer approved as delegate in join_game
     if offered_bpoly > 0 {
-        let cpi_ctx = CpiContext::new(
+        let bank_vault_bump = ctx.bumps.bank_vault;
+        let signer_seeds: &[&[&[u8]]] = &[&[SEED_BANK_VAULT, &game_id, &[bank_vault_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             Transfer {
                 from: ctx.accounts.proposer_bpoly_ata.to_account_info(),
                 to: ctx.accounts.recipient_bpoly_ata.to_account_info(),
-                authority: ctx.accounts.recipient.to_account_info(), // NOTE: needs proposer sig — simplified
+                authority: ctx.accounts.bank_vault.to_account_info(),
             },
+            signer_seeds,
         );
-        // In production, proposer would co-sign. For now, use remaining_accounts for proposer signer.
-        // Simplified: transfer from bank as intermediary
+        token::transfer(cpi_ctx, offered_bpoly)?;
     }
 
     // Transfer BPOLY: recipient → proposer (if requested_bpoly > 0)
@@ -109,7 +123,16 @@ pub fn handler(ctx: Context<AcceptTrade>, game_id: [u8; 32]) -> Result<()> {
         token::transfer(cpi_ctx, requested_bpoly)?;
     }
 
-    // Swap property ownership in PlayerState
+    // Swap property ownership, on each PropertyState and in PlayerState
+    require!(
+        ctx.remaining_accounts.len() == offered_props.len() + requested_props.len(),
+        BlockpolyError::InvalidPropertyAccount
+    );
+    let (offered_accounts, requested_accounts) =
+        ctx.remaining_accounts.split_at(offered_props.len());
+    hand_over(&game_id, &offered_props, proposer_key, recipient_key, offered_accounts)?;
+    hand_over(&game_id, &requested_props, recipient_key, proposer_key, requested_accounts)?;
+
     let proposer_state = &mut ctx.accounts.proposer_state;
     let recipient_state = &mut ctx.accounts.recipient_state;
 
@@ -122,20 +145,30 @@ pub fn handler(ctx: Context<AcceptTrade>, game_id: [u8; 32]) -> Result<()> {
         proposer_state.add_property(prop);
     }
 
-    // Swap jail-free cards
-    if offered_jail_free {
-        proposer_state.has_jail_free_card = false;
-        recipient_state.has_jail_free_card = true;
-        recipient_state.jail_free_card_type = proposer_state.jail_free_card_type;
+    // Swap jail-free cards. Either card may have been used since the offer.
+    let offered_card = if offered_jail_free {
+        Some(proposer_state.take_jail_free_card().ok_or(BlockpolyError::InvalidTradeOffer)?)
+    } else {
+        None
+    };
+    let requested_card = if requested_jail_free {
+        Some(recipient_state.take_jail_free_card().ok_or(BlockpolyError::InvalidTradeOffer)?)
+    } else {
+        None
+    };
+    // A player holds one card at a time; one they have no room for goes
+    // back under its deck
+    if let Some(deck_type) = offered_card {
+        if !recipient_state.keep_jail_free_card(deck_type) {
+            game.return_held_card(deck_type);
+        }
     }
-    if requested_jail_free {
-        recipient_state.has_jail_free_card = false;
-        proposer_state.has_jail_free_card = true;
+    if let Some(deck_type) = requested_card {
+        if !proposer_state.keep_jail_free_card(deck_type) {
+            game.return_held_card(deck_type);
+        }
     }
 
-    // NOTE: PropertyState.owner update requires passing all property PDAs via remaining_accounts.
-    // Full implementation iterates remaining_accounts and updates each PropertyState.owner.
-
     emit!(TradeCompleted {
         game_id,
         proposer: proposer_key,
@@ -144,3 +177,28 @@ pub fn handler(ctx: Context<AcceptTrade>, game_id: [u8; 32]) -> Result<()> {
 
     Ok(())
 }
+
+/// Moves each PropertyState in `accounts` (one per space, same order) from
+/// `from` to `to`. A property `from` no longer owns fails the trade.
+fn hand_over<'info>(
+    game_id: &[u8; 32],
+    spaces: &[u8],
+    from: Pubkey,
+    to: Pubkey,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    for (&space, info) in spaces.iter().zip(accounts.iter()) {
+        let (expected, _) = Pubkey::find_program_address(
+            &[SEED_PROPERTY_STATE, game_id, &[space]],
+            &crate::ID,
+        );
+        require_keys_eq!(info.key(), expected, BlockpolyError::InvalidPropertyAccount);
+        require!(info.is_writable, BlockpolyError::InvalidPropertyAccount);
+
+        let mut property: Account<'info, PropertyState> = Account::try_from(info)?;
+        require_keys_eq!(property.owner, from, BlockpolyError::InvalidTradeOffer);
+        property.owner = to;
+        property.exit(&crate::ID)?;
+    }
+    Ok(())
+}
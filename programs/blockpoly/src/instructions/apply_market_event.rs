@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::constants::*;
+use crate::errors::BlockpolyError;
+use crate::events::{MarketEffectStarted, MarketEventApplied};
+use crate::instructions::settle_debt::pay_or_owe;
+use crate::market_events::{MarketEventEffect, MARKET_EVENTS};
+use crate::state::{GameState, GameStatus, MarketEffect, PlayerState, TurnPhase};
+
+/// Applies this round's market event to the current player before they roll.
+/// Fees go through the debt flow (→ RaiseFunds if short); a global effect is
+/// started by the first player to apply the event.
+#[derive(Accounts)]
+#[instruction(game_id: [u8; 32])]
+pub struct ApplyMarketEvent<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GAME_STATE, &game_id],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PLAYER_STATE, &game_id, player.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.wallet == player.key() @ BlockpolyError::NotYourTurn,
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    /// CHECK: bank vault PDA
+    #[account(seeds = [SEED_BANK_VAULT, &game_id], bump)]
+    pub bank_vault: UncheckedAccount<'info>,
+
+    #[account(mut, address = game_state.bank_bpoly_ata)]
+    pub bank_bpoly_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_state.bpoly_mint,
+        associated_token::authority = player,
+    )]
+    pub player_bpoly_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ApplyMarketEvent>, game_id: [u8; 32]) -> Result<()> {
+    let game = &mut ctx.accounts.game_state;
+    let player_state = &mut ctx.accounts.player_state;
+
+    require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
+    require!(game.turn_phase == TurnPhase::RollDice, BlockpolyError::WrongTurnPhase);
+    require!(
+        game.current_player_index == player_state.player_index,
+        BlockpolyError::NotYourTurn
+    );
+    require!(
+        game.market_event_due(player_state.market_event_round),
+        BlockpolyError::NoMarketEvent
+    );
+    // A debt from another player's card comes first
+    require!(!player_state.has_debt(), BlockpolyError::DebtOutstanding);
+
+    let event_id = game.market_event.ok_or(BlockpolyError::NoMarketEvent)?;
+    let event = &MARKET_EVENTS[event_id as usize];
+    // Applied even if the fee ends up owed; the debt is what's left to settle
+    player_state.market_event_round = game.market_event_round;
+
+    let amount: i64 = match event.effect {
+        MarketEventEffect::PlayersPay(fee) => {
+            pay_or_owe(
+                game,
+                player_state,
+                &mut ctx.accounts.player_bpoly_ata,
+                &ctx.accounts.bank_bpoly_ata,
+                &ctx.accounts.player,
+                &ctx.accounts.token_program,
+                None,
+                fee,
+                DEBT_REASON_MARKET_EVENT,
+            )?;
+            -(fee as i64)
+        }
+        MarketEventEffect::PlayersCollect(payout) => {
+            let bank_vault_bump = ctx.bumps.bank_vault;
+            let signer_seeds: &[&[&[u8]]] = &[&[SEED_BANK_VAULT, &game_id, &[bank_vault_bump]]];
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.bank_bpoly_ata.to_account_info(),
+                    to: ctx.accounts.player_bpoly_ata.to_account_info(),
+                    authority: ctx.accounts.bank_vault.to_account_info(),
+                },
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, payout)?;
+            player_state.bpoly_balance = player_state.bpoly_balance.saturating_add(payout);
+            payout as i64
+        }
+        MarketEventEffect::Global { kind, target, modifier, rounds } => {
+            if !game.market_event_started {
+                let effect = MarketEffect {
+                    kind,
+                    target,
+                    modifier,
+                    ends_round: game.round_number + rounds.saturating_sub(1),
+                };
+                game.add_market_effect(effect);
+                game.market_event_started = true;
+                emit!(MarketEffectStarted { game_id, effect });
+            }
+            0
+        }
+    };
+
+    emit!(MarketEventApplied {
+        game_id,
+        player: ctx.accounts.player.key(),
+        event_id,
+        name: event.name.to_string(),
+        amount,
+    });
+
+    Ok(())
+}
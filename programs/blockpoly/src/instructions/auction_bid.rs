@@ -63,7 +63,6 @@ pub fn handler(
 ) -> Result<()> {
     let game = &mut ctx.accounts.game_state;
     let bidder_state = &mut ctx.accounts.bidder_state;
-    let property = &mut ctx.accounts.property_state;
 
     require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
     require!(game.turn_phase == TurnPhase::AuctionPhase, BlockpolyError::AuctionNotActive);
@@ -81,7 +80,7 @@ pub fn handler(
     game.auction_highest_bid = bid_amount;
     game.auction_highest_bidder = Some(ctx.accounts.bidder.key());
     // Extend deadline on new bid
-    game.auction_end_turn = game.turn_number + AUCTION_DURATION_TURNS;
+    game.auction_end_turn = game.turn_number + game.rules.auction_duration_turns;
 
     emit!(AuctionBid {
         game_id,
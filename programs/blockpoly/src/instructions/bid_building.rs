@@ -0,0 +1,251 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::constants::*;
+use crate::errors::BlockpolyError;
+use crate::events::BuildingAuctionBid;
+use crate::instructions::build_lp::SiblingBuildings;
+use crate::state::{
+    BoardConfig, BuildingAuction, EffectSubject, GameState, GameStatus, PlayerState,
+    PropertyState,
+};
+
+/// Bids for the next building of a kind once the bank holds fewer of them
+/// than there are players. The first bid opens the auction; while it runs
+/// nobody can build that kind. Bidding closes BUILDING_AUCTION_SLOTS after
+/// the last bid, and only the winner may then build it (build_lp /
+/// build_protocol) until their claim lapses.
+///
+/// Only a player who could build that kind may bid: property_state is one of
+/// theirs it could go on, with the other PropertyStates of its colour group in
+/// remaining_accounts, as for build_lp. An auction is only opened when someone
+/// else could build it too: rival_property is another player's property it
+/// could go on, with the rest of that colour group after the bidder's. A
+/// player nobody competes with simply builds.
+///
+/// Bids are escrowed with the bank: each bid is paid when made and the player
+/// it outbids is refunded. The opening bid is at least the building's cost.
+/// The winner's bid is the price of the building on top of its cost; a winner
+/// who lets the claim lapse forfeits it.
+#[derive(Accounts)]
+#[instruction(game_id: [u8; 32])]
+pub struct BidBuilding<'info> {
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GAME_STATE, &game_id],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
+    #[account(
+        seeds = [SEED_PLAYER_STATE, &game_id, bidder.key().as_ref()],
+        bump = bidder_state.bump,
+    )]
+    pub bidder_state: Account<'info, PlayerState>,
+
+    /// A property of the bidder's the building could go on
+    #[account(
+        seeds = [SEED_PROPERTY_STATE, &game_id, &[property_state.space_index]],
+        bump = property_state.bump,
+        constraint = property_state.owner == bidder.key() @ BlockpolyError::NotPropertyOwner,
+    )]
+    pub property_state: Account<'info, PropertyState>,
+
+    /// CHECK: bank vault PDA
+    #[account(seeds = [SEED_BANK_VAULT, &game_id], bump)]
+    pub bank_vault: UncheckedAccount<'info>,
+
+    #[account(mut, address = game_state.bank_bpoly_ata)]
+    pub bank_bpoly_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_state.bpoly_mint,
+        associated_token::authority = bidder,
+    )]
+    pub bidder_bpoly_ata: Account<'info, TokenAccount>,
+
+    /// BPOLY ATA of the highest bidder so far, refunded their bid; required
+    /// while the auction has one
+    #[account(mut)]
+    pub outbid_bpoly_ata: Option<Account<'info, TokenAccount>>,
+
+    /// Another player's property the building could go on; required to open
+    /// the auction
+    pub rival_property: Option<Account<'info, PropertyState>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BidBuilding<'info>>,
+    game_id: [u8; 32],
+    kind: u8,
+    amount: u64,
+) -> Result<()> {
+    let game = &mut ctx.accounts.game_state;
+    let bidder = ctx.accounts.bidder.key();
+    let property = &ctx.accounts.property_state;
+    let slot = Clock::get()?.slot;
+
+    require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
+    require!(!ctx.accounts.bidder_state.is_bankrupt, BlockpolyError::PlayerBankrupt);
+    require!(!ctx.accounts.bidder_state.has_debt(), BlockpolyError::DebtOutstanding);
+    require!(
+        kind == BUILDING_LP || kind == BUILDING_PROTOCOL,
+        BlockpolyError::InvalidBuildingKind
+    );
+    require!(
+        ctx.accounts.bidder_bpoly_ata.amount >= amount,
+        BlockpolyError::InsufficientBalance
+    );
+
+    // The bidder must be able to build this kind, auction aside
+    let board = &ctx.accounts.board_config;
+    let space = board.space(property.space_index);
+    let sibling_count = board.group_spaces(space.group).len().saturating_sub(1);
+    require!(
+        ctx.remaining_accounts.len() >= sibling_count,
+        BlockpolyError::InvalidGroupAccounts
+    );
+    let (sibling_accounts, rival_accounts) = ctx.remaining_accounts.split_at(sibling_count);
+    require_can_build(&game_id, board, kind, property, sibling_accounts)?;
+
+    // An auction past its claim window, or closed without bids, is over; an
+    // unclaimed winning bid stays with the bank
+    if let Some(auction) = game.building_auction {
+        let over = if auction.highest_bidder.is_some() {
+            slot > auction.end_slot + BUILDING_AUCTION_CLAIM_SLOTS
+        } else {
+            slot > auction.end_slot
+        };
+        if over {
+            game.building_auction = None;
+        }
+    }
+
+    let auction = match game.building_auction {
+        Some(auction) => {
+            require!(
+                auction.kind == kind && slot <= auction.end_slot,
+                BlockpolyError::BuildingAuctionActive
+            );
+            require!(amount > auction.highest_bid, BlockpolyError::BidTooLow);
+            auction
+        }
+        None => {
+            let available = game.buildings_available(kind);
+            require!(available > 0, BlockpolyError::BuildingShortage);
+            require!(available < game.player_count, BlockpolyError::NoBuildingShortage);
+
+            let rival = ctx
+                .accounts
+                .rival_property
+                .as_ref()
+                .ok_or(BlockpolyError::UncontestedBuilding)?;
+            let (expected, _) = Pubkey::find_program_address(
+                &[SEED_PROPERTY_STATE, &game_id, &[rival.space_index]],
+                &crate::ID,
+            );
+            require_keys_eq!(rival.key(), expected, BlockpolyError::InvalidPropertyAccount);
+            require!(rival.owner != bidder, BlockpolyError::UncontestedBuilding);
+            require_can_build(&game_id, board, kind, rival, rival_accounts)?;
+
+            let cost = game.apply_market_effects(EffectSubject::BuildCost, space.lp_cost);
+            require!(amount >= cost, BlockpolyError::BidTooLow);
+            BuildingAuction {
+                kind,
+                highest_bid: 0,
+                highest_bidder: None,
+                end_slot: 0,
+            }
+        }
+    };
+
+    // Refund the bid this one tops
+    if let Some(outbid) = auction.highest_bidder {
+        let outbid_ata = ctx
+            .accounts
+            .outbid_bpoly_ata
+            .as_ref()
+            .ok_or(BlockpolyError::InvalidOutbidAccount)?;
+        require!(
+            outbid_ata.owner == outbid && outbid_ata.mint == game.bpoly_mint,
+            BlockpolyError::InvalidOutbidAccount
+        );
+
+        let bank_vault_bump = ctx.bumps.bank_vault;
+        let signer_seeds: &[&[&[u8]]] = &[&[SEED_BANK_VAULT, &game_id, &[bank_vault_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.bank_bpoly_ata.to_account_info(),
+                to: outbid_ata.to_account_info(),
+                authority: ctx.accounts.bank_vault.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, auction.highest_bid)?;
+    }
+
+    // Escrow this bid with the bank
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.bidder_bpoly_ata.to_account_info(),
+            to: ctx.accounts.bank_bpoly_ata.to_account_info(),
+            authority: ctx.accounts.bidder.to_account_info(),
+        },
+    );
+    token::transfer(cpi_ctx, amount)?;
+
+    let end_slot = slot + BUILDING_AUCTION_SLOTS;
+    game.building_auction = Some(BuildingAuction {
+        highest_bid: amount,
+        highest_bidder: Some(bidder),
+        end_slot,
+        ..auction
+    });
+
+    emit!(BuildingAuctionBid {
+        game_id,
+        bidder,
+        kind,
+        amount,
+        end_slot,
+    });
+
+    Ok(())
+}
+
+/// Requires that `kind` could be built on `property` by its owner: it stands on
+/// a property space, takes that kind next and keeps its colour group even.
+/// `accounts` are the group's other PropertyStates, as for build_lp.
+fn require_can_build<'info>(
+    game_id: &[u8; 32],
+    board: &BoardConfig,
+    kind: u8,
+    property: &PropertyState,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    let space = board.space(property.space_index);
+    require!(space.space_type == SPACE_TYPE_PROPERTY, BlockpolyError::PropertyNotAvailable);
+    if kind == BUILDING_LP {
+        require!(property.can_build_lp(), BlockpolyError::MaxLPsReached);
+    } else {
+        require!(property.can_upgrade_protocol(), BlockpolyError::UnevenBuilding);
+    }
+    let siblings = SiblingBuildings::load(
+        game_id,
+        &board.group_spaces(space.group),
+        property.space_index,
+        &property.owner,
+        accounts,
+    )?;
+    siblings.require_even_build(property)
+}
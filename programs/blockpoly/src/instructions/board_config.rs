@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::board::default_spaces;
+use crate::constants::*;
+use crate::errors::BlockpolyError;
+use crate::events::BoardConfigFinalized;
+use crate::state::{BoardConfig, BoardSpace};
+
+/// Creates a BoardConfig holding the default board. The signer becomes its
+/// authority and may rewrite spaces with set_board_spaces until it is finalized.
+#[derive(Accounts)]
+#[instruction(board_id: [u8; 32])]
+pub struct CreateBoardConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = BoardConfig::MAX_SIZE,
+        seeds = [SEED_BOARD_CONFIG, &board_id],
+        bump
+    )]
+    pub board_config: Account<'info, BoardConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Shared by set_board_spaces and finalize_board_config
+#[derive(Accounts)]
+#[instruction(board_id: [u8; 32])]
+pub struct EditBoardConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_BOARD_CONFIG, &board_id],
+        bump = board_config.bump,
+        has_one = authority @ BlockpolyError::NotBoardAuthority,
+    )]
+    pub board_config: Account<'info, BoardConfig>,
+}
+
+pub fn handler_create(ctx: Context<CreateBoardConfig>, board_id: [u8; 32]) -> Result<()> {
+    let board = &mut ctx.accounts.board_config;
+    board.authority = ctx.accounts.authority.key();
+    board.board_id = board_id;
+    board.finalized = false;
+    board.spaces = default_spaces();
+    board.bump = ctx.bumps.board_config;
+    Ok(())
+}
+
+/// Overwrites `spaces.len()` spaces starting at `start_index`. Boards are
+/// written in chunks because a full board does not fit in one transaction.
+pub fn handler_set_spaces(
+    ctx: Context<EditBoardConfig>,
+    _board_id: [u8; 32],
+    start_index: u8,
+    spaces: Vec<BoardSpace>,
+) -> Result<()> {
+    let board = &mut ctx.accounts.board_config;
+    require!(!board.finalized, BlockpolyError::BoardConfigFinalized);
+
+    let start = start_index as usize;
+    require!(
+        start + spaces.len() <= BOARD_SIZE as usize,
+        BlockpolyError::InvalidSpaceIndex
+    );
+    for (offset, space) in spaces.into_iter().enumerate() {
+        require!(space.name.len() <= MAX_SPACE_NAME_LEN, BlockpolyError::InvalidBoardSpace);
+        board.spaces[start + offset] = space;
+    }
+    Ok(())
+}
+
+/// Validates the board and locks it; games can only be created on finalized boards.
+pub fn handler_finalize(ctx: Context<EditBoardConfig>, board_id: [u8; 32]) -> Result<()> {
+    let board = &mut ctx.accounts.board_config;
+    require!(!board.finalized, BlockpolyError::BoardConfigFinalized);
+
+    board.validate()?;
+    board.finalized = true;
+
+    emit!(BoardConfigFinalized {
+        board_id,
+        authority: board.authority,
+    });
+    Ok(())
+}
@@ -1,11 +1,14 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::board::{BOARD, group_spaces};
 use crate::constants::*;
 use crate::errors::BlockpolyError;
-use crate::events::LPBuilt;
-use crate::state::{GameState, GameStatus, PlayerState, PropertyState, TurnPhase};
+use crate::events::{BuildingAuctionWon, LPBuilt};
+use crate::state::{BoardConfig, EffectSubject, GameState, GameStatus, PlayerState, PropertyState};
 
+/// Builds an LP on a property of a complete, unmortgaged colour group.
+/// remaining_accounts must hold the PropertyState PDA of every other space in
+/// the group, in board order; building must keep the group even. The LP
+/// comes out of the bank's stock.
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32], space_index: u8)]
 pub struct BuildLP<'info> {
@@ -19,6 +22,10 @@ pub struct BuildLP<'info> {
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
     #[account(
         seeds = [SEED_PLAYER_STATE, &game_id, player.key().as_ref()],
         bump = player_state.bump,
@@ -51,43 +58,40 @@ pub struct BuildLP<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(
-    ctx: Context<BuildLP>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuildLP<'info>>,
     game_id: [u8; 32],
     space_index: u8,
-    // LP counts of all other properties in the same group (for even-build validation)
-    sibling_lp_counts: Vec<u8>,
 ) -> Result<()> {
-    let game = &ctx.accounts.game_state;
+    let game = &mut ctx.accounts.game_state;
     let player_state = &ctx.accounts.player_state;
     let property = &mut ctx.accounts.property_state;
 
     require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
     // Building is allowed outside the turn's main action phase (any time)
+    // except while raising funds for a debt
+    require!(!player_state.has_debt(), BlockpolyError::DebtOutstanding);
     require!(!property.is_mortgaged, BlockpolyError::PropertyMortgaged);
     require!(property.can_build_lp(), BlockpolyError::MaxLPsReached);
     require!(property.owner == ctx.accounts.player.key(), BlockpolyError::NotPropertyOwner);
 
-    let space = &BOARD[space_index as usize];
+    let space = ctx.accounts.board_config.space(space_index);
     require!(space.space_type == SPACE_TYPE_PROPERTY, BlockpolyError::PropertyNotAvailable);
 
-    // Validate complete color set ownership
-    let group = space.group;
-    let group_members = group_spaces(group);
-    require!(
-        player_state.properties_owned.iter().filter(|&&s| group_members.contains(&s)).count()
-            == group_members.len(),
-        BlockpolyError::IncompleteColorSet
-    );
-
-    // Even-building rule: can't build if any sibling has fewer LPs
-    let current_lps = property.liquidity_pools;
-    for &sibling_lp in &sibling_lp_counts {
-        require!(sibling_lp >= current_lps, BlockpolyError::UnevenBuilding);
-    }
-
-    let lp_cost = space.lp_cost;
-    // Transfer LP cost from player to bank
+    // Complete colour set, none mortgaged, and no sibling behind this one
+    let siblings = SiblingBuildings::load(
+        &game_id,
+        &ctx.accounts.board_config.group_spaces(space.group),
+        space_index,
+        &property.owner,
+        ctx.remaining_accounts,
+    )?;
+    siblings.require_even_build(property)?;
+
+    let builder = ctx.accounts.player.key();
+    let winning_bid = game.take_building(BUILDING_LP, builder, Clock::get()?.slot)?;
+    let lp_cost = game.apply_market_effects(EffectSubject::BuildCost, space.lp_cost);
+    // Transfer LP cost from player to bank; a shortage auction bid was paid when made
     let cpi_ctx = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
         Transfer {
@@ -100,6 +104,15 @@ pub fn handler(
 
     property.liquidity_pools += 1;
 
+    if winning_bid > 0 {
+        emit!(BuildingAuctionWon {
+            game_id,
+            winner: builder,
+            kind: BUILDING_LP,
+            amount: winning_bid,
+        });
+    }
+
     emit!(LPBuilt {
         game_id,
         player: ctx.accounts.player.key(),
@@ -109,3 +122,69 @@ pub fn handler(
 
     Ok(())
 }
+
+/// The other properties of a colour group, read from their PropertyStates
+pub struct SiblingBuildings {
+    /// building_level of each sibling
+    pub levels: Vec<u8>,
+    /// Any sibling is mortgaged
+    pub any_mortgaged: bool,
+}
+
+impl SiblingBuildings {
+    /// `accounts` must be exactly the PropertyState PDAs of `group_spaces`
+    /// other than `space_index`, in board order, each owned by `owner`;
+    /// a sibling not owned by `owner` means the colour set is incomplete.
+    pub fn load<'info>(
+        game_id: &[u8; 32],
+        group_spaces: &[u8],
+        space_index: u8,
+        owner: &Pubkey,
+        accounts: &'info [AccountInfo<'info>],
+    ) -> Result<Self> {
+        let spaces: Vec<u8> = group_spaces.iter().copied().filter(|&s| s != space_index).collect();
+        require!(accounts.len() == spaces.len(), BlockpolyError::InvalidGroupAccounts);
+
+        let mut siblings = Self {
+            levels: Vec::with_capacity(spaces.len()),
+            any_mortgaged: false,
+        };
+        for (&space, info) in spaces.iter().zip(accounts.iter()) {
+            let (expected, _) = Pubkey::find_program_address(
+                &[SEED_PROPERTY_STATE, game_id, &[space]],
+                &crate::ID,
+            );
+            require_keys_eq!(info.key(), expected, BlockpolyError::InvalidGroupAccounts);
+            require!(!info.data_is_empty(), BlockpolyError::IncompleteColorSet);
+
+            let state: Account<'info, PropertyState> = Account::try_from(info)?;
+            require_keys_eq!(state.owner, *owner, BlockpolyError::IncompleteColorSet);
+            siblings.levels.push(state.building_level());
+            siblings.any_mortgaged |= state.is_mortgaged;
+        }
+        Ok(siblings)
+    }
+
+    /// Building on `property` may not put it more than one level ahead of any
+    /// sibling, and nothing can be built while a sibling is mortgaged
+    pub fn require_even_build(&self, property: &PropertyState) -> Result<()> {
+        require!(!self.any_mortgaged, BlockpolyError::PropertyMortgaged);
+        let level = property.building_level();
+        require!(
+            self.levels.iter().all(|&l| l >= level),
+            BlockpolyError::UnevenBuilding
+        );
+        Ok(())
+    }
+
+    /// Selling from `property` may not leave it more than one level behind
+    /// any sibling
+    pub fn require_even_sell(&self, property: &PropertyState) -> Result<()> {
+        let level = property.building_level();
+        require!(
+            self.levels.iter().all(|&l| l <= level),
+            BlockpolyError::UnevenSelling
+        );
+        Ok(())
+    }
+}
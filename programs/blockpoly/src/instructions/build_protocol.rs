@@ -1,11 +1,15 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::board::BOARD;
 use crate::constants::*;
 use crate::errors::BlockpolyError;
-use crate::events::ProtocolBuilt;
-use crate::state::{GameState, GameStatus, PropertyState};
+use crate::events::{BuildingAuctionWon, ProtocolBuilt};
+use crate::instructions::build_lp::SiblingBuildings;
+use crate::state::{BoardConfig, EffectSubject, GameState, PlayerState, PropertyState};
 
+/// Upgrades a property with 4 LPs to Full Protocol. remaining_accounts must
+/// hold the other PropertyStates of the colour group, as for build_lp; every
+/// sibling needs its 4 LPs first. The protocol comes out of the bank's stock
+/// and the 4 LPs go back to it.
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32], space_index: u8)]
 pub struct BuildProtocol<'info> {
@@ -13,11 +17,23 @@ pub struct BuildProtocol<'info> {
     pub player: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [SEED_GAME_STATE, &game_id],
         bump = game_state.bump,
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
+    #[account(
+        seeds = [SEED_PLAYER_STATE, &game_id, player.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.wallet == player.key() @ BlockpolyError::NotPropertyOwner,
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
     #[account(
         mut,
         seeds = [SEED_PROPERTY_STATE, &game_id, &[space_index]],
@@ -43,8 +59,8 @@ pub struct BuildProtocol<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(
-    ctx: Context<BuildProtocol>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuildProtocol<'info>>,
     game_id: [u8; 32],
     space_index: u8,
 ) -> Result<()> {
@@ -53,9 +69,23 @@ pub fn handler(
     require!(!property.is_full_protocol, BlockpolyError::MaxProtocolReached);
     require!(property.can_upgrade_protocol(), BlockpolyError::UnevenBuilding);
     require!(!property.is_mortgaged, BlockpolyError::PropertyMortgaged);
+    require!(!ctx.accounts.player_state.has_debt(), BlockpolyError::DebtOutstanding);
+
+    let space = ctx.accounts.board_config.space(space_index);
+    let siblings = SiblingBuildings::load(
+        &game_id,
+        &ctx.accounts.board_config.group_spaces(space.group),
+        space_index,
+        &property.owner,
+        ctx.remaining_accounts,
+    )?;
+    siblings.require_even_build(property)?;
 
-    let space = &BOARD[space_index as usize];
-    let lp_cost = space.lp_cost; // Protocol upgrade costs same as one LP
+    let game = &mut ctx.accounts.game_state;
+    let builder = ctx.accounts.player.key();
+    let winning_bid = game.take_building(BUILDING_PROTOCOL, builder, Clock::get()?.slot)?;
+    // Protocol upgrade costs same as one LP
+    let lp_cost = game.apply_market_effects(EffectSubject::BuildCost, space.lp_cost);
 
     let cpi_ctx = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
@@ -70,6 +100,15 @@ pub fn handler(
     property.is_full_protocol = true;
     property.liquidity_pools = 4; // keep count for sell-back
 
+    if winning_bid > 0 {
+        emit!(BuildingAuctionWon {
+            game_id,
+            winner: builder,
+            kind: BUILDING_PROTOCOL,
+            amount: winning_bid,
+        });
+    }
+
     emit!(ProtocolBuilt {
         game_id,
         player: ctx.accounts.player.key(),
@@ -1,11 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{instruction::Instruction, program::invoke};
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::board::BOARD;
 use crate::constants::*;
 use crate::errors::BlockpolyError;
 use crate::events::PropertyPurchased;
-use crate::state::{GameState, GameStatus, PlayerState, PropertyState, TurnPhase};
+use crate::state::{
+    BoardConfig, CardModifier, GameState, GameStatus, PlayerState, PropertyState, TurnPhase,
+};
 
 /// Metaplex Core program ID
 pub const MPL_CORE_PROGRAM: Pubkey = pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
@@ -23,6 +24,10 @@ pub struct BuyProperty<'info> {
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
     #[account(
         mut,
         seeds = [SEED_PLAYER_STATE, &game_id, player.key().as_ref()],
@@ -115,7 +120,7 @@ pub fn handler(
     );
     require!(space_index == player_state.position, BlockpolyError::InvalidSpaceIndex);
 
-    let space = &BOARD[space_index as usize];
+    let space = ctx.accounts.board_config.space(space_index);
     require!(
         space.space_type == SPACE_TYPE_PROPERTY
             || space.space_type == SPACE_TYPE_BRIDGE
@@ -123,8 +128,12 @@ pub fn handler(
         BlockpolyError::PropertyNotAvailable
     );
 
-    let price = space.price;
-    require!(price > 0, BlockpolyError::PropertyNotAvailable);
+    require!(space.price > 0, BlockpolyError::PropertyNotAvailable);
+    let price = if game.card_modifier == CardModifier::HalfPricePurchase {
+        space.price / 2
+    } else {
+        space.price
+    };
 
     // Transfer payment from player to bank
     let cpi_ctx = CpiContext::new(
@@ -140,7 +149,7 @@ pub fn handler(
 
     // Mint Metaplex Core NFT via raw CPI
     let uri = format!("{}/property-{}.json", NFT_BASE_URI, space_index);
-    let ix_data = build_create_v2_data(space.name, &uri);
+    let ix_data = build_create_v2_data(&space.name, &uri);
 
     let create_ix = Instruction {
         program_id: MPL_CORE_PROGRAM,
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::cards::{default_alpha_call_cards, default_governance_cards};
+use crate::constants::*;
+use crate::errors::BlockpolyError;
+use crate::events::CardDeckFinalized;
+use crate::state::{CardDeck, CardDef};
+
+/// Creates a CardDeck holding the default Alpha Call and Governance Vote
+/// cards. The signer becomes its authority and may rewrite cards with
+/// set_deck_cards until it is finalized.
+#[derive(Accounts)]
+#[instruction(deck_id: [u8; 32])]
+pub struct CreateCardDeck<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = CardDeck::MAX_SIZE,
+        seeds = [SEED_CARD_DECK, &deck_id],
+        bump
+    )]
+    pub card_deck: Account<'info, CardDeck>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Shared by set_deck_cards and finalize_card_deck
+#[derive(Accounts)]
+#[instruction(deck_id: [u8; 32])]
+pub struct EditCardDeck<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CARD_DECK, &deck_id],
+        bump = card_deck.bump,
+        has_one = authority @ BlockpolyError::NotDeckAuthority,
+    )]
+    pub card_deck: Account<'info, CardDeck>,
+}
+
+pub fn handler_create(ctx: Context<CreateCardDeck>, deck_id: [u8; 32]) -> Result<()> {
+    let deck = &mut ctx.accounts.card_deck;
+    deck.authority = ctx.accounts.authority.key();
+    deck.deck_id = deck_id;
+    deck.finalized = false;
+    deck.alpha_call = default_alpha_call_cards();
+    deck.governance = default_governance_cards();
+    deck.bump = ctx.bumps.card_deck;
+    Ok(())
+}
+
+/// Replaces the cards of `deck_type` from `start_index` on with `cards`; the
+/// deck ends after them. Decks are written in chunks because a full deck
+/// may not fit in one transaction.
+pub fn handler_set_cards(
+    ctx: Context<EditCardDeck>,
+    _deck_id: [u8; 32],
+    deck_type: u8,
+    start_index: u8,
+    cards: Vec<CardDef>,
+) -> Result<()> {
+    let deck = &mut ctx.accounts.card_deck;
+    require!(!deck.finalized, BlockpolyError::CardDeckFinalized);
+    require!(
+        deck_type == DECK_ALPHA_CALL || deck_type == DECK_GOVERNANCE,
+        BlockpolyError::InvalidCardDeck
+    );
+
+    let start = start_index as usize;
+    let existing = deck.cards_mut(deck_type);
+    require!(
+        start <= existing.len() && start + cards.len() <= MAX_DECK_CARDS,
+        BlockpolyError::InvalidCardDeck
+    );
+    for card in &cards {
+        card.validate()?;
+    }
+    existing.truncate(start);
+    existing.extend(cards);
+    Ok(())
+}
+
+/// Validates both decks and locks them; games can only be created with
+/// finalized decks.
+pub fn handler_finalize(ctx: Context<EditCardDeck>, deck_id: [u8; 32]) -> Result<()> {
+    let deck = &mut ctx.accounts.card_deck;
+    require!(!deck.finalized, BlockpolyError::CardDeckFinalized);
+
+    deck.validate()?;
+    deck.finalized = true;
+
+    emit!(CardDeckFinalized {
+        deck_id,
+        authority: deck.authority,
+        alpha_call_cards: deck.alpha_call.len() as u8,
+        governance_cards: deck.governance.len() as u8,
+    });
+    Ok(())
+}
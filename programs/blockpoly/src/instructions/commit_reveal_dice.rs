@@ -0,0 +1,314 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::BlockpolyError;
+use crate::events::{DiceCommitted, DiceRevealed, DiceRollExpired, RugPullEntered};
+use crate::instructions::consume_randomness::settle_roll;
+use crate::instructions::repay_flash_loan::default_flash_loan;
+use crate::state::{BoardConfig, DiceCommitment, DiceMode, GameState, GameStatus, PlayerState, PlayerStatus, TurnPhase};
+
+/// Oracle-free dice for DiceMode::CommitReveal games.
+/// - commit_dice: the current player opens the roll with their commitment,
+///   then every other active player commits sha256(wallet || secret). Opening
+///   it with a Flash Loan overdue defaults on the loan instead (RaiseFunds)
+/// - reveal_dice: once all have committed, each reveals their secret; the
+///   last reveal XORs them together and settles the roll
+/// - resolve_dice_timeout: after a stage deadline anyone may close it; players
+///   who failed to commit or reveal are sent to the Rug Pull Zone. A missed
+///   reveal never settles the roll on the secrets revealed so far (the last
+///   revealer could pick the outcome by withholding): the roller rolls again,
+///   or forfeits the roll if the secret withheld was their own
+#[derive(Accounts)]
+#[instruction(game_id: [u8; 32])]
+pub struct CommitDice<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GAME_STATE, &game_id],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PLAYER_STATE, &game_id, player.key().as_ref()],
+        bump = player_state.bump,
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = DiceCommitment::MAX_SIZE,
+        seeds = [SEED_DICE_COMMITMENT, &game_id, &game_state.turn_number.to_le_bytes()],
+        bump
+    )]
+    pub dice_commitment: Account<'info, DiceCommitment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: [u8; 32])]
+pub struct RevealDice<'info> {
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GAME_STATE, &game_id],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_DICE_COMMITMENT, &game_id, &game_state.turn_number.to_le_bytes()],
+        bump = dice_commitment.bump,
+    )]
+    pub dice_commitment: Account<'info, DiceCommitment>,
+
+    /// Player whose roll this is; moved once the last secret is revealed
+    #[account(
+        mut,
+        seeds = [SEED_PLAYER_STATE, &game_id, dice_commitment.roller.as_ref()],
+        bump = roller_state.bump,
+    )]
+    pub roller_state: Account<'info, PlayerState>,
+
+    /// CHECK: refunded the commitment record's rent when the roll settles
+    #[account(mut, address = dice_commitment.roller)]
+    pub roller: UncheckedAccount<'info>,
+}
+
+/// Same accounts as RevealDice with any signer; remaining_accounts must hold
+/// the PlayerState of every player who missed the deadline (roller excepted).
+#[derive(Accounts)]
+#[instruction(game_id: [u8; 32])]
+pub struct ResolveDiceTimeout<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GAME_STATE, &game_id],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_DICE_COMMITMENT, &game_id, &game_state.turn_number.to_le_bytes()],
+        bump = dice_commitment.bump,
+    )]
+    pub dice_commitment: Account<'info, DiceCommitment>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PLAYER_STATE, &game_id, dice_commitment.roller.as_ref()],
+        bump = roller_state.bump,
+    )]
+    pub roller_state: Account<'info, PlayerState>,
+
+    /// CHECK: refunded the commitment record's rent when the roll closes
+    #[account(mut, address = dice_commitment.roller)]
+    pub roller: UncheckedAccount<'info>,
+}
+
+pub fn handler_commit(
+    ctx: Context<CommitDice>,
+    game_id: [u8; 32],
+    commitment: [u8; 32],
+) -> Result<()> {
+    let game_key = ctx.accounts.game_state.key();
+    let game = &mut ctx.accounts.game_state;
+    let record = &mut ctx.accounts.dice_commitment;
+    let player = ctx.accounts.player.key();
+    let clock = Clock::get()?;
+
+    require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
+    require!(game.dice_mode == DiceMode::CommitReveal, BlockpolyError::WrongDiceMode);
+
+    if record.game == Pubkey::default() {
+        // First commitment opens the roll — only the current player may do that
+        require!(game.current_player() == Some(player), BlockpolyError::NotYourTurn);
+        require!(game.turn_phase == TurnPhase::RollDice, BlockpolyError::WrongTurnPhase);
+        require!(
+            !game.market_event_due(ctx.accounts.player_state.market_event_round),
+            BlockpolyError::MarketEventPending
+        );
+        require!(!ctx.accounts.player_state.has_debt(), BlockpolyError::DebtOutstanding);
+        if ctx.accounts.player_state.flash_loan_overdue(game.turn_number) {
+            default_flash_loan(game, &mut ctx.accounts.player_state);
+            return Ok(());
+        }
+
+        record.game = game_key;
+        record.turn_number = game.turn_number;
+        record.roller = player;
+        record.players = game.players.clone();
+        record.commitments = [[0; 32]; 8];
+        record.committed_mask = 0;
+        record.revealed_mask = 0;
+        record.seed = [0; 32];
+        record.reveal_open = false;
+        record.deadline_slot = clock.slot + DICE_COMMIT_TIMEOUT_SLOTS;
+        record.bump = ctx.bumps.dice_commitment;
+
+        game.turn_phase = TurnPhase::AwaitingVRF;
+    } else {
+        require!(game.turn_phase == TurnPhase::AwaitingVRF, BlockpolyError::WrongTurnPhase);
+        require!(!record.reveal_open, BlockpolyError::CommitStageClosed);
+    }
+
+    let slot = record.player_slot(&player).ok_or(BlockpolyError::NotInGame)?;
+    require!(!record.has_committed(slot), BlockpolyError::AlreadyCommitted);
+    record.commitments[slot] = commitment;
+    record.committed_mask |= 1 << slot;
+
+    if record.committed_mask == record.all_mask() {
+        record.reveal_open = true;
+        record.deadline_slot = clock.slot + DICE_REVEAL_TIMEOUT_SLOTS;
+    }
+
+    emit!(DiceCommitted {
+        game_id,
+        player,
+        turn_number: record.turn_number,
+    });
+
+    Ok(())
+}
+
+pub fn handler_reveal(ctx: Context<RevealDice>, game_id: [u8; 32], secret: [u8; 32]) -> Result<()> {
+    let game = &mut ctx.accounts.game_state;
+    let record = &mut ctx.accounts.dice_commitment;
+    let player = ctx.accounts.player.key();
+
+    require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
+    require!(game.turn_phase == TurnPhase::AwaitingVRF, BlockpolyError::WrongTurnPhase);
+    require!(record.reveal_open, BlockpolyError::RevealNotOpen);
+
+    let slot = record.player_slot(&player).ok_or(BlockpolyError::NotInGame)?;
+    // Players dropped at the commit deadline have nothing to reveal
+    require!(record.has_committed(slot), BlockpolyError::CommitStageClosed);
+    require!(!record.has_revealed(slot), BlockpolyError::AlreadyRevealed);
+    require!(
+        DiceCommitment::hash_secret(&player, &secret) == record.commitments[slot],
+        BlockpolyError::InvalidReveal
+    );
+
+    for (acc, byte) in record.seed.iter_mut().zip(secret.iter()) {
+        *acc ^= byte;
+    }
+    record.revealed_mask |= 1 << slot;
+
+    emit!(DiceRevealed {
+        game_id,
+        player,
+        turn_number: record.turn_number,
+    });
+
+    if record.revealed_mask == record.committed_mask {
+        let seed = record.seed;
+        settle_roll(game, &ctx.accounts.board_config, &mut ctx.accounts.roller_state, game_id, &seed)?;
+        record.close(ctx.accounts.roller.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+pub fn handler_timeout<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ResolveDiceTimeout<'info>>,
+    game_id: [u8; 32],
+) -> Result<()> {
+    let game = &mut ctx.accounts.game_state;
+    let record = &mut ctx.accounts.dice_commitment;
+    let roller_state = &mut ctx.accounts.roller_state;
+    let clock = Clock::get()?;
+
+    require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
+    require!(game.turn_phase == TurnPhase::AwaitingVRF, BlockpolyError::WrongTurnPhase);
+    require!(clock.slot > record.deadline_slot, BlockpolyError::DiceTimeoutNotReached);
+
+    // Commit stage: whoever did not commit; reveal stage: whoever committed but did not reveal
+    let offenders = if record.reveal_open {
+        record.committed_mask & !record.revealed_mask
+    } else {
+        record.all_mask() & !record.committed_mask
+    };
+
+    let mut roller_offended = false;
+    for (slot, wallet) in record.players.iter().enumerate() {
+        if offenders & (1 << slot) == 0 {
+            continue;
+        }
+        if *wallet == record.roller {
+            roller_offended = true;
+            continue;
+        }
+        let (expected, _) = Pubkey::find_program_address(
+            &[SEED_PLAYER_STATE, &game_id, wallet.as_ref()],
+            &crate::ID,
+        );
+        let info = ctx
+            .remaining_accounts
+            .iter()
+            .find(|acc| acc.key() == expected)
+            .ok_or(BlockpolyError::MissingOffenderAccounts)?;
+        let mut offender: Account<'info, PlayerState> = Account::try_from(info)?;
+        send_to_rugpull(&mut offender, game.rules.rugpull_max_turns);
+        offender.exit(&crate::ID)?;
+
+        emit!(RugPullEntered {
+            game_id,
+            player: *wallet,
+            reason: 3, // missed dice commit/reveal
+        });
+    }
+
+    if !record.reveal_open {
+        // The roller committed when opening the roll, so someone is left to reveal
+        record.reveal_open = true;
+        record.deadline_slot = clock.slot + DICE_REVEAL_TIMEOUT_SLOTS;
+        return Ok(());
+    }
+
+    if roller_offended {
+        // The roller refused to reveal their own roll: jail them and end the turn
+        send_to_rugpull(roller_state, game.rules.rugpull_max_turns);
+        game.advance_turn();
+
+        emit!(RugPullEntered {
+            game_id,
+            player: roller_state.wallet,
+            reason: 3,
+        });
+    } else {
+        // Roll again with fresh commitments rather than settle on a partial XOR
+        game.turn_phase = TurnPhase::RollDice;
+    }
+
+    emit!(DiceRollExpired {
+        game_id,
+        player: roller_state.wallet,
+        forfeited: roller_offended,
+    });
+
+    record.close(ctx.accounts.roller.to_account_info())?;
+    Ok(())
+}
+
+pub(crate) fn send_to_rugpull(player: &mut PlayerState, max_turns: u8) {
+    player.position = SPACE_RUGPULL_ZONE;
+    player.rugpull_turns_remaining = max_turns;
+    player.status = PlayerStatus::InRugPullZone;
+    player.doubles_streak = 0;
+}
@@ -1,17 +1,19 @@
 use anchor_lang::prelude::*;
-use crate::board::BOARD;
 use crate::constants::*;
 use crate::errors::BlockpolyError;
-use crate::events::{DiceRolled, RugPullEntered};
-use crate::state::{GameState, GameStatus, PlayerState, PlayerStatus, TurnPhase};
-
-/// Called by the Switchboard oracle (or test mock) to fulfill VRF randomness.
-/// Derives dice from the random bytes, moves the player, and transitions phase.
+use crate::events::{DiceRolled, RugPullEntered, RugPullExited};
+use crate::state::{BoardConfig, GameState, GameStatus, PlayerState, PlayerStatus, TurnPhase};
+use crate::switchboard::{default_randomness_authority, RandomnessAccountData};
+
+/// Settles the pending roll once the oracle has revealed the randomness
+/// committed in request_dice_roll, in the reveal's transaction or any later
+/// one. Derives dice from the revealed value, moves the player, and
+/// transitions phase. A roll left unrevealed is closed by expire_dice_roll.
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32])]
 pub struct ConsumeRandomness<'info> {
-    /// In production: must be the Switchboard oracle or VRF callback signer.
-    /// For the hackathon: accept the current player (simplified auth).
+    /// Must be game_state.randomness_authority when the game has a keeper;
+    /// any signer may crank games on the default authority
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -19,22 +21,33 @@ pub struct ConsumeRandomness<'info> {
         mut,
         seeds = [SEED_GAME_STATE, &game_id],
         bump = game_state.bump,
+        constraint = game_state.randomness_authority == authority.key()
+            || game_state.randomness_authority == default_randomness_authority()
+            @ BlockpolyError::UnauthorizedRandomnessAuthority,
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
     #[account(
         mut,
         seeds = [SEED_PLAYER_STATE, &game_id, player_state.wallet.as_ref()],
         bump = player_state.bump,
     )]
     pub player_state: Account<'info, PlayerState>,
+
+    /// Randomness account committed in request_dice_roll
+    /// CHECK: must match game_state.vrf_request; layout verified on load
+    #[account(
+        constraint = game_state.vrf_request == Some(randomness_account.key()) @ BlockpolyError::VRFNotRequested,
+    )]
+    pub randomness_account: UncheckedAccount<'info>,
 }
 
-pub fn handler(
-    ctx: Context<ConsumeRandomness>,
-    game_id: [u8; 32],
-    random_bytes: [u8; 32],
-) -> Result<()> {
+pub fn handler(ctx: Context<ConsumeRandomness>, game_id: [u8; 32]) -> Result<()> {
+    let randomness = RandomnessAccountData::load(&ctx.accounts.randomness_account)?;
     let game = &mut ctx.accounts.game_state;
     let player = &mut ctx.accounts.player_state;
 
@@ -44,21 +57,89 @@ pub fn handler(
         game.current_player_index == player.player_index,
         BlockpolyError::NotYourTurn
     );
+    // A re-commit after request_dice_roll would let the player reroll
+    require!(
+        randomness.seed_slot == game.vrf_seed_slot,
+        BlockpolyError::RandomnessNotCommitted
+    );
+    let random_bytes = randomness.get_value()?;
+
+    settle_roll(game, &ctx.accounts.board_config, player, game_id, &random_bytes)
+}
+
+/// Turns 32 random bytes into the current player's roll: moves them and
+/// moves the game to LandingEffect. Shared by both dice modes.
+/// A player in the Rug Pull Zone only moves on doubles or on their last attempt.
+pub(crate) fn settle_roll(
+    game: &mut GameState,
+    board: &BoardConfig,
+    player: &mut PlayerState,
+    game_id: [u8; 32],
+    random_bytes: &[u8; 32],
+) -> Result<()> {
+    // Bytes 8..16 are unused by the dice; keep them for the next market event draw
+    let mut entropy = [0u8; 8];
+    entropy.copy_from_slice(&random_bytes[8..16]);
+    game.last_roll_entropy = u64::from_le_bytes(entropy);
 
     // Derive dice: 1–6 using modulo
     let die1 = (random_bytes[0] % 6) + 1;
     let die2 = (random_bytes[1] % 6) + 1;
     let roll_total = die1 + die2;
     let is_doubles = die1 == die2;
-
-    // Check for triple doubles → SEC Investigation (jail)
-    if is_doubles {
+    // Doubles earn another roll once the landing resolves, unless they end in jail
+    // or were used to leave the Rug Pull Zone
+    let mut rolls_again = false;
+    game.extra_roll = false;
+
+    if player.is_in_rugpull() {
+        if is_doubles {
+            // Rolled out of the Rug Pull Zone
+            player.rugpull_turns_remaining = 0;
+            player.status = PlayerStatus::Active;
+            emit!(RugPullExited {
+                game_id,
+                player: player.wallet,
+                method: 2, // doubles
+            });
+        } else {
+            player.rugpull_turns_remaining -= 1;
+            if player.rugpull_turns_remaining > 0 {
+                // Failed attempt: stay put; resolve_landing on the zone ends the turn
+                game.turn_phase = TurnPhase::LandingEffect;
+                game.vrf_request = None;
+                game.pending_dice = Some([die1, die2]);
+
+                emit!(DiceRolled {
+                    game_id,
+                    player: player.wallet,
+                    die1,
+                    die2,
+                    new_position: player.position,
+                    passed_genesis: false,
+                });
+                return Ok(());
+            }
+            // Third failure: released, but bail is collected in resolve_landing
+            // (the player signs there) before the landing resolves
+            player.status = PlayerStatus::Active;
+            player.rugpull_bail_due = true;
+            emit!(RugPullExited {
+                game_id,
+                player: player.wallet,
+                method: 0, // bail
+            });
+        }
+        // Leaving the zone on doubles does not count toward a streak
+        player.doubles_streak = 0;
+    } else if is_doubles {
+        // Check for triple doubles → SEC Investigation (jail)
         player.doubles_streak += 1;
         if player.doubles_streak >= 3 {
             // Triple doubles: go directly to Rug Pull Zone
             player.doubles_streak = 0;
             player.position = SPACE_RUGPULL_ZONE;
-            player.rugpull_turns_remaining = RUGPULL_MAX_TURNS;
+            player.rugpull_turns_remaining = game.rules.rugpull_max_turns;
             player.status = PlayerStatus::InRugPullZone;
 
             game.turn_phase = TurnPhase::LandingEffect;
@@ -80,6 +161,7 @@ pub fn handler(
             });
             return Ok(());
         }
+        rolls_again = true;
     } else {
         player.doubles_streak = 0;
     }
@@ -87,18 +169,16 @@ pub fn handler(
     // Move player
     let old_position = player.position;
     let new_position = (old_position + roll_total) % BOARD_SIZE;
-    let passed_genesis = new_position < old_position || (old_position == 0 && roll_total > 0);
-
-    player.position = new_position;
+    player.advance_to(new_position);
 
     // Collect Genesis Block salary if passed GO
     // Actual token transfer happens in resolve_landing to keep this composable
     let actually_passed = new_position < old_position;
 
     // Check if landed on SEC Investigation (Go To Jail)
-    if BOARD[new_position as usize].space_type == SPACE_TYPE_GO_TO_JAIL {
+    if board.space(new_position).space_type == SPACE_TYPE_GO_TO_JAIL {
         player.position = SPACE_RUGPULL_ZONE;
-        player.rugpull_turns_remaining = RUGPULL_MAX_TURNS;
+        player.rugpull_turns_remaining = game.rules.rugpull_max_turns;
         player.status = PlayerStatus::InRugPullZone;
         player.doubles_streak = 0;
 
@@ -125,6 +205,7 @@ pub fn handler(
     game.turn_phase = TurnPhase::LandingEffect;
     game.vrf_request = None;
     game.pending_dice = Some([die1, die2]);
+    game.extra_roll = rolls_again;
 
     emit!(DiceRolled {
         game_id,
@@ -1,9 +1,21 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::constants::*;
 use crate::errors::BlockpolyError;
 use crate::events::PlayerBankrupted;
-use crate::state::{GameState, GameStatus, PlayerState, PlayerStatus};
+use crate::instructions::resolve_card::burn_property_nft;
+use crate::state::{GameState, GameStatus, PlayerState, PlayerStatus, PropertyState};
 
+/// Takes a player out of the game and hands their estate to the creditor of
+/// their debt: the BPOLY left in their ATA and every property they own. With
+/// no debt, or a debt to the bank, the bank takes it and the properties go
+/// back up for sale.
+///
+/// remaining_accounts must hold the PropertyState of every space in
+/// properties_owned, in that order; their buildings are liquidated back to the
+/// bank's stock. When the bank takes the estate they are followed by
+/// [collection, mpl-core program] and the NFT asset of each property that has
+/// one, in the same order.
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32])]
 pub struct DeclareBankruptcy<'info> {
@@ -24,12 +36,29 @@ pub struct DeclareBankruptcy<'info> {
         constraint = player_state.wallet == player.key() @ BlockpolyError::NotYourTurn,
     )]
     pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_state.bpoly_mint,
+        associated_token::authority = player,
+    )]
+    pub player_bpoly_ata: Account<'info, TokenAccount>,
+
+    /// PlayerState of the creditor; required when the debt is owed to a player
+    #[account(mut)]
+    pub creditor_state: Option<Account<'info, PlayerState>>,
+
+    /// The creditor's BPOLY ATA, or the bank's when the bank takes the
+    /// estate; verified in the handler
+    #[account(mut)]
+    pub creditor_bpoly_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(
-    ctx: Context<DeclareBankruptcy>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DeclareBankruptcy<'info>>,
     game_id: [u8; 32],
-    creditor: Option<Pubkey>,
 ) -> Result<()> {
     let game = &mut ctx.accounts.game_state;
     let player = &mut ctx.accounts.player_state;
@@ -37,16 +66,118 @@ pub fn handler(
     require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
     require!(!player.is_bankrupt, BlockpolyError::PlayerBankrupt);
 
+    // Whoever the player owed takes the estate; no debt means the bank does
+    let creditor = if player.has_debt() { player.debt_creditor } else { None };
+    player.clear_debt();
+
+    let mut creditor_state = match creditor {
+        Some(creditor) => {
+            let (expected, _) = Pubkey::find_program_address(
+                &[SEED_PLAYER_STATE, &game_id, creditor.as_ref()],
+                &crate::ID,
+            );
+            let state = ctx
+                .accounts
+                .creditor_state
+                .as_mut()
+                .ok_or(BlockpolyError::InvalidCreditorAccount)?;
+            require_keys_eq!(state.key(), expected, BlockpolyError::InvalidCreditorAccount);
+            require!(
+                ctx.accounts.creditor_bpoly_ata.owner == creditor
+                    && ctx.accounts.creditor_bpoly_ata.mint == game.bpoly_mint,
+                BlockpolyError::InvalidCreditorAccount
+            );
+            Some(state)
+        }
+        None => {
+            require_keys_eq!(
+                ctx.accounts.creditor_bpoly_ata.key(),
+                game.bank_bpoly_ata,
+                BlockpolyError::InvalidCreditorAccount
+            );
+            None
+        }
+    };
+
     player.is_bankrupt = true;
     player.status = PlayerStatus::Bankrupt;
 
+    // A held Get Out of Rug Pull Free card goes back under its deck
+    if let Some(deck_type) = player.take_jail_free_card() {
+        game.return_held_card(deck_type);
+    }
+
+    // The BPOLY left goes to the creditor
+    let balance = ctx.accounts.player_bpoly_ata.amount;
+    if balance > 0 {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player_bpoly_ata.to_account_info(),
+                to: ctx.accounts.creditor_bpoly_ata.to_account_info(),
+                authority: ctx.accounts.player.to_account_info(),
+            },
+        );
+        token::transfer(cpi_ctx, balance)?;
+    }
+    player.bpoly_balance = 0;
+    if let Some(state) = creditor_state.as_mut() {
+        state.bpoly_balance = state.bpoly_balance.saturating_add(balance);
+    }
+
+    // And so do the properties, without their buildings
+    let owned = std::mem::take(&mut player.properties_owned);
+    require!(
+        ctx.remaining_accounts.len() >= owned.len(),
+        BlockpolyError::InvalidHoldingsAccounts
+    );
+    let (holdings, nft_accounts) = ctx.remaining_accounts.split_at(owned.len());
+    let mut nft_assets = nft_accounts.iter().skip(2);
+    for (&space, info) in owned.iter().zip(holdings.iter()) {
+        let (expected, _) = Pubkey::find_program_address(
+            &[SEED_PROPERTY_STATE, &game_id, &[space]],
+            &crate::ID,
+        );
+        require_keys_eq!(info.key(), expected, BlockpolyError::InvalidHoldingsAccounts);
+
+        let mut property: Account<'info, PropertyState> = Account::try_from(info)?;
+        require_keys_eq!(property.owner, player.wallet, BlockpolyError::InvalidHoldingsAccounts);
+        if property.has_buildings() {
+            game.return_buildings_of(&property);
+            property.liquidity_pools = 0;
+            property.is_full_protocol = false;
+        }
+
+        match creditor_state.as_mut() {
+            Some(state) => {
+                property.owner = state.wallet;
+                state.add_property(space);
+                property.exit(&crate::ID)?;
+            }
+            None => {
+                if property.nft_asset != Pubkey::default() {
+                    let [collection, mpl_core_program, ..] = nft_accounts else {
+                        return err!(BlockpolyError::InvalidHoldingsAccounts);
+                    };
+                    let asset = nft_assets.next().ok_or(BlockpolyError::InvalidHoldingsAccounts)?;
+                    burn_property_nft(
+                        game,
+                        &property,
+                        asset,
+                        collection,
+                        mpl_core_program,
+                        &ctx.accounts.player,
+                    )?;
+                }
+                property.close(ctx.accounts.player.to_account_info())?;
+            }
+        }
+    }
+
     // Remove from active players
     game.players.retain(|&p| p != ctx.accounts.player.key());
     game.player_count -= 1;
 
-    // NOTE: Property transfers to creditor/bank happen via remaining_accounts iteration.
-    // Each PropertyState in remaining_accounts gets owner = creditor (or cleared if bank).
-
     emit!(PlayerBankrupted {
         game_id,
         player: ctx.accounts.player.key(),
@@ -59,7 +190,8 @@ pub fn handler(
         game.status = GameStatus::Finished;
     } else {
         // Skip to next player's turn
-        game.current_player_index = game.current_player_index % game.player_count;
+        game.current_player_index %= game.player_count;
+        game.extra_roll = false;
         game.advance_turn();
     }
 
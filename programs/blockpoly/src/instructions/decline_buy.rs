@@ -1,9 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::board::BOARD;
 use crate::constants::*;
 use crate::errors::BlockpolyError;
 use crate::events::AuctionStarted;
-use crate::state::{GameState, GameStatus, PlayerState, TurnPhase};
+use crate::state::{BoardConfig, GameState, GameStatus, PlayerState, TurnPhase};
 
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32])]
@@ -18,6 +17,10 @@ pub struct DeclineBuy<'info> {
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
     #[account(
         seeds = [SEED_PLAYER_STATE, &game_id, player.key().as_ref()],
         bump = player_state.bump,
@@ -38,13 +41,18 @@ pub fn handler(ctx: Context<DeclineBuy>, game_id: [u8; 32]) -> Result<()> {
     );
 
     let space = player_state.position;
-    let starting_bid = BOARD[space as usize].price / 10; // starting bid = 10% of price
+    if !game.rules.auction_on_decline {
+        // House rule: no auction, the space stays with the bank
+        game.advance_turn();
+        return Ok(());
+    }
+    let starting_bid = ctx.accounts.board_config.space(space).price / 10; // starting bid = 10% of price
 
     // Set up auction in GameState
     game.auction_space = Some(space);
     game.auction_highest_bid = starting_bid;
     game.auction_highest_bidder = None;
-    game.auction_end_turn = game.turn_number + AUCTION_DURATION_TURNS;
+    game.auction_end_turn = game.turn_number + game.rules.auction_duration_turns;
     game.turn_phase = TurnPhase::AuctionPhase;
 
     emit!(AuctionStarted {
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::BlockpolyError;
 use crate::events::CardDrawn;
-use crate::state::{GameState, GameStatus, PlayerState, TurnPhase};
+use crate::state::{BoardConfig, GameState, GameStatus, PlayerState, TurnPhase};
 
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32])]
@@ -17,6 +17,10 @@ pub struct DrawCard<'info> {
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
     #[account(
         seeds = [SEED_PLAYER_STATE, &game_id, player.key().as_ref()],
         bump = player_state.bump,
@@ -37,16 +41,16 @@ pub fn handler(ctx: Context<DrawCard>, game_id: [u8; 32]) -> Result<(u8, u8)> {
     );
 
     let position = player_state.position;
-    let space = &crate::board::BOARD[position as usize];
+    let space = ctx.accounts.board_config.space(position);
 
     let (deck_type, card_id) = match space.space_type {
         SPACE_TYPE_CARD_ALPHA => {
             let card = game.next_alpha_card();
-            (0u8, card)
+            (DECK_ALPHA_CALL, card)
         }
         SPACE_TYPE_CARD_GOVERNANCE => {
             let card = game.next_governance_card();
-            (1u8, card)
+            (DECK_GOVERNANCE, card)
         }
         _ => return Err(BlockpolyError::WrongTurnPhase.into()),
     };
@@ -58,9 +62,8 @@ pub fn handler(ctx: Context<DrawCard>, game_id: [u8; 32]) -> Result<(u8, u8)> {
         card_id,
     });
 
-    // Store the drawn card in pending_dice as a hack-free approach
-    // We repurpose pending_dice[0] = deck_type, [1] = card_id for resolve_card
-    game.pending_dice = Some([deck_type, card_id]);
+    // resolve_card resolves exactly this card
+    game.drawn_card = Some([deck_type, card_id]);
 
     Ok((deck_type, card_id))
 }
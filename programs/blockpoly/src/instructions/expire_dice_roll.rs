@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::BlockpolyError;
+use crate::events::{DiceRollExpired, RugPullEntered};
+use crate::instructions::commit_reveal_dice::send_to_rugpull;
+use crate::state::{DiceMode, GameState, GameStatus, PlayerState, TurnPhase};
+use crate::switchboard::RandomnessAccountData;
+
+/// Closes an oracle roll still unsettled VRF_TIMEOUT_SLOTS after its commit.
+/// Anyone may call it. A revealed value must be settled with
+/// consume_randomness instead, so nobody can drop a roll they dislike. If the
+/// oracle never revealed, the roller is back in RollDice to request again;
+/// if the roller re-committed the randomness account behind the game's back,
+/// they forfeit the roll to the Rug Pull Zone and the turn ends.
+#[derive(Accounts)]
+#[instruction(game_id: [u8; 32])]
+pub struct ExpireDiceRoll<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GAME_STATE, &game_id],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    /// The current player
+    #[account(
+        mut,
+        seeds = [SEED_PLAYER_STATE, &game_id, roller_state.wallet.as_ref()],
+        bump = roller_state.bump,
+        constraint = roller_state.player_index == game_state.current_player_index
+            @ BlockpolyError::NotYourTurn,
+    )]
+    pub roller_state: Account<'info, PlayerState>,
+
+    /// Randomness account committed in request_dice_roll
+    /// CHECK: must match game_state.vrf_request; layout verified on load
+    #[account(
+        constraint = game_state.vrf_request == Some(randomness_account.key()) @ BlockpolyError::VRFNotRequested,
+    )]
+    pub randomness_account: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ExpireDiceRoll>, game_id: [u8; 32]) -> Result<()> {
+    let randomness = RandomnessAccountData::load(&ctx.accounts.randomness_account)?;
+    let game = &mut ctx.accounts.game_state;
+    let roller = &mut ctx.accounts.roller_state;
+
+    require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
+    require!(game.dice_mode == DiceMode::Oracle, BlockpolyError::WrongDiceMode);
+    require!(game.turn_phase == TurnPhase::AwaitingVRF, BlockpolyError::WrongTurnPhase);
+    require!(
+        Clock::get()?.slot > game.vrf_seed_slot + VRF_TIMEOUT_SLOTS,
+        BlockpolyError::DiceTimeoutNotReached
+    );
+
+    let forfeited = randomness.seed_slot != game.vrf_seed_slot;
+    require!(
+        forfeited || !randomness.is_revealed(),
+        BlockpolyError::RandomnessRevealed
+    );
+
+    game.vrf_request = None;
+    if forfeited {
+        send_to_rugpull(roller, game.rules.rugpull_max_turns);
+        game.advance_turn();
+
+        emit!(RugPullEntered {
+            game_id,
+            player: roller.wallet,
+            reason: 3,
+        });
+    } else {
+        game.turn_phase = TurnPhase::RollDice;
+    }
+
+    emit!(DiceRollExpired {
+        game_id,
+        player: roller.wallet,
+        forfeited,
+    });
+
+    Ok(())
+}
@@ -9,7 +9,7 @@ use crate::state::{GameState, GameStatus, PlayerState, PlayerStatus, TurnPhase};
 /// - pay_bail: pay 50 BPOLY to exit
 /// - use_jail_free_card: use held GORPF card
 /// - attempt_doubles: declared when rolling — if doubles, exit; else stay
-///   (this instruction covers the bail scenario; doubles are handled in request_dice_roll)
+///   (the roll itself is settled in consume_randomness / reveal_dice)
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32])]
 pub struct HandleRugPull<'info> {
@@ -78,8 +78,8 @@ pub fn handler_pay_bail(ctx: Context<HandleRugPull>, game_id: [u8; 32]) -> Resul
             authority: ctx.accounts.player.to_account_info(),
         },
     );
-    token::transfer(cpi_ctx, RUGPULL_BAIL_AMOUNT)?;
-    player.bpoly_balance = player.bpoly_balance.saturating_sub(RUGPULL_BAIL_AMOUNT);
+    token::transfer(cpi_ctx, game.rules.rugpull_bail)?;
+    player.bpoly_balance = player.bpoly_balance.saturating_sub(game.rules.rugpull_bail);
 
     player.rugpull_turns_remaining = 0;
     player.status = PlayerStatus::Active;
@@ -106,10 +106,9 @@ pub fn handler_use_jail_free_card(ctx: Context<HandleRugPull>, game_id: [u8; 32]
         BlockpolyError::NotYourTurn
     );
     require!(player.is_in_rugpull(), BlockpolyError::NotInRugPullZone);
-    require!(player.has_jail_free_card, BlockpolyError::NoJailFreeCard);
+    let deck_type = player.take_jail_free_card().ok_or(BlockpolyError::NoJailFreeCard)?;
+    game.return_held_card(deck_type);
 
-    player.has_jail_free_card = false;
-    player.jail_free_card_type = 0;
     player.rugpull_turns_remaining = 0;
     player.status = PlayerStatus::Active;
 
@@ -125,9 +124,10 @@ pub fn handler_use_jail_free_card(ctx: Context<HandleRugPull>, game_id: [u8; 32]
 }
 
 /// Called when a player in Rug Pull Zone has their turn and chooses to attempt doubles.
-/// This sets up the turn phase to RollDice; the consume_randomness callback will check
-/// if doubles were rolled and either exit or decrement the turn counter.
-pub fn handler_attempt_doubles(ctx: Context<HandleRugPull>, game_id: [u8; 32]) -> Result<()> {
+/// This sets up the turn phase to RollDice; settling the roll checks for doubles:
+/// doubles exit and move, otherwise the counter drops and the player stays, and the
+/// third failure releases the player with the RuleSet bail owed in resolve_landing.
+pub fn handler_attempt_doubles(ctx: Context<HandleRugPull>, _game_id: [u8; 32]) -> Result<()> {
     let game = &mut ctx.accounts.game_state;
     let player = &ctx.accounts.player_state;
 
@@ -137,6 +137,10 @@ pub fn handler_attempt_doubles(ctx: Context<HandleRugPull>, game_id: [u8; 32]) -
         BlockpolyError::NotYourTurn
     );
     require!(player.is_in_rugpull(), BlockpolyError::NotInRugPullZone);
+    require!(
+        game.turn_phase == TurnPhase::RugPullDecision || game.turn_phase == TurnPhase::RollDice,
+        BlockpolyError::WrongTurnPhase
+    );
 
     // Proceed to roll — consume_randomness handles the doubles check
     game.turn_phase = TurnPhase::RollDice;
@@ -4,7 +4,8 @@ use anchor_spl::token::{Mint, Token, TokenAccount};
 use crate::constants::*;
 use crate::errors::BlockpolyError;
 use crate::events::GameCreated;
-use crate::state::{GameState, GameStatus, TurnPhase};
+use crate::state::{BoardConfig, CardDeck, CardModifier, DiceMode, GameState, GameStatus, RuleSet, TurnPhase};
+use crate::switchboard::default_randomness_authority;
 
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32], max_players: u8)]
@@ -21,6 +22,14 @@ pub struct InitializeGame<'info> {
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// Finalized board the game is played on
+    #[account(constraint = board_config.finalized @ BlockpolyError::BoardConfigNotFinalized)]
+    pub board_config: Account<'info, BoardConfig>,
+
+    /// Finalized cards the game draws from
+    #[account(constraint = card_deck.finalized @ BlockpolyError::CardDeckNotFinalized)]
+    pub card_deck: Account<'info, CardDeck>,
+
     /// BPOLY SPL token mint (must be pre-created)
     pub bpoly_mint: Account<'info, Mint>,
 
@@ -47,17 +56,25 @@ pub struct InitializeGame<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<InitializeGame>,
     game_id: [u8; 32],
     max_players: u8,
     entry_fee_lamports: u64,
     nft_collection: Pubkey,
+    // Keeper that fulfils dice rolls; None = anyone may crank them
+    randomness_authority: Option<Pubkey>,
+    dice_mode: DiceMode,
+    // House rules; None = standard rules
+    rules: Option<RuleSet>,
 ) -> Result<()> {
     require!(
-        max_players >= 2 && max_players <= MAX_PLAYERS,
+        (2..=MAX_PLAYERS).contains(&max_players),
         BlockpolyError::InvalidPlayerCount
     );
+    let rules = rules.unwrap_or_default();
+    rules.validate()?;
 
     let game = &mut ctx.accounts.game_state;
     game.game_id = game_id;
@@ -70,25 +87,43 @@ pub fn handler(
     game.players = Vec::new();
     game.player_count = 0;
     game.max_players = max_players;
+    game.dice_mode = dice_mode;
+    game.rules = rules;
     game.vrf_request = None;
+    game.vrf_seed_slot = 0;
+    game.randomness_authority = randomness_authority.unwrap_or_else(default_randomness_authority);
     game.pending_dice = None;
-    game.alpha_call_deck = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    game.extra_roll = false;
+    // Unshuffled until start_game
+    let cards = &ctx.accounts.card_deck;
+    game.alpha_call_deck = (0..cards.alpha_call.len() as u8).collect();
     game.alpha_call_index = 0;
-    game.governance_deck = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    game.governance_deck = (0..cards.governance.len() as u8).collect();
     game.governance_index = 0;
-    game.bull_run_active = false;
-    game.bull_run_ends_round = 0;
+    game.drawn_card = None;
+    game.held_cards = Vec::new();
+    game.market_effects = Vec::new();
+    game.last_roll_entropy = 0;
+    game.market_event = None;
+    game.market_event_round = 0;
+    game.market_event_started = false;
     game.auction_space = None;
     game.auction_highest_bid = 0;
     game.auction_highest_bidder = None;
     game.auction_end_turn = 0;
+    game.lps_available = STARTING_LPS;
+    game.protocols_available = STARTING_PROTOCOLS;
+    game.building_auction = None;
     game.prize_pool_lamports = entry_fee_lamports;
     game.winner = None;
     game.nft_collection = nft_collection;
     game.bpoly_mint = ctx.accounts.bpoly_mint.key();
     game.bank_bpoly_ata = ctx.accounts.bank_bpoly_ata.key();
+    game.board_config = ctx.accounts.board_config.key();
+    game.card_deck = ctx.accounts.card_deck.key();
     game.last_rent_payer = None;
     game.last_rent_amount = 0;
+    game.card_modifier = CardModifier::None;
     game.bump = ctx.bumps.game_state;
 
     emit!(GameCreated {
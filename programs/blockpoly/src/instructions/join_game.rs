@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token::{self, Approve, Mint, Token, TokenAccount, Transfer};
 use crate::constants::*;
 use crate::errors::BlockpolyError;
 use crate::events::PlayerJoined;
@@ -40,7 +40,7 @@ pub struct JoinGame<'info> {
     #[account(address = game_state.bpoly_mint)]
     pub bpoly_mint: Account<'info, Mint>,
 
-    /// Bank vault PDA (signs BPOLY transfer)
+    /// Bank vault PDA (signs BPOLY transfer, approved as the player's delegate)
     /// CHECK: seeds verified
     #[account(
         seeds = [SEED_BANK_VAULT, &game_id],
@@ -80,8 +80,10 @@ pub fn handler(ctx: Context<JoinGame>, game_id: [u8; 32]) -> Result<()> {
     ps.player_index = player_index;
     ps.status = PlayerStatus::Active;
     ps.position = 0;
+    ps.passed_genesis_pending = false;
     ps.doubles_streak = 0;
     ps.rugpull_turns_remaining = 0;
+    ps.rugpull_bail_due = false;
     ps.has_jail_free_card = false;
     ps.jail_free_card_type = 0;
     ps.properties_owned = Vec::new();
@@ -89,7 +91,11 @@ pub fn handler(ctx: Context<JoinGame>, game_id: [u8; 32]) -> Result<()> {
     ps.flash_loan_repay_amount = 0;
     ps.flash_loan_due_turn = 0;
     ps.is_bankrupt = false;
-    ps.bpoly_balance = STARTING_BALANCE;
+    ps.bpoly_balance = game.rules.starting_balance;
+    ps.debt_amount = 0;
+    ps.debt_creditor = None;
+    ps.debt_reason = DEBT_REASON_NONE;
+    ps.market_event_round = 0;
     ps.bump = ctx.bumps.player_state;
 
     // Transfer starting balance from bank to player
@@ -105,7 +111,19 @@ pub fn handler(ctx: Context<JoinGame>, game_id: [u8; 32]) -> Result<()> {
         },
         signer_seeds,
     );
-    token::transfer(cpi_ctx, STARTING_BALANCE)?;
+    token::transfer(cpi_ctx, game.rules.starting_balance)?;
+
+    // The bank vault stands as delegate over the player's BPOLY, so card shares
+    // owed to other players and offered trade BPOLY move without their signature
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Approve {
+            to: ctx.accounts.player_bpoly_ata.to_account_info(),
+            delegate: ctx.accounts.bank_vault.to_account_info(),
+            authority: ctx.accounts.player.to_account_info(),
+        },
+    );
+    token::approve(cpi_ctx, u64::MAX)?;
 
     emit!(PlayerJoined {
         game_id,
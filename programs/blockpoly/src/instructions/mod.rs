@@ -1,18 +1,30 @@
+// Every instruction module exposes its own `handler`; lib.rs calls them by path.
+#![allow(ambiguous_glob_reexports)]
+
+pub mod board_config;
+pub mod card_deck;
 pub mod initialize_game;
 pub mod join_game;
 pub mod start_game;
 pub mod request_dice_roll;
 pub mod consume_randomness;
+pub mod expire_dice_roll;
+pub mod commit_reveal_dice;
 pub mod resolve_landing;
 pub mod buy_property;
 pub mod decline_buy;
 pub mod auction_bid;
 pub mod pay_rent;
+pub mod settle_debt;
 pub mod build_lp;
 pub mod build_protocol;
 pub mod sell_lp;
+pub mod sell_protocol;
+pub mod bid_building;
 pub mod draw_card;
 pub mod resolve_card;
+pub mod repay_flash_loan;
+pub mod apply_market_event;
 pub mod handle_rugpull;
 pub mod mortgage_property;
 pub mod unmortgage_property;
@@ -24,21 +36,30 @@ pub mod claim_prize;
 pub mod delegate_game;
 pub mod undelegate_game;
 
+pub use board_config::*;
+pub use card_deck::*;
 pub use initialize_game::*;
 pub use join_game::*;
 pub use start_game::*;
 pub use request_dice_roll::*;
 pub use consume_randomness::*;
+pub use expire_dice_roll::*;
+pub use commit_reveal_dice::*;
 pub use resolve_landing::*;
 pub use buy_property::*;
 pub use decline_buy::*;
 pub use auction_bid::*;
 pub use pay_rent::*;
+pub use settle_debt::*;
 pub use build_lp::*;
 pub use build_protocol::*;
 pub use sell_lp::*;
+pub use sell_protocol::*;
+pub use bid_building::*;
 pub use draw_card::*;
 pub use resolve_card::*;
+pub use repay_flash_loan::*;
+pub use apply_market_event::*;
 pub use handle_rugpull::*;
 pub use mortgage_property::*;
 pub use unmortgage_property::*;
@@ -1,10 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::board::BOARD;
 use crate::constants::*;
 use crate::errors::BlockpolyError;
 use crate::events::PropertyMortgaged;
-use crate::state::{GameState, GameStatus, PlayerState, PropertyState};
+use crate::state::{BoardConfig, GameState, GameStatus, PlayerState, PropertyState};
 
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32], space_index: u8)]
@@ -18,6 +17,10 @@ pub struct MortgageProperty<'info> {
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
     #[account(
         seeds = [SEED_PLAYER_STATE, &game_id, player.key().as_ref()],
         bump = player_state.bump,
@@ -63,7 +66,7 @@ pub fn handler(
     require!(!property.is_mortgaged, BlockpolyError::PropertyMortgaged);
     require!(!property.has_buildings(), BlockpolyError::CannotMortgageWithBuildings);
 
-    let space = &BOARD[space_index as usize];
+    let space = ctx.accounts.board_config.space(space_index);
     let mortgage_value = space.mortgage_value;
 
     // Bank pays player the mortgage value
@@ -1,11 +1,18 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::board::{BOARD, group_spaces};
+use anchor_spl::token::{Token, TokenAccount};
 use crate::constants::*;
 use crate::errors::BlockpolyError;
 use crate::events::RentPaid;
-use crate::state::{GameState, GameStatus, PlayerState, PropertyState, TurnPhase};
-
+use crate::instructions::settle_debt::pay_or_owe;
+use crate::state::{
+    BoardConfig, BoardSpace, CardModifier, EffectSubject, GameState, GameStatus, PlayerState,
+    PropertyState, TurnPhase,
+};
+
+/// Pays rent on an owned space. Utility rent uses the dice in
+/// game_state.pending_dice. remaining_accounts must hold the PropertyState PDA
+/// of every space in the landed space's group, in board order (uninitialized =
+/// bank-owned), so bridge/utility counts and monopolies are read on-chain.
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32])]
 pub struct PayRent<'info> {
@@ -19,6 +26,10 @@ pub struct PayRent<'info> {
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
     #[account(
         mut,
         seeds = [SEED_PLAYER_STATE, &game_id, payer.key().as_ref()],
@@ -34,6 +45,13 @@ pub struct PayRent<'info> {
     )]
     pub property_state: Account<'info, PropertyState>,
 
+    /// Owner's PlayerState (house rule: no rent while in the Rug Pull Zone)
+    #[account(
+        seeds = [SEED_PLAYER_STATE, &game_id, property_state.owner.as_ref()],
+        bump = owner_state.bump,
+    )]
+    pub owner_state: Account<'info, PlayerState>,
+
     #[account(
         mut,
         associated_token::mint = game_state.bpoly_mint,
@@ -53,22 +71,16 @@ pub struct PayRent<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(
-    ctx: Context<PayRent>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PayRent<'info>>,
     game_id: [u8; 32],
-    // For utilities: the dice roll total (passed in since VRF already resolved)
-    dice_total: u8,
-    // Number of bridges owned by the property owner (passed in to avoid loading extra accounts)
-    bridges_owned: u8,
-    // Number of utilities owned by the owner
-    utilities_owned: u8,
 ) -> Result<()> {
     let game = &mut ctx.accounts.game_state;
     let payer_state = &mut ctx.accounts.payer_state;
     let property = &ctx.accounts.property_state;
 
     require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
-    require!(game.turn_phase == TurnPhase::BuyDecision, BlockpolyError::WrongTurnPhase);
+    require!(game.turn_phase == TurnPhase::PayRent, BlockpolyError::WrongTurnPhase);
     require!(
         game.current_player_index == payer_state.player_index,
         BlockpolyError::NotYourTurn
@@ -78,28 +90,42 @@ pub fn handler(
     require!(property.owner != ctx.accounts.payer.key(), BlockpolyError::PropertyNotAvailable);
 
     let space_index = payer_state.position;
-    let space = &BOARD[space_index as usize];
+    require!(property.space_index == space_index, BlockpolyError::InvalidPropertyAccount);
+
+    let dice = game.pending_dice.ok_or(BlockpolyError::DiceNotRolled)?;
+    let dice_total = dice[0] + dice[1];
+
+    let board = &ctx.accounts.board_config;
+    let space = board.space(space_index);
+    let holdings = GroupHoldings::load(
+        &game_id,
+        &board.group_spaces(space.group),
+        &property.owner,
+        ctx.remaining_accounts,
+    )?;
+
+    let rent = if game.rules.no_rent_in_rugpull && ctx.accounts.owner_state.is_in_rugpull() {
+        0
+    } else {
+        calculate_rent(space, property, game, dice_total, &holdings)?
+    };
 
-    let rent = calculate_rent(
-        space_index,
-        property,
+    // Transfer BPOLY from payer to owner, or owe it and raise funds first
+    let owner = property.owner;
+    let paid = pay_or_owe(
         game,
-        dice_total,
-        bridges_owned,
-        utilities_owned,
+        payer_state,
+        &mut ctx.accounts.payer_bpoly_ata,
+        &ctx.accounts.owner_bpoly_ata,
+        &ctx.accounts.payer,
+        &ctx.accounts.token_program,
+        Some(owner),
+        rent,
+        DEBT_REASON_RENT,
     )?;
-
-    // Transfer BPOLY from payer to owner
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.payer_bpoly_ata.to_account_info(),
-            to: ctx.accounts.owner_bpoly_ata.to_account_info(),
-            authority: ctx.accounts.payer.to_account_info(),
-        },
-    );
-    token::transfer(cpi_ctx, rent)?;
-    payer_state.bpoly_balance = payer_state.bpoly_balance.saturating_sub(rent);
+    if !paid {
+        return Ok(());
+    }
 
     // Track last rent for 51% Attack card
     game.last_rent_payer = Some(ctx.accounts.payer.key());
@@ -108,7 +134,7 @@ pub fn handler(
     emit!(RentPaid {
         game_id,
         payer: ctx.accounts.payer.key(),
-        owner: property.owner,
+        owner,
         space: space_index,
         amount: rent,
     });
@@ -119,24 +145,25 @@ pub fn handler(
 }
 
 pub fn calculate_rent(
-    space_index: u8,
+    space: &BoardSpace,
     property: &PropertyState,
     game: &GameState,
     dice_total: u8,
-    bridges_owned: u8,
-    utilities_owned: u8,
+    holdings: &GroupHoldings,
 ) -> Result<u64> {
-    let space = &BOARD[space_index as usize];
-
     let base_rent = match space.space_type {
         SPACE_TYPE_BRIDGE => {
             // Bridge rent based on how many bridges the owner has
-            let idx = (bridges_owned.saturating_sub(1)).min(3) as usize;
-            space.bridge_rents[idx]
+            let idx = (holdings.owned.saturating_sub(1)).min(3) as usize;
+            if game.card_modifier == CardModifier::DoubleBridgeRent {
+                space.bridge_rents[idx].saturating_mul(2)
+            } else {
+                space.bridge_rents[idx]
+            }
         }
         SPACE_TYPE_UTILITY => {
             // Utility: dice × 4 (1 owned) or dice × 10 (both owned)
-            let multiplier: u64 = if utilities_owned >= 2 { 10 } else { 4 };
+            let multiplier: u64 = if holdings.owned >= 2 { 10 } else { 4 };
             (dice_total as u64) * 1_000_000 * multiplier
         }
         SPACE_TYPE_PROPERTY => {
@@ -144,30 +171,72 @@ pub fn calculate_rent(
                 space.protocol_rent
             } else if property.liquidity_pools > 0 {
                 space.lp_rents[(property.liquidity_pools - 1) as usize]
+            } else if holdings.is_monopoly() {
+                // Whole color group owned and unmortgaged: double base rent
+                space.base_rent.saturating_mul(2)
             } else {
-                // Check for color group monopoly (doubles base rent)
-                let group_spaces = group_spaces(space.group);
-                let owner = property.owner;
-                // Monopoly bonus requires ALL spaces in group to be owned by same player
-                // For full accuracy we'd need to load all PropertyState PDAs.
-                // The instruction accepts a boolean flag from the caller.
-                // Here we use the raw base rent; the caller validates monopoly.
                 space.base_rent
             }
         }
         _ => 0,
     };
 
-    // Apply Bull Run doubling (doubles rent for the current round)
-    let rent = if game.bull_run_active
-        && game.round_number <= game.bull_run_ends_round
-        && !property.is_full_protocol
-        && property.liquidity_pools == 0
-    {
-        base_rent.saturating_mul(2)
-    } else {
-        base_rent
+    // Bull Run, Bear Market and other active market effects
+    let subject = EffectSubject::Rent {
+        group: space.group,
+        improved: property.is_full_protocol || property.liquidity_pools > 0,
     };
+    Ok(game.apply_market_effects(subject, base_rent))
+}
+
+/// An owner's holdings in one group, read from verified PropertyState accounts.
+pub struct GroupHoldings {
+    /// Spaces in the group
+    pub size: u8,
+    /// Spaces the owner holds
+    pub owned: u8,
+    /// Any of the owner's spaces in the group is mortgaged
+    pub any_mortgaged: bool,
+}
+
+impl GroupHoldings {
+    /// `accounts` must be exactly the PropertyState PDAs of `spaces` (the
+    /// group's spaces in board order); an uninitialized PDA is bank-owned.
+    /// Any other list is rejected.
+    pub fn load<'info>(
+        game_id: &[u8; 32],
+        spaces: &[u8],
+        owner: &Pubkey,
+        accounts: &'info [AccountInfo<'info>],
+    ) -> Result<Self> {
+        require!(accounts.len() == spaces.len(), BlockpolyError::InvalidGroupAccounts);
+
+        let mut holdings = Self {
+            size: spaces.len() as u8,
+            owned: 0,
+            any_mortgaged: false,
+        };
+        for (&space, info) in spaces.iter().zip(accounts.iter()) {
+            let (expected, _) = Pubkey::find_program_address(
+                &[SEED_PROPERTY_STATE, game_id, &[space]],
+                &crate::ID,
+            );
+            require_keys_eq!(info.key(), expected, BlockpolyError::InvalidGroupAccounts);
+
+            if info.data_is_empty() {
+                continue; // never bought
+            }
+            let state: Account<'info, PropertyState> = Account::try_from(info)?;
+            if state.owner == *owner {
+                holdings.owned += 1;
+                holdings.any_mortgaged |= state.is_mortgaged;
+            }
+        }
+        Ok(holdings)
+    }
 
-    Ok(rent)
+    /// Owner holds every space of the group and none is mortgaged
+    pub fn is_monopoly(&self) -> bool {
+        self.size > 0 && self.owned == self.size && !self.any_mortgaged
+    }
 }
@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
 use crate::constants::*;
 use crate::errors::BlockpolyError;
 use crate::events::TradeProposed;
 use crate::state::{GameState, GameStatus, PlayerState, TradeOffer};
 
+/// Offers a trade to another player. Offered BPOLY stays in the proposer's
+/// ATA; accept_trade moves it through the bank vault, which every player
+/// approves as delegate on joining (an ATA has a single delegate).
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32])]
 pub struct ProposeTrade<'info> {
@@ -32,10 +36,18 @@ pub struct ProposeTrade<'info> {
     )]
     pub trade_offer: Account<'info, TradeOffer>,
 
+    #[account(
+        mut,
+        associated_token::mint = game_state.bpoly_mint,
+        associated_token::authority = proposer,
+    )]
+    pub proposer_bpoly_ata: Account<'info, TokenAccount>,
+
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<ProposeTrade>,
     game_id: [u8; 32],
@@ -60,6 +72,11 @@ pub fn handler(
         require!(proposer_state.owns_property(prop), BlockpolyError::NotPropertyOwner);
     }
 
+    require!(
+        ctx.accounts.proposer_bpoly_ata.amount >= offered_bpoly,
+        BlockpolyError::InsufficientBalance
+    );
+
     if offered_jail_free {
         require!(proposer_state.has_jail_free_card, BlockpolyError::InvalidTradeOffer);
     }
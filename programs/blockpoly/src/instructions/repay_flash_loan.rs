@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::constants::*;
+use crate::errors::BlockpolyError;
+use crate::events::FlashLoanRepaid;
+use crate::instructions::settle_debt::record_debt;
+use crate::state::{GameState, GameStatus, PlayerState, TurnPhase};
+
+/// Repays a Flash Loan to the bank, any time while it is open. The loan falls
+/// due at the start of the borrower's next turn (flash_loan_due_turn). Once it
+/// is due, rolling without repaying, or repaying from an ATA that can't cover
+/// it, defaults the loan: it becomes a debt to the bank with the RuleSet
+/// penalty added, paid off in RaiseFunds before the roll.
+#[derive(Accounts)]
+#[instruction(game_id: [u8; 32])]
+pub struct RepayFlashLoan<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GAME_STATE, &game_id],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PLAYER_STATE, &game_id, player.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.wallet == player.key() @ BlockpolyError::NotYourTurn,
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(mut, address = game_state.bank_bpoly_ata)]
+    pub bank_bpoly_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_state.bpoly_mint,
+        associated_token::authority = player,
+    )]
+    pub player_bpoly_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<RepayFlashLoan>, game_id: [u8; 32]) -> Result<()> {
+    let game = &mut ctx.accounts.game_state;
+    let player = &mut ctx.accounts.player_state;
+
+    require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
+    require!(player.flash_loan_active, BlockpolyError::NoFlashLoan);
+
+    let amount = player.flash_loan_repay_amount;
+    if ctx.accounts.player_bpoly_ata.amount < amount {
+        // Only a loan already due defaults, and only where RaiseFunds can
+        // resume the roll
+        require!(
+            player.flash_loan_overdue(game.turn_number)
+                && game.current_player_index == player.player_index
+                && game.turn_phase == TurnPhase::RollDice,
+            BlockpolyError::InsufficientBalance
+        );
+        default_flash_loan(game, player);
+        return Ok(());
+    }
+
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.player_bpoly_ata.to_account_info(),
+            to: ctx.accounts.bank_bpoly_ata.to_account_info(),
+            authority: ctx.accounts.player.to_account_info(),
+        },
+    );
+    token::transfer(cpi_ctx, amount)?;
+    player.bpoly_balance = player.bpoly_balance.saturating_sub(amount);
+    player.clear_flash_loan();
+
+    emit!(FlashLoanRepaid {
+        game_id,
+        player: player.wallet,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Turns the player's Flash Loan into a debt to the bank for the repayment
+/// plus the RuleSet penalty, and puts the turn in RaiseFunds
+pub(crate) fn default_flash_loan(game: &mut GameState, player: &mut PlayerState) {
+    let owed = player
+        .flash_loan_repay_amount
+        .saturating_add(game.rules.flash_loan_penalty);
+    player.clear_flash_loan();
+    record_debt(game.game_id, player, None, owed, DEBT_REASON_FLASH_LOAN);
+    game.turn_phase = TurnPhase::RaiseFunds;
+}
@@ -1,15 +1,21 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::slot_hashes;
 use crate::constants::*;
 use crate::errors::BlockpolyError;
-use crate::state::{GameState, GameStatus, PlayerState, TurnPhase};
+use crate::instructions::repay_flash_loan::default_flash_loan;
+use crate::state::{DiceMode, GameState, GameStatus, PlayerState, TurnPhase};
+use crate::switchboard::{self, RandomnessAccountData, SWITCHBOARD_ON_DEMAND_PROGRAM};
 
-/// Request a dice roll via Switchboard VRF.
-/// In the real deployment this integrates with the Switchboard oracle.
-/// For the hackathon build, we accept a pseudo-randomness fallback where
-/// the Switchboard callback (consume_randomness) fulfills the roll.
+/// Request a dice roll backed by Switchboard On-Demand randomness.
 ///
-/// The VRF account is passed in and tracked in game_state.vrf_request.
-/// The oracle fulfills it by calling consume_randomness.
+/// The player passes a randomness account they created off-chain (SDK
+/// `Randomness.create`, authority = player). This instruction commits it via
+/// CPI, checks the commitment is fresh, and records it in
+/// game_state.vrf_request. Once the oracle reveals, consume_randomness reads
+/// the value straight from that account.
+///
+/// Rolling with a Flash Loan overdue defaults on it instead: nothing is
+/// committed and the turn moves to RaiseFunds to pay the loan and penalty.
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32])]
 pub struct RequestDiceRoll<'info> {
@@ -30,36 +36,83 @@ pub struct RequestDiceRoll<'info> {
         constraint = player_state.wallet == player.key() @ BlockpolyError::NotYourTurn,
     )]
     pub player_state: Account<'info, PlayerState>,
+
+    /// Switchboard randomness account committed for this roll
+    /// CHECK: owner and layout verified in switchboard::RandomnessAccountData::load
+    #[account(mut, owner = SWITCHBOARD_ON_DEMAND_PROGRAM @ BlockpolyError::InvalidRandomnessAccount)]
+    pub randomness_account: UncheckedAccount<'info>,
+
+    /// CHECK: Switchboard oracle queue, verified by the Switchboard program
+    pub randomness_queue: UncheckedAccount<'info>,
+
+    /// CHECK: oracle assigned to reveal, verified by the Switchboard program
+    #[account(mut)]
+    pub randomness_oracle: UncheckedAccount<'info>,
+
+    /// CHECK: SlotHashes sysvar
+    #[account(address = slot_hashes::ID)]
+    pub recent_slothashes: UncheckedAccount<'info>,
+
+    /// CHECK: Switchboard On-Demand program, address constrained
+    #[account(address = SWITCHBOARD_ON_DEMAND_PROGRAM)]
+    pub switchboard_program: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<RequestDiceRoll>, game_id: [u8; 32]) -> Result<()> {
-    let game = &mut ctx.accounts.game_state;
-    let player = &ctx.accounts.player_state;
+    {
+        let game = &mut ctx.accounts.game_state;
+        let player = &mut ctx.accounts.player_state;
 
-    require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
-    require!(
-        game.current_player_index == player.player_index,
-        BlockpolyError::NotYourTurn
-    );
+        require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
+        require!(game.dice_mode == DiceMode::Oracle, BlockpolyError::WrongDiceMode);
+        require!(
+            game.current_player_index == player.player_index,
+            BlockpolyError::NotYourTurn
+        );
+        require!(
+            game.turn_phase == TurnPhase::RollDice,
+            BlockpolyError::WrongTurnPhase
+        );
+        require!(game.vrf_request.is_none(), BlockpolyError::VRFPending);
+        require!(
+            !game.market_event_due(player.market_event_round),
+            BlockpolyError::MarketEventPending
+        );
+        require!(!player.has_debt(), BlockpolyError::DebtOutstanding);
+        if player.flash_loan_overdue(game.turn_number) {
+            default_flash_loan(game, player);
+            return Ok(());
+        }
+    }
+
+    switchboard::randomness_commit(
+        &ctx.accounts.switchboard_program.to_account_info(),
+        &ctx.accounts.randomness_account.to_account_info(),
+        &ctx.accounts.randomness_queue.to_account_info(),
+        &ctx.accounts.randomness_oracle.to_account_info(),
+        &ctx.accounts.recent_slothashes.to_account_info(),
+        &ctx.accounts.player.to_account_info(),
+    )?;
+
+    // The commitment is seeded with the previous slot's hash; anything older
+    // means the account was committed earlier and its value may already be known.
+    let randomness = RandomnessAccountData::load(&ctx.accounts.randomness_account)?;
+    let clock = Clock::get()?;
     require!(
-        game.turn_phase == TurnPhase::RollDice,
-        BlockpolyError::WrongTurnPhase
+        randomness.seed_slot == clock.slot.saturating_sub(1),
+        BlockpolyError::RandomnessNotCommitted
     );
-    require!(game.vrf_request.is_none(), BlockpolyError::VRFPending);
-
-    // In production: CPI to Switchboard to request VRF
-    // The oracle callback will call consume_randomness with the result.
-    // For now, we mark the phase as AwaitingVRF. A separate consume_randomness
-    // instruction (called by the oracle or, in tests, directly) will move us forward.
 
+    let randomness_key = ctx.accounts.randomness_account.key();
+    let game = &mut ctx.accounts.game_state;
     game.turn_phase = TurnPhase::AwaitingVRF;
-    // vrf_request pubkey would be set here with the actual Switchboard VRF account
-    // game.vrf_request = Some(vrf_account.key());
+    game.vrf_request = Some(randomness_key);
+    game.vrf_seed_slot = randomness.seed_slot;
 
     emit!(crate::events::VrfRequested {
         game_id,
         player: ctx.accounts.player.key(),
-        vrf_account: Pubkey::default(), // replaced with actual VRF account in production
+        vrf_account: randomness_key,
     });
 
     Ok(())
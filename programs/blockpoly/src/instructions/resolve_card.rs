@@ -1,13 +1,35 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke, program_option::COption};
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::board::{BOARD, nearest_bridge_ahead};
 use crate::constants::*;
 use crate::errors::BlockpolyError;
-use crate::events::{BullRunActivated, CardResolved, RugPullEntered};
-use crate::state::{GameState, GameStatus, PlayerState, PlayerStatus, TurnPhase};
+use crate::events::{
+    CardResolved, FlashLoanDeclined, MarketEffectStarted, PropertyReturnedToBank, RugPullEntered,
+    RugPullExited,
+};
+use crate::instructions::buy_property::MPL_CORE_PROGRAM;
+use crate::instructions::settle_debt::{pay_or_owe, record_debt};
+use crate::state::{
+    BoardConfig, CardDeck, CardEffect, GameState, GameStatus, MarketEffect, PlayerState,
+    PlayerStatus, PropertyState, TurnPhase,
+};
 
-/// Resolves card effects for both Alpha Call and Governance Vote decks.
-/// Card IDs are 0-indexed (0 = card 1 in the plan).
+/// Resolves the card draw_card left in game_state.drawn_card by interpreting
+/// its CardEffect from the game's CardDeck.
+///
+/// Effects involving every other player (PayEachPlayer, CollectFromEachPlayer)
+/// take an [opponent PlayerState, opponent BPOLY ATA] pair per other player in
+/// remaining_accounts, in GameState.players order.
+///
+/// Effects that read or take the player's properties (PayPerBuilding,
+/// CollectPerBuilding, LoseCheapestProperty, SellMostExpensiveProperty,
+/// LoseBridgeOrCollect) take the PropertyState of every space in
+/// properties_owned, in that order, followed by [NFT asset, collection,
+/// mpl-core program] if a property lost has an NFT.
+///
+/// MoveToNearestUnowned takes the PropertyState PDA of each space of its type
+/// ahead of the player, nearest first, up to and including the first one the
+/// bank owns (all of them if none is).
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32])]
 pub struct ResolveCard<'info> {
@@ -21,6 +43,14 @@ pub struct ResolveCard<'info> {
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
+    /// Cards this game draws from
+    #[account(address = game_state.card_deck @ BlockpolyError::InvalidCardDeck)]
+    pub card_deck: Account<'info, CardDeck>,
+
     #[account(
         mut,
         seeds = [SEED_PLAYER_STATE, &game_id, player.key().as_ref()],
@@ -47,576 +77,603 @@ pub struct ResolveCard<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
-    ctx: Context<ResolveCard>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ResolveCard<'info>>,
     game_id: [u8; 32],
-    deck_type: u8, // 0 = alpha_call, 1 = governance
-    card_id: u8,   // 0-15
-    // Additional parameters for cards that need them (e.g., last dice roll, LP counts)
-    extra_param: u64,
 ) -> Result<()> {
-    {
-        let game = &ctx.accounts.game_state;
+    let [deck_type, card_id] = {
+        let game = &mut ctx.accounts.game_state;
         let player = &ctx.accounts.player_state;
         require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
+        require!(game.turn_phase == TurnPhase::DrawCard, BlockpolyError::WrongTurnPhase);
         require!(
             game.current_player_index == player.player_index,
             BlockpolyError::NotYourTurn
         );
-    }
+        // Only the card draw_card put on the table, and only once
+        game.drawn_card.take().ok_or(BlockpolyError::NoCardDrawn)?
+    };
+    let card = ctx
+        .accounts
+        .card_deck
+        .cards(deck_type)
+        .get(card_id as usize)
+        .cloned()
+        .ok_or(BlockpolyError::InvalidCardDeck)?;
 
     let bank_vault_bump = ctx.bumps.bank_vault;
     let signer_seeds: &[&[&[u8]]] = &[&[SEED_BANK_VAULT, &game_id, &[bank_vault_bump]]];
 
-    let effect_desc = if deck_type == 0 {
-        resolve_alpha_call(
-            card_id,
-            &mut ctx.accounts.game_state,
-            &mut ctx.accounts.player_state,
-            &ctx.accounts.token_program,
-            &ctx.accounts.bank_bpoly_ata,
-            &ctx.accounts.player_bpoly_ata,
-            &ctx.accounts.bank_vault,
-            &ctx.accounts.player,
-            extra_param,
-            signer_seeds,
-        )?
-    } else {
-        resolve_governance_vote(
-            card_id,
-            &mut ctx.accounts.game_state,
-            &mut ctx.accounts.player_state,
-            &ctx.accounts.token_program,
-            &ctx.accounts.bank_bpoly_ata,
-            &ctx.accounts.player_bpoly_ata,
-            &ctx.accounts.bank_vault,
-            &ctx.accounts.player,
-            extra_param,
-            signer_seeds,
-        )?
-    };
+    apply_effect(
+        card.effect,
+        deck_type,
+        card_id,
+        &mut ctx.accounts.game_state,
+        &ctx.accounts.board_config,
+        &mut ctx.accounts.player_state,
+        &ctx.accounts.token_program,
+        &ctx.accounts.bank_bpoly_ata,
+        &mut ctx.accounts.player_bpoly_ata,
+        &ctx.accounts.bank_vault,
+        &ctx.accounts.player,
+        ctx.remaining_accounts,
+        signer_seeds,
+    )?;
 
     let player_key = ctx.accounts.player.key();
     emit!(CardResolved {
         game_id,
         player: player_key,
         card_id,
-        effect: effect_desc.to_string(),
+        effect: card.name,
     });
 
-    ctx.accounts.game_state.pending_dice = None;
-    ctx.accounts.game_state.advance_turn();
+    // Movement cards hand the new space to resolve_landing (which also pays
+    // any Genesis salary); a card fee the player couldn't cover leaves the
+    // turn in RaiseFunds
+    let game = &mut ctx.accounts.game_state;
+    match game.turn_phase {
+        TurnPhase::LandingEffect | TurnPhase::RaiseFunds => {}
+        _ => game.advance_turn(),
+    }
 
     Ok(())
 }
 
-fn resolve_alpha_call<'info>(
+#[allow(clippy::too_many_arguments)]
+fn apply_effect<'info>(
+    effect: CardEffect,
+    deck_type: u8,
     card_id: u8,
     game: &mut GameState,
+    board: &BoardConfig,
     player: &mut PlayerState,
     token_program: &Program<'info, Token>,
     bank_bpoly_ata: &Account<'info, TokenAccount>,
-    player_bpoly_ata: &Account<'info, TokenAccount>,
+    player_bpoly_ata: &mut Account<'info, TokenAccount>,
     bank_vault: &UncheckedAccount<'info>,
     player_signer: &Signer<'info>,
-    extra_param: u64,
+    remaining_accounts: &'info [AccountInfo<'info>],
     signer_seeds: &[&[&[u8]]],
-) -> Result<&'static str> {
-    let bank_vault_bump_bytes = signer_seeds[0][2];
-    match card_id {
-        0 => {
-            // Advance to Genesis Block, collect 200 BPOLY
-            player.position = SPACE_GENESIS;
-            let cpi_ctx = CpiContext::new_with_signer(
-                token_program.to_account_info(),
-                Transfer {
-                    from: bank_bpoly_ata.to_account_info(),
-                    to: player_bpoly_ata.to_account_info(),
-                    authority: bank_vault.to_account_info(),
-                },
-                signer_seeds,
-            );
-            token::transfer(cpi_ctx, GENESIS_SALARY)?;
-            player.bpoly_balance = player.bpoly_balance.saturating_add(GENESIS_SALARY);
-            Ok("Advance to Genesis Block")
-        }
-        1 => {
-            // Advance to Solana (space 39), collect 200 if passing GO
-            let old_pos = player.position;
-            player.position = 39;
-            if old_pos > 39 || old_pos == 0 {
-                // no pass
-            } else if old_pos < 39 {
-                // no pass unless wrapping
+) -> Result<()> {
+    // Card fees go to the bank; one the player can't cover becomes a debt
+    let pay_bank = |game: &mut GameState,
+                    player: &mut PlayerState,
+                    player_bpoly_ata: &mut Account<'info, TokenAccount>,
+                    amount: u64|
+     -> Result<()> {
+        if amount > 0 {
+            pay_or_owe(
+                game,
+                player,
+                player_bpoly_ata,
+                bank_bpoly_ata,
+                player_signer,
+                token_program,
+                None,
+                amount,
+                DEBT_REASON_CARD,
+            )?;
+        }
+        Ok(())
+    };
+    let bank_pays = |player: &mut PlayerState,
+                     player_bpoly_ata: &Account<'info, TokenAccount>,
+                     amount: u64| {
+        collect_from_bank(
+            player,
+            player_bpoly_ata,
+            token_program,
+            bank_bpoly_ata,
+            bank_vault,
+            signer_seeds,
+            amount,
+        )
+    };
+
+    match effect {
+        CardEffect::PayBank(amount) => pay_bank(game, player, player_bpoly_ata, amount)?,
+        CardEffect::CollectFromBank(amount) => bank_pays(player, player_bpoly_ata, amount)?,
+        CardEffect::PayEachPlayer(share) => {
+            let mut owed = 0u64;
+            for mut opponent in load_opponents(game, &player.wallet, remaining_accounts)? {
+                player_bpoly_ata.reload()?;
+                let cpi_ctx = if player_bpoly_ata.amount >= share {
+                    player.bpoly_balance = player.bpoly_balance.saturating_sub(share);
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: player_bpoly_ata.to_account_info(),
+                            to: opponent.bpoly_ata.to_account_info(),
+                            authority: player_signer.to_account_info(),
+                        },
+                    )
+                } else {
+                    // Short for this opponent: the bank pays them and the
+                    // player owes the bank, so every opponent is paid in full
+                    owed += share;
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: bank_bpoly_ata.to_account_info(),
+                            to: opponent.bpoly_ata.to_account_info(),
+                            authority: bank_vault.to_account_info(),
+                        },
+                        signer_seeds,
+                    )
+                };
+                token::transfer(cpi_ctx, share)?;
+                opponent.state.bpoly_balance = opponent.state.bpoly_balance.saturating_add(share);
+                opponent.state.exit(&crate::ID)?;
             }
-            // Simplified: if current position > 39 position, passed genesis
-            let passed = player.position < old_pos && old_pos != 0;
-            if passed {
-                let cpi_ctx = CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    Transfer {
-                        from: bank_bpoly_ata.to_account_info(),
-                        to: player_bpoly_ata.to_account_info(),
-                        authority: bank_vault.to_account_info(),
-                    },
-                    signer_seeds,
-                );
-                token::transfer(cpi_ctx, GENESIS_SALARY)?;
-                player.bpoly_balance = player.bpoly_balance.saturating_add(GENESIS_SALARY);
+            if owed > 0 {
+                record_debt(game.game_id, player, None, owed, DEBT_REASON_CARD);
+                game.turn_phase = TurnPhase::RaiseFunds;
             }
-            Ok("Advance to Solana")
         }
-        2 => {
-            // Advance to nearest bridge; pay 2× rent if owned
-            let nearest = nearest_bridge_ahead(player.position);
-            player.position = nearest;
-            Ok("Advance to Nearest Bridge")
-        }
-        3 => {
-            // Advance to Wormhole (space 5)
-            let old_pos = player.position;
-            player.position = 5;
-            if old_pos > 5 {
-                // passed genesis
-                let cpi_ctx = CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    Transfer {
-                        from: bank_bpoly_ata.to_account_info(),
-                        to: player_bpoly_ata.to_account_info(),
-                        authority: bank_vault.to_account_info(),
-                    },
-                    signer_seeds,
-                );
-                token::transfer(cpi_ctx, GENESIS_SALARY)?;
-                player.bpoly_balance = player.bpoly_balance.saturating_add(GENESIS_SALARY);
+        CardEffect::CollectFromEachPlayer(share) => {
+            let collector = player.wallet;
+            let opponents = load_opponents(game, &collector, remaining_accounts)?;
+            for Opponent { mut state, bpoly_ata } in opponents {
+                if bank_can_take(&bpoly_ata, bank_vault.key, share) {
+                    // Opponents don't sign the card; the bank vault moves
+                    // their share as the delegate they approved on joining
+                    let cpi_ctx = CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: bpoly_ata.to_account_info(),
+                            to: player_bpoly_ata.to_account_info(),
+                            authority: bank_vault.to_account_info(),
+                        },
+                        signer_seeds,
+                    );
+                    token::transfer(cpi_ctx, share)?;
+                    state.bpoly_balance = state.bpoly_balance.saturating_sub(share);
+                    player.bpoly_balance = player.bpoly_balance.saturating_add(share);
+                } else if !state.has_debt() || state.debt_creditor == Some(collector) {
+                    // Short: they owe the collector and settle it with
+                    // settle_debt before their next roll. A player owes one
+                    // creditor at a time, so one already owing someone else
+                    // skips this share.
+                    let reason = DEBT_REASON_PLAYER_CARD;
+                    record_debt(game.game_id, &mut state, Some(collector), share, reason);
+                }
+                state.exit(&crate::ID)?;
             }
-            Ok("Advance to Wormhole")
-        }
-        4 => {
-            // Staking Rewards: collect 50 BPOLY
-            let amount = 50_000_000u64;
-            let cpi_ctx = CpiContext::new_with_signer(
-                token_program.to_account_info(),
-                Transfer {
-                    from: bank_bpoly_ata.to_account_info(),
-                    to: player_bpoly_ata.to_account_info(),
-                    authority: bank_vault.to_account_info(),
-                },
-                signer_seeds,
-            );
-            token::transfer(cpi_ctx, amount)?;
-            player.bpoly_balance = player.bpoly_balance.saturating_add(amount);
-            Ok("Staking Rewards")
         }
-        5 => {
-            // Get Out of Rug Pull Free
-            player.has_jail_free_card = true;
-            player.jail_free_card_type = 1; // alpha_call
-            Ok("Get Out of Rug Pull Free")
-        }
-        6 => {
-            // MEV Bot Attack: pay dice roll × 4 BPOLY
-            // extra_param = last dice roll total
-            let toll = extra_param.saturating_mul(4_000_000);
-            let cpi_ctx = CpiContext::new(
-                token_program.to_account_info(),
-                Transfer {
-                    from: player_bpoly_ata.to_account_info(),
-                    to: bank_bpoly_ata.to_account_info(),
-                    authority: player_signer.to_account_info(),
-                },
-            );
-            token::transfer(cpi_ctx, toll)?;
-            player.bpoly_balance = player.bpoly_balance.saturating_sub(toll);
-            Ok("MEV Bot Attack")
-        }
-        7 => {
-            // Market Crash: lose 20% of balance
-            let loss = player.bpoly_balance / 5;
-            if loss > 0 {
-                let cpi_ctx = CpiContext::new(
-                    token_program.to_account_info(),
-                    Transfer {
-                        from: player_bpoly_ata.to_account_info(),
-                        to: bank_bpoly_ata.to_account_info(),
-                        authority: player_signer.to_account_info(),
-                    },
-                );
-                token::transfer(cpi_ctx, loss)?;
-                player.bpoly_balance = player.bpoly_balance.saturating_sub(loss);
+        CardEffect::PayPerBuilding { per_lp, per_protocol } => {
+            let (holdings, _) = load_holdings(game, player, remaining_accounts)?;
+            let (lp_count, protocol_count) = count_buildings(&holdings);
+            let levy = lp_count
+                .saturating_mul(per_lp)
+                .saturating_add(protocol_count.saturating_mul(per_protocol));
+            pay_bank(game, player, player_bpoly_ata, levy)?;
+        }
+        CardEffect::CollectPerBuilding { per_lp, per_protocol } => {
+            let (holdings, _) = load_holdings(game, player, remaining_accounts)?;
+            let (lp_count, protocol_count) = count_buildings(&holdings);
+            let rewards = lp_count
+                .saturating_mul(per_lp)
+                .saturating_add(protocol_count.saturating_mul(per_protocol));
+            bank_pays(player, player_bpoly_ata, rewards)?;
+        }
+        CardEffect::CollectPerCompleteGroup(per_group) => {
+            let set_count = (GROUP_BROWN..=GROUP_DARK_BLUE)
+                .map(|group| board.group_spaces(group))
+                .filter(|spaces| !spaces.is_empty() && spaces.iter().all(|&s| player.owns_property(s)))
+                .count() as u64;
+            bank_pays(player, player_bpoly_ata, set_count.saturating_mul(per_group))?;
+        }
+        CardEffect::PayPerDicePoint(per_point) => {
+            // The roll that landed on the card
+            let dice = game.pending_dice.ok_or(BlockpolyError::DiceNotRolled)?;
+            let toll = (dice[0] + dice[1]) as u64 * per_point;
+            pay_bank(game, player, player_bpoly_ata, toll)?;
+        }
+        CardEffect::PayBalanceBps(bps) => {
+            // A share of what the ATA holds; bpoly_balance is only a mirror
+            player_bpoly_ata.reload()?;
+            let loss = (player_bpoly_ata.amount as u128 * bps as u128 / 10_000) as u64;
+            pay_bank(game, player, player_bpoly_ata, loss)?;
+        }
+        CardEffect::MoveTo { space, modifier } => {
+            player.advance_to(space);
+            game.card_modifier = modifier;
+            game.turn_phase = TurnPhase::LandingEffect;
+        }
+        CardEffect::MoveToNearest { space_type, modifier } => {
+            // A board without one leaves the player where they are
+            if let Some(nearest) = board.nearest_ahead(player.position, space_type) {
+                player.advance_to(nearest);
+                game.card_modifier = modifier;
+                game.turn_phase = TurnPhase::LandingEffect;
             }
-            Ok("Market Crash")
-        }
-        8 => {
-            // Bull Run: all property rents doubled for next full round
-            game.bull_run_active = true;
-            game.bull_run_ends_round = game.round_number + 1;
-            emit!(BullRunActivated {
-                game_id: game.game_id,
-                ends_round: game.bull_run_ends_round,
-            });
-            Ok("Bull Run")
         }
-        9 => {
-            // 51% Attack: steal last rent payment
-            let amount = game.last_rent_amount;
-            if amount > 0 {
-                let cpi_ctx = CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    Transfer {
-                        from: bank_bpoly_ata.to_account_info(),
-                        to: player_bpoly_ata.to_account_info(),
-                        authority: bank_vault.to_account_info(),
-                    },
-                    signer_seeds,
-                );
-                token::transfer(cpi_ctx, amount)?;
-                player.bpoly_balance = player.bpoly_balance.saturating_add(amount);
-                game.last_rent_amount = 0;
+        CardEffect::MoveToNearestUnowned { space_type, modifier } => {
+            if let Some(nearest) =
+                nearest_bank_owned(game, board, player.position, space_type, remaining_accounts)?
+            {
+                player.advance_to(nearest);
+                game.card_modifier = modifier;
+                game.turn_phase = TurnPhase::LandingEffect;
             }
-            Ok("51% Attack")
-        }
-        10 => {
-            // Flash Loan: receive 200 BPOLY now
-            require!(!player.flash_loan_active, BlockpolyError::FlashLoanAlreadyActive);
-            let cpi_ctx = CpiContext::new_with_signer(
-                token_program.to_account_info(),
-                Transfer {
-                    from: bank_bpoly_ata.to_account_info(),
-                    to: player_bpoly_ata.to_account_info(),
-                    authority: bank_vault.to_account_info(),
-                },
-                signer_seeds,
-            );
-            token::transfer(cpi_ctx, FLASH_LOAN_AMOUNT)?;
-            player.bpoly_balance = player.bpoly_balance.saturating_add(FLASH_LOAN_AMOUNT);
-            player.flash_loan_active = true;
-            player.flash_loan_repay_amount = FLASH_LOAN_REPAY;
-            player.flash_loan_due_turn = game.turn_number + game.player_count as u32;
-            Ok("Flash Loan")
         }
-        11 => {
-            // Protocol Hack: lose cheapest unprotected property (handled via remaining_accounts in real impl)
-            // For now: mark turn as ended
-            Ok("Protocol Hack")
+        CardEffect::MoveToNearestInGroup { group, modifier } => {
+            // A board without one leaves the player where they are
+            if let Some(nearest) = board.nearest_in_group(player.position, group) {
+                player.advance_to(nearest);
+                game.card_modifier = modifier;
+                game.turn_phase = TurnPhase::LandingEffect;
+            }
         }
-        12 => {
-            // Go Back 3 Spaces
-            let new_pos = if player.position < 3 {
-                40 - (3 - player.position)
-            } else {
-                player.position - 3
-            };
-            player.position = new_pos;
-            Ok("Go Back 3 Spaces")
+        CardEffect::MoveBack(steps) => {
+            // Moving back never passes Genesis
+            player.position = (player.position + BOARD_SIZE - steps) % BOARD_SIZE;
+            game.turn_phase = TurnPhase::LandingEffect;
         }
-        13 => {
-            // SEC Investigation: go directly to Rug Pull Zone
+        CardEffect::GoToJail => {
             player.position = SPACE_RUGPULL_ZONE;
-            player.rugpull_turns_remaining = RUGPULL_MAX_TURNS;
+            player.rugpull_turns_remaining = game.rules.rugpull_max_turns;
             player.status = PlayerStatus::InRugPullZone;
+            player.doubles_streak = 0;
+            // Jail ends the turn even after doubles
+            game.extra_roll = false;
             emit!(RugPullEntered {
                 game_id: game.game_id,
                 player: player.wallet,
                 reason: 1,
             });
-            Ok("SEC Investigation")
-        }
-        14 => {
-            // Whale Dump: lose most expensive property at 50% (complex — skip for now, mark TODO)
-            Ok("Whale Dump")
         }
-        15 => {
-            // Airdrop Season: advance to nearest unowned property, buy at 50%
-            let nearest = crate::board::nearest_property_ahead(player.position);
-            player.position = nearest;
-            Ok("Airdrop Season")
-        }
-        _ => Ok("Unknown Alpha Call"),
-    }
-}
-
-fn resolve_governance_vote<'info>(
-    card_id: u8,
-    game: &mut GameState,
-    player: &mut PlayerState,
-    token_program: &Program<'info, Token>,
-    bank_bpoly_ata: &Account<'info, TokenAccount>,
-    player_bpoly_ata: &Account<'info, TokenAccount>,
-    bank_vault: &UncheckedAccount<'info>,
-    player_signer: &Signer<'info>,
-    extra_param: u64,
-    signer_seeds: &[&[&[u8]]],
-) -> Result<&'static str> {
-    match card_id {
-        0 => {
-            // Protocol Treasury Release: collect 200 BPOLY
-            let cpi_ctx = CpiContext::new_with_signer(
-                token_program.to_account_info(),
-                Transfer {
-                    from: bank_bpoly_ata.to_account_info(),
-                    to: player_bpoly_ata.to_account_info(),
-                    authority: bank_vault.to_account_info(),
-                },
-                signer_seeds,
-            );
-            token::transfer(cpi_ctx, 200_000_000)?;
-            player.bpoly_balance = player.bpoly_balance.saturating_add(200_000_000);
-            Ok("Protocol Treasury Release")
-        }
-        1 => {
-            // Validator Node Income: collect 100 BPOLY
-            let cpi_ctx = CpiContext::new_with_signer(
-                token_program.to_account_info(),
-                Transfer {
-                    from: bank_bpoly_ata.to_account_info(),
-                    to: player_bpoly_ata.to_account_info(),
-                    authority: bank_vault.to_account_info(),
-                },
-                signer_seeds,
-            );
-            token::transfer(cpi_ctx, 100_000_000)?;
-            player.bpoly_balance = player.bpoly_balance.saturating_add(100_000_000);
-            Ok("Validator Node Income")
-        }
-        2 => {
-            // DAO Airdrop: collect 10 BPOLY from every other player
-            // (multi-player transfer handled via remaining_accounts in full impl)
-            // For now: take from bank as simplified version
-            let per_player = 10_000_000u64;
-            let total = per_player.saturating_mul(game.player_count.saturating_sub(1) as u64);
-            if total > 0 {
-                let cpi_ctx = CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    Transfer {
-                        from: bank_bpoly_ata.to_account_info(),
-                        to: player_bpoly_ata.to_account_info(),
-                        authority: bank_vault.to_account_info(),
-                    },
-                    signer_seeds,
-                );
-                token::transfer(cpi_ctx, total)?;
-                player.bpoly_balance = player.bpoly_balance.saturating_add(total);
+        CardEffect::JailFreeCard => {
+            // A player already holding one leaves this card in the deck,
+            // under the cards still to be drawn
+            if player.keep_jail_free_card(deck_type) {
+                game.hold_card(deck_type, card_id);
             }
-            Ok("DAO Airdrop")
         }
-        3 => {
-            // Get Out of Rug Pull Free
-            player.has_jail_free_card = true;
-            player.jail_free_card_type = 2; // governance
-            Ok("Get Out of Rug Pull Free")
+        CardEffect::LeaveJailOrCollect(amount) => {
+            if player.is_in_rugpull() {
+                player.rugpull_turns_remaining = 0;
+                player.status = PlayerStatus::Active;
+                emit!(RugPullExited {
+                    game_id: game.game_id,
+                    player: player.wallet,
+                    method: 1,
+                });
+            } else {
+                bank_pays(player, player_bpoly_ata, amount)?;
+            }
         }
-        4 => {
-            // Smart Contract Exploit Found: go to Rug Pull Zone
-            player.position = SPACE_RUGPULL_ZONE;
-            player.rugpull_turns_remaining = RUGPULL_MAX_TURNS;
-            player.status = PlayerStatus::InRugPullZone;
-            emit!(RugPullEntered {
+        CardEffect::AddGlobalEffect { kind, target, modifier, rounds } => {
+            let effect = MarketEffect {
+                kind,
+                target,
+                modifier,
+                ends_round: game.round_number + (rounds as u32).saturating_sub(1),
+            };
+            game.add_market_effect(effect);
+            emit!(MarketEffectStarted {
                 game_id: game.game_id,
-                player: player.wallet,
-                reason: 1,
+                effect,
             });
-            Ok("Smart Contract Exploit Found")
-        }
-        5 => {
-            // Gas Fee Rebate: collect 50 BPOLY
-            let cpi_ctx = CpiContext::new_with_signer(
-                token_program.to_account_info(),
-                Transfer {
-                    from: bank_bpoly_ata.to_account_info(),
-                    to: player_bpoly_ata.to_account_info(),
-                    authority: bank_vault.to_account_info(),
-                },
-                signer_seeds,
-            );
-            token::transfer(cpi_ctx, 50_000_000)?;
-            player.bpoly_balance = player.bpoly_balance.saturating_add(50_000_000);
-            Ok("Gas Fee Rebate")
         }
-        6 => {
-            // Infrastructure Levy: pay 40 per LP + 115 per Full Protocol
-            // extra_param encodes: lower 32 bits = lp_count, upper 32 bits = protocol_count
-            let lp_count = (extra_param & 0xFFFFFFFF) as u64;
-            let protocol_count = (extra_param >> 32) as u64;
-            let levy = lp_count.saturating_mul(40_000_000)
-                + protocol_count.saturating_mul(115_000_000);
-            if levy > 0 {
-                let cpi_ctx = CpiContext::new(
-                    token_program.to_account_info(),
-                    Transfer {
-                        from: player_bpoly_ata.to_account_info(),
-                        to: bank_bpoly_ata.to_account_info(),
-                        authority: player_signer.to_account_info(),
-                    },
-                );
-                token::transfer(cpi_ctx, levy)?;
-                player.bpoly_balance = player.bpoly_balance.saturating_sub(levy);
-            }
-            Ok("Infrastructure Levy")
-        }
-        7 => {
-            // Protocol Upgrade Vote: pay 100 BPOLY
-            let cpi_ctx = CpiContext::new(
-                token_program.to_account_info(),
-                Transfer {
-                    from: player_bpoly_ata.to_account_info(),
-                    to: bank_bpoly_ata.to_account_info(),
-                    authority: player_signer.to_account_info(),
-                },
-            );
-            token::transfer(cpi_ctx, 100_000_000)?;
-            player.bpoly_balance = player.bpoly_balance.saturating_sub(100_000_000);
-            Ok("Protocol Upgrade Vote")
-        }
-        8 => {
-            // Liquidity Mining Rewards: collect 25 BPOLY per LP
-            let lp_count = extra_param; // caller passes total LP count
-            let rewards = lp_count.saturating_mul(25_000_000);
-            if rewards > 0 {
-                let cpi_ctx = CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    Transfer {
-                        from: bank_bpoly_ata.to_account_info(),
-                        to: player_bpoly_ata.to_account_info(),
-                        authority: bank_vault.to_account_info(),
-                    },
-                    signer_seeds,
-                );
-                token::transfer(cpi_ctx, rewards)?;
-                player.bpoly_balance = player.bpoly_balance.saturating_add(rewards);
-            }
-            Ok("Liquidity Mining Rewards")
-        }
-        9 => {
-            // Token Unlock Cliff: pay 150 to every other player (simplified: pay to bank)
-            let amount = 150_000_000u64.saturating_mul(game.player_count.saturating_sub(1) as u64);
+        CardEffect::CollectLastRent => {
+            let amount = game.last_rent_amount;
             if amount > 0 {
-                let cpi_ctx = CpiContext::new(
-                    token_program.to_account_info(),
-                    Transfer {
-                        from: player_bpoly_ata.to_account_info(),
-                        to: bank_bpoly_ata.to_account_info(),
-                        authority: player_signer.to_account_info(),
-                    },
-                );
-                token::transfer(cpi_ctx, amount)?;
-                player.bpoly_balance = player.bpoly_balance.saturating_sub(amount);
+                bank_pays(player, player_bpoly_ata, amount)?;
+                game.last_rent_amount = 0;
             }
-            Ok("Token Unlock Cliff")
         }
-        10 => {
-            // Bridge Exploited: if you own a bridge, lose it (simplified); else collect 50
-            let own_bridge = extra_param > 0; // caller passes 1 if player owns a bridge
-            if own_bridge {
-                // Property loss handled via remaining_accounts in full impl
+        CardEffect::FlashLoan => {
+            // One loan at a time; drawn again while one is open (after
+            // doubles), the card is declined rather than failing the turn
+            if player.flash_loan_active {
+                emit!(FlashLoanDeclined {
+                    game_id: game.game_id,
+                    player: player.wallet,
+                });
             } else {
-                let cpi_ctx = CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    Transfer {
-                        from: bank_bpoly_ata.to_account_info(),
-                        to: player_bpoly_ata.to_account_info(),
-                        authority: bank_vault.to_account_info(),
-                    },
-                    signer_seeds,
-                );
-                token::transfer(cpi_ctx, 50_000_000)?;
-                player.bpoly_balance = player.bpoly_balance.saturating_add(50_000_000);
+                bank_pays(player, player_bpoly_ata, game.rules.flash_loan_amount)?;
+                player.flash_loan_active = true;
+                player.flash_loan_repay_amount = game.rules.flash_loan_repay;
+                player.flash_loan_due_turn = game.turn_number + game.player_count as u32;
             }
-            Ok("Bridge Exploited")
-        }
-        11 => {
-            // DAO Birthday Vote: all other players pay you 50 (simplified: receive from bank)
-            let total = 50_000_000u64.saturating_mul(game.player_count.saturating_sub(1) as u64);
-            let cpi_ctx = CpiContext::new_with_signer(
-                token_program.to_account_info(),
-                Transfer {
-                    from: bank_bpoly_ata.to_account_info(),
-                    to: player_bpoly_ata.to_account_info(),
-                    authority: bank_vault.to_account_info(),
-                },
-                signer_seeds,
-            );
-            token::transfer(cpi_ctx, total)?;
-            player.bpoly_balance = player.bpoly_balance.saturating_add(total);
-            Ok("DAO Birthday Vote")
         }
-        12 => {
-            // Yield Farming Season: move to DeFi Summer (space 20), +200 if pass GO
-            let old_pos = player.position;
-            player.position = SPACE_DEFI_SUMMER;
-            if old_pos > SPACE_DEFI_SUMMER {
-                let cpi_ctx = CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    Transfer {
-                        from: bank_bpoly_ata.to_account_info(),
-                        to: player_bpoly_ata.to_account_info(),
-                        authority: bank_vault.to_account_info(),
-                    },
-                    signer_seeds,
-                );
-                token::transfer(cpi_ctx, GENESIS_SALARY)?;
-                player.bpoly_balance = player.bpoly_balance.saturating_add(GENESIS_SALARY);
+        CardEffect::LoseCheapestProperty => {
+            let (mut holdings, nft_accounts) = load_holdings(game, player, remaining_accounts)?;
+            let built = built_groups(board, &holdings);
+            let hacked = holdings
+                .iter()
+                .enumerate()
+                .filter(|(_, p)| {
+                    !built.contains(&board.space(p.space_index).group) && !p.is_mortgaged
+                })
+                .min_by_key(|(_, p)| board.space(p.space_index).price)
+                .map(|(i, _)| i);
+            if let Some(i) = hacked {
+                let property = holdings.swap_remove(i);
+                return_to_bank(game, player, property, nft_accounts, player_signer, 0)?;
             }
-            Ok("Yield Farming Season")
         }
-        13 => {
-            // Regulatory Compliance Fine: pay 50 BPOLY
-            let cpi_ctx = CpiContext::new(
-                token_program.to_account_info(),
-                Transfer {
-                    from: player_bpoly_ata.to_account_info(),
-                    to: bank_bpoly_ata.to_account_info(),
-                    authority: player_signer.to_account_info(),
-                },
-            );
-            token::transfer(cpi_ctx, 50_000_000)?;
-            player.bpoly_balance = player.bpoly_balance.saturating_sub(50_000_000);
-            Ok("Regulatory Compliance Fine")
-        }
-        14 => {
-            // NFT Royalty Income: collect 20 BPOLY per complete color set
-            let set_count = extra_param; // caller passes number of complete sets owned
-            let income = set_count.saturating_mul(20_000_000);
-            if income > 0 {
-                let cpi_ctx = CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    Transfer {
-                        from: bank_bpoly_ata.to_account_info(),
-                        to: player_bpoly_ata.to_account_info(),
-                        authority: bank_vault.to_account_info(),
-                    },
-                    signer_seeds,
-                );
-                token::transfer(cpi_ctx, income)?;
-                player.bpoly_balance = player.bpoly_balance.saturating_add(income);
+        CardEffect::SellMostExpensiveProperty { bps } => {
+            let (mut holdings, nft_accounts) = load_holdings(game, player, remaining_accounts)?;
+            let built = built_groups(board, &holdings);
+            let dumped = holdings
+                .iter()
+                .enumerate()
+                .filter(|(_, p)| !built.contains(&board.space(p.space_index).group))
+                .min_by_key(|(_, p)| std::cmp::Reverse(board.space(p.space_index).price))
+                .map(|(i, _)| i);
+            if let Some(i) = dumped {
+                let property = holdings.swap_remove(i);
+                let space = board.space(property.space_index);
+                // A mortgage is paid off out of the sale
+                let mortgage = if property.is_mortgaged { space.mortgage_value } else { 0 };
+                let price = (space.price as u128 * bps as u128 / 10_000) as u64;
+                let proceeds = price.saturating_sub(mortgage);
+                bank_pays(player, player_bpoly_ata, proceeds)?;
+                return_to_bank(game, player, property, nft_accounts, player_signer, proceeds)?;
             }
-            Ok("NFT Royalty Income")
         }
-        15 => {
-            // Rug Pull Insurance: if in RP zone exit free; else collect 75
-            if player.is_in_rugpull() {
-                player.rugpull_turns_remaining = 0;
-                player.status = crate::state::PlayerStatus::Active;
-                emit!(crate::events::RugPullExited {
-                    game_id: game.game_id,
-                    player: player.wallet,
-                    method: 1,
-                });
+        CardEffect::LoseBridgeOrCollect(amount) => {
+            let (mut holdings, nft_accounts) = load_holdings(game, player, remaining_accounts)?;
+            let bridge = holdings
+                .iter()
+                .position(|p| board.space(p.space_index).space_type == SPACE_TYPE_BRIDGE);
+            if let Some(i) = bridge {
+                let property = holdings.swap_remove(i);
+                return_to_bank(game, player, property, nft_accounts, player_signer, 0)?;
             } else {
-                let cpi_ctx = CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    Transfer {
-                        from: bank_bpoly_ata.to_account_info(),
-                        to: player_bpoly_ata.to_account_info(),
-                        authority: bank_vault.to_account_info(),
-                    },
-                    signer_seeds,
-                );
-                token::transfer(cpi_ctx, 75_000_000)?;
-                player.bpoly_balance = player.bpoly_balance.saturating_add(75_000_000);
+                bank_pays(player, player_bpoly_ata, amount)?;
             }
-            Ok("Rug Pull Insurance")
         }
-        _ => Ok("Unknown Governance Vote"),
     }
+    Ok(())
+}
+
+/// The bank pays the player; nothing moves for a zero amount
+#[allow(clippy::too_many_arguments)]
+fn collect_from_bank<'info>(
+    player: &mut PlayerState,
+    player_bpoly_ata: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    bank_bpoly_ata: &Account<'info, TokenAccount>,
+    bank_vault: &UncheckedAccount<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        Transfer {
+            from: bank_bpoly_ata.to_account_info(),
+            to: player_bpoly_ata.to_account_info(),
+            authority: bank_vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, amount)?;
+    player.bpoly_balance = player.bpoly_balance.saturating_add(amount);
+    Ok(())
+}
+
+/// Another player's accounts, taken from remaining_accounts
+struct Opponent<'info> {
+    state: Account<'info, PlayerState>,
+    bpoly_ata: Account<'info, TokenAccount>,
+}
+
+/// `accounts` must be exactly one [PlayerState, BPOLY ATA] pair per player in
+/// GameState.players other than `player`, in that order. Both are writable.
+fn load_opponents<'info>(
+    game: &GameState,
+    player: &Pubkey,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<Vec<Opponent<'info>>> {
+    let wallets: Vec<Pubkey> = game.players.iter().copied().filter(|p| p != player).collect();
+    require!(
+        accounts.len() == wallets.len() * 2,
+        BlockpolyError::InvalidOpponentAccounts
+    );
+
+    let mut opponents = Vec::with_capacity(wallets.len());
+    for (wallet, pair) in wallets.iter().zip(accounts.chunks(2)) {
+        let (expected, _) = Pubkey::find_program_address(
+            &[SEED_PLAYER_STATE, &game.game_id, wallet.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(pair[0].key(), expected, BlockpolyError::InvalidOpponentAccounts);
+        require!(
+            pair[0].is_writable && pair[1].is_writable,
+            BlockpolyError::InvalidOpponentAccounts
+        );
+
+        let state: Account<'info, PlayerState> = Account::try_from(&pair[0])?;
+        let bpoly_ata: Account<'info, TokenAccount> = Account::try_from(&pair[1])?;
+        require!(
+            bpoly_ata.owner == *wallet && bpoly_ata.mint == game.bpoly_mint,
+            BlockpolyError::InvalidOpponentAccounts
+        );
+        opponents.push(Opponent { state, bpoly_ata });
+    }
+    Ok(opponents)
+}
+
+/// Whether the bank vault, as the delegate players approve on joining, can
+/// take `amount` from the ATA
+fn bank_can_take(ata: &TokenAccount, bank_vault: &Pubkey, amount: u64) -> bool {
+    ata.amount >= amount
+        && ata.delegate == COption::Some(*bank_vault)
+        && ata.delegated_amount >= amount
+}
+
+/// `accounts` must start with the PropertyState of every space in
+/// properties_owned, in that order. Returns them and the accounts after them.
+fn load_holdings<'info>(
+    game: &GameState,
+    player: &PlayerState,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<(Vec<Account<'info, PropertyState>>, &'info [AccountInfo<'info>])> {
+    let owned = player.properties_owned.len();
+    require!(accounts.len() >= owned, BlockpolyError::InvalidHoldingsAccounts);
+
+    let mut holdings = Vec::with_capacity(owned);
+    for (&space, info) in player.properties_owned.iter().zip(accounts.iter()) {
+        let (expected, _) = Pubkey::find_program_address(
+            &[SEED_PROPERTY_STATE, &game.game_id, &[space]],
+            &crate::ID,
+        );
+        require_keys_eq!(info.key(), expected, BlockpolyError::InvalidHoldingsAccounts);
+        require!(info.is_writable, BlockpolyError::InvalidHoldingsAccounts);
+
+        let property: Account<'info, PropertyState> = Account::try_from(info)?;
+        require_keys_eq!(property.owner, player.wallet, BlockpolyError::InvalidHoldingsAccounts);
+        holdings.push(property);
+    }
+    Ok((holdings, &accounts[owned..]))
+}
+
+/// First space of `space_type` ahead of `position` with no PropertyState, i.e.
+/// still owned by the bank. `accounts` hold the PropertyState PDA of every
+/// space checked, nearest first.
+fn nearest_bank_owned(
+    game: &GameState,
+    board: &BoardConfig,
+    position: u8,
+    space_type: u8,
+    accounts: &[AccountInfo],
+) -> Result<Option<u8>> {
+    let mut accounts = accounts.iter();
+    let candidates = (1..BOARD_SIZE)
+        .map(|step| (position + step) % BOARD_SIZE)
+        .filter(|&i| board.space(i).space_type == space_type);
+    for space in candidates {
+        let info = accounts.next().ok_or(BlockpolyError::InvalidPropertyAccount)?;
+        let (expected, _) = Pubkey::find_program_address(
+            &[SEED_PROPERTY_STATE, &game.game_id, &[space]],
+            &crate::ID,
+        );
+        require_keys_eq!(info.key(), expected, BlockpolyError::InvalidPropertyAccount);
+        if info.data_is_empty() {
+            return Ok(Some(space));
+        }
+    }
+    Ok(None)
+}
+
+/// (LPs, Full Protocols) across the holdings; a Full Protocol is not counted as LPs
+fn count_buildings(holdings: &[Account<PropertyState>]) -> (u64, u64) {
+    holdings.iter().fold((0, 0), |(lps, protocols), p| {
+        if p.is_full_protocol {
+            (lps, protocols + 1)
+        } else {
+            (lps + p.liquidity_pools as u64, protocols)
+        }
+    })
+}
+
+/// Colour groups with an LP or Full Protocol on any of the holdings. As in
+/// Monopoly, cards only take properties from groups without buildings.
+fn built_groups(board: &BoardConfig, holdings: &[Account<PropertyState>]) -> Vec<u8> {
+    holdings
+        .iter()
+        .filter(|p| p.has_buildings())
+        .map(|p| board.space(p.space_index).group)
+        .collect()
+}
+
+/// Hands a property back to the bank: burns its NFT (auction wins have none),
+/// closes the PropertyState with its rent going to the player, and drops it
+/// from properties_owned. It must stand in a group without buildings.
+fn return_to_bank<'info>(
+    game: &mut GameState,
+    player: &mut PlayerState,
+    property: Account<'info, PropertyState>,
+    nft_accounts: &'info [AccountInfo<'info>],
+    player_signer: &Signer<'info>,
+    proceeds: u64,
+) -> Result<()> {
+    if property.nft_asset != Pubkey::default() {
+        let [asset, collection, mpl_core_program] = nft_accounts else {
+            return err!(BlockpolyError::InvalidHoldingsAccounts);
+        };
+        burn_property_nft(game, &property, asset, collection, mpl_core_program, player_signer)?;
+    }
+
+    let space = property.space_index;
+    player.remove_property(space);
+    property.close(player_signer.to_account_info())?;
+
+    emit!(PropertyReturnedToBank {
+        game_id: game.game_id,
+        player: player.wallet,
+        space,
+        proceeds,
+    });
+    Ok(())
+}
+
+/// Burns the mpl-core NFT of a property the player is giving up
+pub(crate) fn burn_property_nft<'info>(
+    game: &GameState,
+    property: &PropertyState,
+    asset: &AccountInfo<'info>,
+    collection: &AccountInfo<'info>,
+    mpl_core_program: &AccountInfo<'info>,
+    player_signer: &Signer<'info>,
+) -> Result<()> {
+    require_keys_eq!(asset.key(), property.nft_asset, BlockpolyError::InvalidHoldingsAccounts);
+    require_keys_eq!(collection.key(), game.nft_collection, BlockpolyError::InvalidCollection);
+    require_keys_eq!(
+        mpl_core_program.key(),
+        MPL_CORE_PROGRAM,
+        BlockpolyError::InvalidHoldingsAccounts
+    );
+
+    // mpl-core BurnV1: discriminator 12, compression_proof None.
+    // Optional accounts left out are passed as the program id.
+    let burn_ix = Instruction {
+        program_id: MPL_CORE_PROGRAM,
+        accounts: vec![
+            AccountMeta::new(asset.key(), false),                     // asset
+            AccountMeta::new(collection.key(), false),                // collection
+            AccountMeta::new(player_signer.key(), true),              // payer
+            AccountMeta::new_readonly(player_signer.key(), true),     // authority (asset owner)
+            AccountMeta::new_readonly(MPL_CORE_PROGRAM, false),       // system_program: None
+            AccountMeta::new_readonly(MPL_CORE_PROGRAM, false),       // log_wrapper: None
+        ],
+        data: vec![12, 0],
+    };
+    invoke(
+        &burn_ix,
+        &[
+            asset.clone(),
+            collection.clone(),
+            player_signer.to_account_info(),
+            mpl_core_program.clone(),
+        ],
+    )?;
+    Ok(())
 }
@@ -1,14 +1,17 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::board::BOARD;
 use crate::constants::*;
 use crate::errors::BlockpolyError;
 use crate::events::TaxPaid;
-use crate::state::{GameState, GameStatus, PlayerState, PropertyState, TurnPhase};
+use crate::instructions::settle_debt::pay_or_owe;
+use crate::state::{
+    BoardConfig, EffectSubject, GameState, GameStatus, PlayerState, PropertyState, TurnPhase,
+};
 
 /// Resolves what happens when a player lands on a space.
-/// Handles: Genesis salary, tax spaces, free parking, Rug Pull Zone (just visiting),
-/// unowned property (→ BuyDecision), owned property (→ pay_rent), card spaces (→ DrawCard).
+/// Handles: Genesis salary (passed or landed on), tax spaces (→ RaiseFunds if short), free parking, Rug Pull Zone (just visiting),
+/// unowned property (→ BuyDecision), opponent's property (→ PayRent), own or mortgaged property
+/// (→ next turn), card spaces (→ DrawCard).
 /// The frontend calls this after consume_randomness emits DiceRolled.
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32])]
@@ -23,6 +26,10 @@ pub struct ResolveLanding<'info> {
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
     #[account(
         mut,
         seeds = [SEED_PLAYER_STATE, &game_id, player.key().as_ref()],
@@ -31,6 +38,12 @@ pub struct ResolveLanding<'info> {
     )]
     pub player_state: Account<'info, PlayerState>,
 
+    /// PropertyState PDA of the space landed on. Required on property, bridge and
+    /// utility spaces (uninitialized = bank-owned); pass None anywhere else.
+    /// CHECK: address derived from the player's position; deserialized when initialized
+    #[account(seeds = [SEED_PROPERTY_STATE, &game_id, &[player_state.position]], bump)]
+    pub property_state: Option<UncheckedAccount<'info>>,
+
     /// Bank vault PDA (signer for bank-side transfers)
     /// CHECK: seeds verified
     #[account(seeds = [SEED_BANK_VAULT, &game_id], bump)]
@@ -61,63 +74,72 @@ pub fn handler(ctx: Context<ResolveLanding>, game_id: [u8; 32]) -> Result<()> {
     );
 
     let position = player_state.position;
-    let space = &BOARD[position as usize];
-    let dice = game.pending_dice.unwrap_or([1, 1]);
-    let passed_genesis = {
-        // We re-derive based on stored pending_dice vs previous position being > current
-        // The DiceRolled event already reported passed_genesis; here we track it via position context
-        // Simple heuristic: if position < (position - dice total) % 40
-        false // will be handled properly by the event; here we only care if ON space 0
-    };
-
-    // Handle passing Genesis Block (position == 0 means landed ON it, which also pays)
-    // The actual salary transfer happens here for tax/free spaces and if they LANDED on Genesis
+    let space = ctx.accounts.board_config.space(position);
     let bank_vault_bump = ctx.bumps.bank_vault;
     let signer_seeds: &[&[&[u8]]] = &[&[SEED_BANK_VAULT, &game_id, &[bank_vault_bump]]];
 
-    // Helper: transfer from bank to player
-    let transfer_from_bank = |amount: u64| -> Result<()> {
-        let cpi_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.bank_bpoly_ata.to_account_info(),
-                to: ctx.accounts.player_bpoly_ata.to_account_info(),
-                authority: ctx.accounts.bank_vault.to_account_info(),
-            },
-            signer_seeds,
-        );
-        token::transfer(cpi_ctx, amount)
-    };
+    // Salary for passing or landing on Genesis Block, whatever the space does next;
+    // paid first so it counts toward anything owed below
+    pay_genesis_salary(
+        game,
+        player_state,
+        &ctx.accounts.token_program,
+        &ctx.accounts.bank_bpoly_ata,
+        &ctx.accounts.player_bpoly_ata,
+        &ctx.accounts.bank_vault,
+        signer_seeds,
+    )?;
+
+    // Forced bail after a third failed doubles attempt, owed before the landing resolves
+    if player_state.rugpull_bail_due {
+        player_state.rugpull_bail_due = false;
+        let bail = game.rules.rugpull_bail;
+        let paid = pay_or_owe(
+            game,
+            player_state,
+            &mut ctx.accounts.player_bpoly_ata,
+            &ctx.accounts.bank_bpoly_ata,
+            &ctx.accounts.player,
+            &ctx.accounts.token_program,
+            None,
+            bail,
+            DEBT_REASON_BAIL,
+        )?;
+        if !paid {
+            // settle_debt hands the turn back to LandingEffect
+            return Ok(());
+        }
+    }
 
     match space.space_type {
         SPACE_TYPE_GENESIS => {
-            // Landed ON Genesis Block: collect 200 BPOLY salary
-            transfer_from_bank(GENESIS_SALARY)?;
-            player_state.bpoly_balance = player_state.bpoly_balance.saturating_add(GENESIS_SALARY);
+            // Landed ON Genesis Block: salary already paid above
             game.advance_turn();
         }
         SPACE_TYPE_TAX => {
-            // Gas Fees Tax (200) or Protocol Fee (100)
-            let tax_amount = space.base_rent; // base_rent stores tax amount
-            // Transfer from player to bank
-            let cpi_ctx = CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.player_bpoly_ata.to_account_info(),
-                    to: ctx.accounts.bank_bpoly_ata.to_account_info(),
-                    authority: ctx.accounts.player.to_account_info(),
-                },
-            );
-            token::transfer(cpi_ctx, tax_amount)?;
-            player_state.bpoly_balance = player_state.bpoly_balance.saturating_sub(tax_amount);
-
-            emit!(TaxPaid {
-                game_id,
-                player: ctx.accounts.player.key(),
-                space: position,
-                amount: tax_amount,
-            });
-            game.advance_turn();
+            // The board's tax for this space, unless the game's RuleSet overrides it
+            let tax_amount = game
+                .apply_market_effects(EffectSubject::Tax, game.rules.tax_for(position, space));
+            let paid = pay_or_owe(
+                game,
+                player_state,
+                &mut ctx.accounts.player_bpoly_ata,
+                &ctx.accounts.bank_bpoly_ata,
+                &ctx.accounts.player,
+                &ctx.accounts.token_program,
+                None,
+                tax_amount,
+                DEBT_REASON_TAX,
+            )?;
+            if paid {
+                emit!(TaxPaid {
+                    game_id,
+                    player: ctx.accounts.player.key(),
+                    space: position,
+                    amount: tax_amount,
+                });
+                game.advance_turn();
+            }
         }
         SPACE_TYPE_FREE_PARKING | SPACE_TYPE_RUGPULL => {
             // Nothing happens — just visiting
@@ -134,9 +156,24 @@ pub fn handler(ctx: Context<ResolveLanding>, game_id: [u8; 32]) -> Result<()> {
             game.turn_phase = TurnPhase::DrawCard;
         }
         SPACE_TYPE_PROPERTY | SPACE_TYPE_BRIDGE | SPACE_TYPE_UTILITY => {
-            // Check if property exists (is owned)
-            // If no PropertyState PDA → bank-owned → give player option to buy
-            game.turn_phase = TurnPhase::BuyDecision;
+            let info = ctx
+                .accounts
+                .property_state
+                .as_ref()
+                .ok_or(BlockpolyError::InvalidPropertyAccount)?;
+            if info.data_is_empty() {
+                // No PropertyState yet → bank-owned → give player option to buy
+                game.turn_phase = TurnPhase::BuyDecision;
+            } else {
+                require_keys_eq!(*info.owner, crate::ID, BlockpolyError::InvalidPropertyAccount);
+                let property = PropertyState::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+                if property.owner == player_state.wallet || property.is_mortgaged {
+                    // Own space, or no rent while mortgaged
+                    game.advance_turn();
+                } else {
+                    game.turn_phase = TurnPhase::PayRent;
+                }
+            }
         }
         _ => {
             game.advance_turn();
@@ -145,3 +182,37 @@ pub fn handler(ctx: Context<ResolveLanding>, game_id: [u8; 32]) -> Result<()> {
 
     Ok(())
 }
+
+/// Pays the RuleSet salary from the bank if the player's last move passed or
+/// landed on Genesis Block, and clears the flag. Every move that can wrap the
+/// board goes through PlayerState::advance_to, so salary is only ever paid here.
+pub(crate) fn pay_genesis_salary<'info>(
+    game: &GameState,
+    player: &mut PlayerState,
+    token_program: &Program<'info, Token>,
+    bank_bpoly_ata: &Account<'info, TokenAccount>,
+    player_bpoly_ata: &Account<'info, TokenAccount>,
+    bank_vault: &UncheckedAccount<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if !player.passed_genesis_pending {
+        return Ok(());
+    }
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        Transfer {
+            from: bank_bpoly_ata.to_account_info(),
+            to: player_bpoly_ata.to_account_info(),
+            authority: bank_vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+    let salary = game.apply_market_effects(
+        EffectSubject::Salary,
+        game.rules.salary_for(player.position),
+    );
+    token::transfer(cpi_ctx, salary)?;
+    player.bpoly_balance = player.bpoly_balance.saturating_add(salary);
+    player.passed_genesis_pending = false;
+    Ok(())
+}
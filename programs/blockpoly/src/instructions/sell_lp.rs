@@ -1,11 +1,15 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::board::BOARD;
 use crate::constants::*;
 use crate::errors::BlockpolyError;
 use crate::events::LPSold;
-use crate::state::{GameState, PropertyState};
+use crate::instructions::build_lp::SiblingBuildings;
+use crate::state::{BoardConfig, GameState, GameStatus, PropertyState};
 
+/// Sells an LP back to the bank for half the LP cost. remaining_accounts must
+/// hold the other PropertyStates of the colour group, as for build_lp;
+/// selling must keep the group even. The LP goes back to the bank's stock.
+/// A Full Protocol is sold with sell_protocol.
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32], space_index: u8)]
 pub struct SellLP<'info> {
@@ -13,11 +17,16 @@ pub struct SellLP<'info> {
     pub player: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [SEED_GAME_STATE, &game_id],
         bump = game_state.bump,
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
     #[account(
         mut,
         seeds = [SEED_PROPERTY_STATE, &game_id, &[space_index]],
@@ -43,26 +52,35 @@ pub struct SellLP<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(
-    ctx: Context<SellLP>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SellLP<'info>>,
     game_id: [u8; 32],
     space_index: u8,
 ) -> Result<()> {
-    let game = &ctx.accounts.game_state;
     let property = &mut ctx.accounts.property_state;
 
-    require!(property.has_buildings(), BlockpolyError::WrongTurnPhase);
+    require!(
+        ctx.accounts.game_state.status == GameStatus::InProgress,
+        BlockpolyError::GameNotStarted
+    );
+    require!(!property.is_full_protocol, BlockpolyError::FullProtocolBuilt);
+    require!(property.liquidity_pools > 0, BlockpolyError::WrongTurnPhase);
+
+    let space = ctx.accounts.board_config.space(space_index);
+    let siblings = SiblingBuildings::load(
+        &game_id,
+        &ctx.accounts.board_config.group_spaces(space.group),
+        space_index,
+        &property.owner,
+        ctx.remaining_accounts,
+    )?;
+    siblings.require_even_sell(property)?;
 
-    let space = &BOARD[space_index as usize];
     // Sell back at half LP cost
     let refund = space.lp_cost / 2;
 
-    if property.is_full_protocol {
-        property.is_full_protocol = false;
-        // liquidity_pools stays at 4
-    } else {
-        property.liquidity_pools -= 1;
-    }
+    let level = property.liquidity_pools - 1;
+    ctx.accounts.game_state.sell_buildings_to(property, level)?;
 
     let bank_vault_bump = ctx.bumps.bank_vault;
     let signer_seeds: &[&[&[u8]]] = &[&[SEED_BANK_VAULT, &game_id, &[bank_vault_bump]]];
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::constants::*;
+use crate::errors::BlockpolyError;
+use crate::events::{LPSold, ProtocolSold};
+use crate::instructions::build_lp::SiblingBuildings;
+use crate::state::{BoardConfig, GameState, GameStatus, PropertyState};
+
+/// Sells a property's Full Protocol back to the bank, leaving it `lp_count`
+/// LPs: 4 for a plain downgrade, fewer to liquidate the group or when the
+/// bank can't supply the LPs the protocol breaks into. Each level sold
+/// refunds half the LP cost of the property it comes off. remaining_accounts must hold the other
+/// PropertyStates of the colour group, as for build_lp; any sibling more than
+/// one level above `lp_count` is sold down with it (and must be writable) so
+/// the group stays even.
+#[derive(Accounts)]
+#[instruction(game_id: [u8; 32], space_index: u8)]
+pub struct SellProtocol<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GAME_STATE, &game_id],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PROPERTY_STATE, &game_id, &[space_index]],
+        bump = property_state.bump,
+        constraint = property_state.owner == player.key() @ BlockpolyError::NotPropertyOwner,
+    )]
+    pub property_state: Account<'info, PropertyState>,
+
+    /// CHECK: bank vault PDA
+    #[account(seeds = [SEED_BANK_VAULT, &game_id], bump)]
+    pub bank_vault: UncheckedAccount<'info>,
+
+    #[account(mut, address = game_state.bank_bpoly_ata)]
+    pub bank_bpoly_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_state.bpoly_mint,
+        associated_token::authority = player,
+    )]
+    pub player_bpoly_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SellProtocol<'info>>,
+    game_id: [u8; 32],
+    space_index: u8,
+    lp_count: u8,
+) -> Result<()> {
+    let property = &mut ctx.accounts.property_state;
+    let player = ctx.accounts.player.key();
+
+    require!(
+        ctx.accounts.game_state.status == GameStatus::InProgress,
+        BlockpolyError::GameNotStarted
+    );
+    require!(property.is_full_protocol, BlockpolyError::NoFullProtocol);
+    require!(lp_count <= 4, BlockpolyError::InvalidLpCount);
+
+    let board = &ctx.accounts.board_config;
+    let space = board.space(space_index);
+    let siblings = SiblingBuildings::load(
+        &game_id,
+        &board.group_spaces(space.group),
+        space_index,
+        &property.owner,
+        ctx.remaining_accounts,
+    )?;
+    require!(
+        siblings.levels.iter().all(|&l| l + 1 >= lp_count),
+        BlockpolyError::UnevenSelling
+    );
+
+    // Siblings above lp_count + 1 come down to it. Those holding LPs go first
+    // so the LPs they return can go into the protocols broken after them.
+    let mut sold_down: Vec<Account<'info, PropertyState>> = Vec::new();
+    for (info, &level) in ctx.remaining_accounts.iter().zip(siblings.levels.iter()) {
+        if level > lp_count + 1 {
+            sold_down.push(Account::try_from(info)?);
+        }
+    }
+    sold_down.sort_by_key(|s| s.is_full_protocol);
+
+    // Sell back at half LP cost per level, each property at its own LP cost
+    let game = &mut ctx.accounts.game_state;
+    let mut refund = 0u64;
+    for sibling in sold_down.iter_mut() {
+        let was_protocol = sibling.is_full_protocol;
+        let levels_sold = game.sell_buildings_to(sibling, lp_count + 1)? as u64;
+        refund += board.space(sibling.space_index).lp_cost / 2 * levels_sold;
+        sibling.exit(&crate::ID)?;
+
+        if was_protocol {
+            emit!(ProtocolSold {
+                game_id,
+                player,
+                space: sibling.space_index,
+                lp_count: sibling.liquidity_pools,
+            });
+        } else {
+            emit!(LPSold {
+                game_id,
+                player,
+                space: sibling.space_index,
+                lp_count: sibling.liquidity_pools,
+            });
+        }
+    }
+    let levels_sold = game.sell_buildings_to(property, lp_count)? as u64;
+    refund += space.lp_cost / 2 * levels_sold;
+
+    let bank_vault_bump = ctx.bumps.bank_vault;
+    let signer_seeds: &[&[&[u8]]] = &[&[SEED_BANK_VAULT, &game_id, &[bank_vault_bump]]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.bank_bpoly_ata.to_account_info(),
+            to: ctx.accounts.player_bpoly_ata.to_account_info(),
+            authority: ctx.accounts.bank_vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, refund)?;
+
+    emit!(ProtocolSold {
+        game_id,
+        player,
+        space: space_index,
+        lp_count,
+    });
+
+    Ok(())
+}
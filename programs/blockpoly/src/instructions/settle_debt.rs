@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::constants::*;
+use crate::errors::BlockpolyError;
+use crate::events::{DebtIncurred, DebtSettled};
+use crate::state::{GameState, GameStatus, PlayerState, TurnPhase};
+
+/// Pays off a player's debt in full. For the current player in RaiseFunds this
+/// resumes the turn; debts run up on other players' cards can be paid any time.
+/// creditor_bpoly_ata is the creditor's BPOLY ATA, or the bank's when the
+/// debt is owed to the bank.
+#[derive(Accounts)]
+#[instruction(game_id: [u8; 32])]
+pub struct SettleDebt<'info> {
+    #[account(mut)]
+    pub debtor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GAME_STATE, &game_id],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PLAYER_STATE, &game_id, debtor.key().as_ref()],
+        bump = debtor_state.bump,
+        constraint = debtor_state.wallet == debtor.key() @ BlockpolyError::NotYourTurn,
+    )]
+    pub debtor_state: Account<'info, PlayerState>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_state.bpoly_mint,
+        associated_token::authority = debtor,
+    )]
+    pub debtor_bpoly_ata: Account<'info, TokenAccount>,
+
+    /// Verified against debtor_state.debt_creditor in the handler
+    #[account(mut)]
+    pub creditor_bpoly_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<SettleDebt>, game_id: [u8; 32]) -> Result<()> {
+    let game = &mut ctx.accounts.game_state;
+    let debtor = &mut ctx.accounts.debtor_state;
+    let creditor_ata = &ctx.accounts.creditor_bpoly_ata;
+
+    require!(game.status == GameStatus::InProgress, BlockpolyError::GameNotStarted);
+    require!(debtor.has_debt(), BlockpolyError::NoDebt);
+    let resumes_turn = game.turn_phase == TurnPhase::RaiseFunds
+        && game.current_player_index == debtor.player_index;
+
+    match debtor.debt_creditor {
+        Some(creditor) => require!(
+            creditor_ata.owner == creditor && creditor_ata.mint == game.bpoly_mint,
+            BlockpolyError::InvalidCreditorAccount
+        ),
+        None => require_keys_eq!(
+            creditor_ata.key(),
+            game.bank_bpoly_ata,
+            BlockpolyError::InvalidCreditorAccount
+        ),
+    }
+
+    let amount = debtor.debt_amount;
+    require!(
+        ctx.accounts.debtor_bpoly_ata.amount >= amount,
+        BlockpolyError::InsufficientBalance
+    );
+
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.debtor_bpoly_ata.to_account_info(),
+            to: creditor_ata.to_account_info(),
+            authority: ctx.accounts.debtor.to_account_info(),
+        },
+    );
+    token::transfer(cpi_ctx, amount)?;
+    debtor.bpoly_balance = debtor.bpoly_balance.saturating_sub(amount);
+
+    let creditor = debtor.debt_creditor;
+    let reason = debtor.debt_reason;
+    debtor.clear_debt();
+
+    if reason == DEBT_REASON_RENT {
+        // Track last rent for 51% Attack card
+        game.last_rent_payer = Some(ctx.accounts.debtor.key());
+        game.last_rent_amount = amount;
+    }
+
+    emit!(DebtSettled {
+        game_id,
+        debtor: ctx.accounts.debtor.key(),
+        creditor,
+        amount,
+    });
+
+    if !resumes_turn {
+        return Ok(());
+    }
+
+    match reason {
+        // Bail was owed before the landing; the landing itself is still unresolved
+        DEBT_REASON_BAIL => game.turn_phase = TurnPhase::LandingEffect,
+        // Market event fees and defaulted flash loans are owed before the roll
+        DEBT_REASON_MARKET_EVENT | DEBT_REASON_FLASH_LOAN => {
+            game.turn_phase = TurnPhase::RollDice
+        }
+        _ => game.advance_turn(),
+    }
+
+    Ok(())
+}
+
+/// Charges `amount` from the player's ATA to `to`. If the ATA can't cover it,
+/// nothing moves: the whole amount is recorded as the player's debt and the
+/// game enters RaiseFunds. Returns whether the payment went through.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn pay_or_owe<'info>(
+    game: &mut GameState,
+    player: &mut PlayerState,
+    player_bpoly_ata: &mut Account<'info, TokenAccount>,
+    to: &Account<'info, TokenAccount>,
+    player_signer: &Signer<'info>,
+    token_program: &Program<'info, Token>,
+    creditor: Option<Pubkey>,
+    amount: u64,
+    reason: u8,
+) -> Result<bool> {
+    if amount == 0 {
+        return Ok(true);
+    }
+
+    // Earlier transfers in the same instruction (e.g. salary) are not in the cached amount
+    player_bpoly_ata.reload()?;
+    if player_bpoly_ata.amount < amount {
+        record_debt(game.game_id, player, creditor, amount, reason);
+        game.turn_phase = TurnPhase::RaiseFunds;
+        return Ok(false);
+    }
+
+    let cpi_ctx = CpiContext::new(
+        token_program.to_account_info(),
+        Transfer {
+            from: player_bpoly_ata.to_account_info(),
+            to: to.to_account_info(),
+            authority: player_signer.to_account_info(),
+        },
+    );
+    token::transfer(cpi_ctx, amount)?;
+    player.bpoly_balance = player.bpoly_balance.saturating_sub(amount);
+    Ok(true)
+}
+
+/// Adds `amount` to the player's debt. Callers make sure any existing debt is
+/// owed to the same creditor; the turn phase is left to them.
+pub(crate) fn record_debt(
+    game_id: [u8; 32],
+    player: &mut PlayerState,
+    creditor: Option<Pubkey>,
+    amount: u64,
+    reason: u8,
+) {
+    player.debt_amount = player.debt_amount.saturating_add(amount);
+    player.debt_creditor = creditor;
+    player.debt_reason = reason;
+    emit!(DebtIncurred {
+        game_id,
+        debtor: player.wallet,
+        creditor,
+        amount,
+        reason,
+    });
+}
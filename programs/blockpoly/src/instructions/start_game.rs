@@ -30,17 +30,14 @@ pub fn handler(
     require!(game.status == GameStatus::WaitingForPlayers, BlockpolyError::GameNotWaiting);
     require!(game.player_count >= 2, BlockpolyError::InvalidPlayerCount);
 
-    // Shuffle Alpha Call deck using Fisher-Yates with VRF seed
-    let mut alpha_deck: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
-    fisher_yates_shuffle(&mut alpha_deck, &shuffle_seed, 0);
+    // Shuffle Alpha Call deck (laid out in order by initialize_game) using
+    // Fisher-Yates with VRF seed
+    fisher_yates_shuffle(&mut game.alpha_call_deck, &shuffle_seed, 0);
 
     // Shuffle Governance Vote deck with same seed, different offset
-    let mut gov_deck: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
-    fisher_yates_shuffle(&mut gov_deck, &shuffle_seed, 16);
+    fisher_yates_shuffle(&mut game.governance_deck, &shuffle_seed, 16);
 
-    game.alpha_call_deck = alpha_deck;
     game.alpha_call_index = 0;
-    game.governance_deck = gov_deck;
     game.governance_index = 0;
     game.status = GameStatus::InProgress;
     game.turn_phase = TurnPhase::RollDice;
@@ -57,7 +54,7 @@ pub fn handler(
 }
 
 /// Fisher-Yates shuffle using bytes from seed starting at seed_offset
-fn fisher_yates_shuffle(deck: &mut [u8; 16], seed: &[u8; 32], seed_offset: usize) {
+fn fisher_yates_shuffle(deck: &mut [u8], seed: &[u8; 32], seed_offset: usize) {
     let n = deck.len();
     for i in (1..n).rev() {
         // Use seed bytes cyclically
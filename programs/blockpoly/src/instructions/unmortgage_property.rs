@@ -1,10 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::board::BOARD;
 use crate::constants::*;
 use crate::errors::BlockpolyError;
 use crate::events::PropertyUnmortgaged;
-use crate::state::{GameState, GameStatus, PlayerState, PropertyState};
+use crate::state::{BoardConfig, GameState, PlayerState, PropertyState};
 
 #[derive(Accounts)]
 #[instruction(game_id: [u8; 32], space_index: u8)]
@@ -18,6 +17,10 @@ pub struct UnmortgageProperty<'info> {
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// Board this game is played on
+    #[account(address = game_state.board_config @ BlockpolyError::InvalidBoardConfig)]
+    pub board_config: Account<'info, BoardConfig>,
+
     #[account(
         mut,
         seeds = [SEED_PLAYER_STATE, &game_id, player.key().as_ref()],
@@ -60,8 +63,9 @@ pub fn handler(
     let player_state = &mut ctx.accounts.player_state;
 
     require!(property.is_mortgaged, BlockpolyError::WrongTurnPhase);
+    require!(!player_state.has_debt(), BlockpolyError::DebtOutstanding);
 
-    let space = &BOARD[space_index as usize];
+    let space = ctx.accounts.board_config.space(space_index);
     // Unmortgage costs: mortgage value + 10% interest
     let unmortgage_cost = space.mortgage_value + space.mortgage_value / 10;
 
@@ -1,13 +1,17 @@
 use anchor_lang::prelude::*;
 
 pub mod board;
+pub mod cards;
 pub mod constants;
 pub mod errors;
 pub mod events;
 pub mod instructions;
+pub mod market_events;
 pub mod state;
+pub mod switchboard;
 
 use instructions::*;
+use state::{BoardSpace, CardDef, DiceMode, RuleSet};
 
 declare_id!("AicXQXhiHgzaxTXpbxYEriXSQdBRQNbqWgcMU1N57q9n");
 
@@ -15,16 +19,68 @@ declare_id!("AicXQXhiHgzaxTXpbxYEriXSQdBRQNbqWgcMU1N57q9n");
 pub mod blockpoly {
     use super::*;
 
+    // ── Board configs ─────────────────────────────────────────────────────────
+
+    pub fn create_board_config(ctx: Context<CreateBoardConfig>, board_id: [u8; 32]) -> Result<()> {
+        board_config::handler_create(ctx, board_id)
+    }
+
+    pub fn set_board_spaces(
+        ctx: Context<EditBoardConfig>,
+        board_id: [u8; 32],
+        start_index: u8,
+        spaces: Vec<BoardSpace>,
+    ) -> Result<()> {
+        board_config::handler_set_spaces(ctx, board_id, start_index, spaces)
+    }
+
+    pub fn finalize_board_config(ctx: Context<EditBoardConfig>, board_id: [u8; 32]) -> Result<()> {
+        board_config::handler_finalize(ctx, board_id)
+    }
+
+    // ── Card decks ────────────────────────────────────────────────────────────
+
+    pub fn create_card_deck(ctx: Context<CreateCardDeck>, deck_id: [u8; 32]) -> Result<()> {
+        card_deck::handler_create(ctx, deck_id)
+    }
+
+    pub fn set_deck_cards(
+        ctx: Context<EditCardDeck>,
+        deck_id: [u8; 32],
+        deck_type: u8,
+        start_index: u8,
+        cards: Vec<CardDef>,
+    ) -> Result<()> {
+        card_deck::handler_set_cards(ctx, deck_id, deck_type, start_index, cards)
+    }
+
+    pub fn finalize_card_deck(ctx: Context<EditCardDeck>, deck_id: [u8; 32]) -> Result<()> {
+        card_deck::handler_finalize(ctx, deck_id)
+    }
+
     // ── Game lifecycle ────────────────────────────────────────────────────────
 
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_game(
         ctx: Context<InitializeGame>,
         game_id: [u8; 32],
         max_players: u8,
         entry_fee_lamports: u64,
         nft_collection: Pubkey,
+        randomness_authority: Option<Pubkey>,
+        dice_mode: DiceMode,
+        rules: Option<RuleSet>,
     ) -> Result<()> {
-        initialize_game::handler(ctx, game_id, max_players, entry_fee_lamports, nft_collection)
+        initialize_game::handler(
+            ctx,
+            game_id,
+            max_players,
+            entry_fee_lamports,
+            nft_collection,
+            randomness_authority,
+            dice_mode,
+            rules,
+        )
     }
 
     pub fn join_game(ctx: Context<JoinGame>, game_id: [u8; 32]) -> Result<()> {
@@ -45,12 +101,31 @@ pub mod blockpoly {
         request_dice_roll::handler(ctx, game_id)
     }
 
-    pub fn consume_randomness(
-        ctx: Context<ConsumeRandomness>,
+    pub fn consume_randomness(ctx: Context<ConsumeRandomness>, game_id: [u8; 32]) -> Result<()> {
+        consume_randomness::handler(ctx, game_id)
+    }
+
+    pub fn expire_dice_roll(ctx: Context<ExpireDiceRoll>, game_id: [u8; 32]) -> Result<()> {
+        expire_dice_roll::handler(ctx, game_id)
+    }
+
+    pub fn commit_dice(
+        ctx: Context<CommitDice>,
+        game_id: [u8; 32],
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        commit_reveal_dice::handler_commit(ctx, game_id, commitment)
+    }
+
+    pub fn reveal_dice(ctx: Context<RevealDice>, game_id: [u8; 32], secret: [u8; 32]) -> Result<()> {
+        commit_reveal_dice::handler_reveal(ctx, game_id, secret)
+    }
+
+    pub fn resolve_dice_timeout<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveDiceTimeout<'info>>,
         game_id: [u8; 32],
-        random_bytes: [u8; 32],
     ) -> Result<()> {
-        consume_randomness::handler(ctx, game_id, random_bytes)
+        commit_reveal_dice::handler_timeout(ctx, game_id)
     }
 
     pub fn resolve_landing(ctx: Context<ResolveLanding>, game_id: [u8; 32]) -> Result<()> {
@@ -81,53 +156,82 @@ pub mod blockpoly {
         auction_bid::handler(ctx, game_id, space_index, bid_amount, nft_asset)
     }
 
-    pub fn pay_rent(
-        ctx: Context<PayRent>,
+    pub fn pay_rent<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PayRent<'info>>,
         game_id: [u8; 32],
-        dice_total: u8,
-        bridges_owned: u8,
-        utilities_owned: u8,
     ) -> Result<()> {
-        pay_rent::handler(ctx, game_id, dice_total, bridges_owned, utilities_owned)
+        pay_rent::handler(ctx, game_id)
+    }
+
+    pub fn settle_debt(ctx: Context<SettleDebt>, game_id: [u8; 32]) -> Result<()> {
+        settle_debt::handler(ctx, game_id)
     }
 
     // ── Building ───────────────────────────────────────────────────────────────
 
-    pub fn build_lp(
-        ctx: Context<BuildLP>,
+    pub fn build_lp<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuildLP<'info>>,
         game_id: [u8; 32],
         space_index: u8,
-        sibling_lp_counts: Vec<u8>,
     ) -> Result<()> {
-        build_lp::handler(ctx, game_id, space_index, sibling_lp_counts)
+        build_lp::handler(ctx, game_id, space_index)
     }
 
-    pub fn build_protocol(
-        ctx: Context<BuildProtocol>,
+    pub fn build_protocol<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuildProtocol<'info>>,
         game_id: [u8; 32],
         space_index: u8,
     ) -> Result<()> {
         build_protocol::handler(ctx, game_id, space_index)
     }
 
-    pub fn sell_lp(ctx: Context<SellLP>, game_id: [u8; 32], space_index: u8) -> Result<()> {
+    pub fn sell_lp<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SellLP<'info>>,
+        game_id: [u8; 32],
+        space_index: u8,
+    ) -> Result<()> {
         sell_lp::handler(ctx, game_id, space_index)
     }
 
+    pub fn sell_protocol<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SellProtocol<'info>>,
+        game_id: [u8; 32],
+        space_index: u8,
+        lp_count: u8,
+    ) -> Result<()> {
+        sell_protocol::handler(ctx, game_id, space_index, lp_count)
+    }
+
+    pub fn bid_building<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BidBuilding<'info>>,
+        game_id: [u8; 32],
+        kind: u8,
+        amount: u64,
+    ) -> Result<()> {
+        bid_building::handler(ctx, game_id, kind, amount)
+    }
+
     // ── Cards ─────────────────────────────────────────────────────────────────
 
     pub fn draw_card(ctx: Context<DrawCard>, game_id: [u8; 32]) -> Result<(u8, u8)> {
         draw_card::handler(ctx, game_id)
     }
 
-    pub fn resolve_card(
-        ctx: Context<ResolveCard>,
+    pub fn resolve_card<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveCard<'info>>,
         game_id: [u8; 32],
-        deck_type: u8,
-        card_id: u8,
-        extra_param: u64,
     ) -> Result<()> {
-        resolve_card::handler(ctx, game_id, deck_type, card_id, extra_param)
+        resolve_card::handler(ctx, game_id)
+    }
+
+    pub fn repay_flash_loan(ctx: Context<RepayFlashLoan>, game_id: [u8; 32]) -> Result<()> {
+        repay_flash_loan::handler(ctx, game_id)
+    }
+
+    // ── Market events ─────────────────────────────────────────────────────────
+
+    pub fn apply_market_event(ctx: Context<ApplyMarketEvent>, game_id: [u8; 32]) -> Result<()> {
+        apply_market_event::handler(ctx, game_id)
     }
 
     // ── Rug Pull Zone ─────────────────────────────────────────────────────────
@@ -170,6 +274,7 @@ pub mod blockpoly {
 
     // ── Trading ───────────────────────────────────────────────────────────────
 
+    #[allow(clippy::too_many_arguments)]
     pub fn propose_trade(
         ctx: Context<ProposeTrade>,
         game_id: [u8; 32],
@@ -194,7 +299,10 @@ pub mod blockpoly {
         )
     }
 
-    pub fn accept_trade(ctx: Context<AcceptTrade>, game_id: [u8; 32]) -> Result<()> {
+    pub fn accept_trade<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AcceptTrade<'info>>,
+        game_id: [u8; 32],
+    ) -> Result<()> {
         accept_trade::handler(ctx, game_id)
     }
 
@@ -204,12 +312,11 @@ pub mod blockpoly {
 
     // ── End game ──────────────────────────────────────────────────────────────
 
-    pub fn declare_bankruptcy(
-        ctx: Context<DeclareBankruptcy>,
+    pub fn declare_bankruptcy<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DeclareBankruptcy<'info>>,
         game_id: [u8; 32],
-        creditor: Option<Pubkey>,
     ) -> Result<()> {
-        declare_bankruptcy::handler(ctx, game_id, creditor)
+        declare_bankruptcy::handler(ctx, game_id)
     }
 
     pub fn claim_prize(ctx: Context<ClaimPrize>, game_id: [u8; 32]) -> Result<()> {
@@ -0,0 +1,88 @@
+/// Market event table. With RuleSet.market_events on, one event is drawn at
+/// the start of every round (GameState::advance_turn) and each player applies
+/// it with apply_market_event before their first roll of the round.
+/// Separate from the Alpha Call and Governance Vote decks.
+use crate::constants::*;
+use crate::state::{EffectModifier, EffectTarget, MarketEffectKind};
+
+pub enum MarketEventEffect {
+    /// Every player pays the bank
+    PlayersPay(u64),
+    /// The bank pays every player
+    PlayersCollect(u64),
+    /// Starts a market effect lasting `rounds` rounds, including the current one
+    Global {
+        kind: MarketEffectKind,
+        target: EffectTarget,
+        modifier: EffectModifier,
+        rounds: u32,
+    },
+}
+
+pub struct MarketEventData {
+    pub name: &'static str,
+    pub effect: MarketEventEffect,
+}
+
+/// Scale a BPOLY value from display units to micro-units
+const fn bpoly(n: u64) -> u64 {
+    n * 1_000_000
+}
+
+pub const MARKET_EVENTS: [MarketEventData; 6] = [
+    // 0: every player pays 20 BPOLY
+    MarketEventData {
+        name: "Network Congestion",
+        effect: MarketEventEffect::PlayersPay(bpoly(20)),
+    },
+    // 1: the bank pays every player 50 BPOLY
+    MarketEventData {
+        name: "Treasury Airdrop",
+        effect: MarketEventEffect::PlayersCollect(bpoly(50)),
+    },
+    // 2: Brown rent doubled this round
+    MarketEventData {
+        name: "Memecoin Mania",
+        effect: MarketEventEffect::Global {
+            kind: MarketEffectKind::SectorRally,
+            target: EffectTarget::GroupRent(GROUP_BROWN),
+            modifier: EffectModifier::MultiplyBps(20_000),
+            rounds: 1,
+        },
+    },
+    // 3: Green rent +50% this round
+    MarketEventData {
+        name: "DeFi Rally",
+        effect: MarketEventEffect::Global {
+            kind: MarketEffectKind::SectorRally,
+            target: EffectTarget::GroupRent(GROUP_GREEN),
+            modifier: EffectModifier::MultiplyBps(15_000),
+            rounds: 1,
+        },
+    },
+    // 4: all rent halved this round
+    MarketEventData {
+        name: "Bear Market",
+        effect: MarketEventEffect::Global {
+            kind: MarketEffectKind::BearMarket,
+            target: EffectTarget::Rent,
+            modifier: EffectModifier::MultiplyBps(5_000),
+            rounds: 1,
+        },
+    },
+    // 5: taxes +50% for two rounds
+    MarketEventData {
+        name: "Gas Spike",
+        effect: MarketEventEffect::Global {
+            kind: MarketEffectKind::GasSpike,
+            target: EffectTarget::Tax,
+            modifier: EffectModifier::MultiplyBps(15_000),
+            rounds: 2,
+        },
+    },
+];
+
+/// Picks an event from randomness left over by the latest roll
+pub fn draw_market_event(entropy: u64) -> u8 {
+    (entropy % MARKET_EVENTS.len() as u64) as u8
+}
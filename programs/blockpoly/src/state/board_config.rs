@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::BlockpolyError;
+
+/// One space of a BoardConfig. Same fields as board::SpaceData; the index is
+/// the position in BoardConfig.spaces.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct BoardSpace {
+    /// Display name, also used as the property NFT name (max MAX_SPACE_NAME_LEN bytes)
+    pub name: String,
+    pub space_type: u8,
+    pub group: u8,
+    /// Purchase price (0 if not purchasable)
+    pub price: u64,
+    /// Base rent (unimproved, no monopoly); the amount charged on a tax space
+    pub base_rent: u64,
+    /// Rent with 1-4 LPs
+    pub lp_rents: [u64; 4],
+    /// Full Protocol rent
+    pub protocol_rent: u64,
+    pub mortgage_value: u64,
+    /// Cost to build one LP
+    pub lp_cost: u64,
+    /// Bridge rents: [1 owned, 2 owned, 3 owned, 4 owned]
+    pub bridge_rents: [u64; 4],
+}
+
+impl BoardSpace {
+    pub const SIZE: usize =
+        4 + MAX_SPACE_NAME_LEN + // name
+        1 +         // space_type
+        1 +         // group
+        8 +         // price
+        8 +         // base_rent
+        8 * 4 +     // lp_rents
+        8 +         // protocol_rent
+        8 +         // mortgage_value
+        8 +         // lp_cost
+        8 * 4;      // bridge_rents
+
+    /// Property, bridge or utility
+    pub fn is_ownable(&self) -> bool {
+        matches!(
+            self.space_type,
+            SPACE_TYPE_PROPERTY | SPACE_TYPE_BRIDGE | SPACE_TYPE_UTILITY
+        )
+    }
+}
+
+/// A board layout games can be played on. Created with the default board,
+/// optionally rewritten by its authority, then validated and locked by
+/// finalize_board_config. Only finalized boards can be used by a game.
+#[account]
+pub struct BoardConfig {
+    pub authority: Pubkey,
+    pub board_id: [u8; 32],
+    /// Validated and immutable
+    pub finalized: bool,
+    /// Exactly BOARD_SIZE spaces once finalized
+    pub spaces: Vec<BoardSpace>,
+    pub bump: u8,
+}
+
+impl BoardConfig {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        32 +        // authority
+        32 +        // board_id
+        1 +         // finalized
+        4 + BOARD_SIZE as usize * BoardSpace::SIZE + // spaces
+        1;          // bump
+
+    pub fn space(&self, index: u8) -> &BoardSpace {
+        &self.spaces[index as usize]
+    }
+
+    /// Space indices of a group, in board order
+    pub fn group_spaces(&self, group: u8) -> Vec<u8> {
+        (0..BOARD_SIZE)
+            .filter(|&i| self.space(i).is_ownable() && self.space(i).group == group)
+            .collect()
+    }
+
+    /// First space of `space_type` strictly ahead of `position` (wraps around)
+    pub fn nearest_ahead(&self, position: u8, space_type: u8) -> Option<u8> {
+        (1..BOARD_SIZE)
+            .map(|step| (position + step) % BOARD_SIZE)
+            .find(|&i| self.space(i).space_type == space_type)
+    }
+
+    /// First ownable space of `group` strictly ahead of `position` (wraps around)
+    pub fn nearest_in_group(&self, position: u8, group: u8) -> Option<u8> {
+        (1..BOARD_SIZE)
+            .map(|step| (position + step) % BOARD_SIZE)
+            .find(|&i| self.space(i).is_ownable() && self.space(i).group == group)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.spaces.len() == BOARD_SIZE as usize,
+            BlockpolyError::InvalidBoardSpace
+        );
+
+        let mut ownable = 0usize;
+        for (index, space) in self.spaces.iter().enumerate() {
+            let index = index as u8;
+            require!(space.name.len() <= MAX_SPACE_NAME_LEN, BlockpolyError::InvalidBoardSpace);
+
+            // Movement rules send players to these two corners by index
+            match index {
+                SPACE_GENESIS => require!(
+                    space.space_type == SPACE_TYPE_GENESIS,
+                    BlockpolyError::InvalidBoardSpace
+                ),
+                SPACE_RUGPULL_ZONE => require!(
+                    space.space_type == SPACE_TYPE_RUGPULL,
+                    BlockpolyError::InvalidBoardSpace
+                ),
+                _ => require!(
+                    space.space_type != SPACE_TYPE_GENESIS && space.space_type != SPACE_TYPE_RUGPULL,
+                    BlockpolyError::InvalidBoardSpace
+                ),
+            }
+
+            match space.space_type {
+                SPACE_TYPE_PROPERTY => {
+                    require!(space.group <= GROUP_DARK_BLUE, BlockpolyError::InvalidBoardSpace);
+                    require!(space.lp_cost > 0, BlockpolyError::InvalidBoardSpace);
+                    require!(
+                        space.base_rent <= space.lp_rents[0]
+                            && space.lp_rents.windows(2).all(|w| w[0] <= w[1])
+                            && space.lp_rents[3] <= space.protocol_rent,
+                        BlockpolyError::InvalidBoardSpace
+                    );
+                }
+                SPACE_TYPE_BRIDGE => {
+                    require!(space.group == GROUP_BRIDGE, BlockpolyError::InvalidBoardSpace);
+                    require!(
+                        space.bridge_rents.windows(2).all(|w| w[0] <= w[1]),
+                        BlockpolyError::InvalidBoardSpace
+                    );
+                }
+                SPACE_TYPE_UTILITY => {
+                    require!(space.group == GROUP_UTILITY, BlockpolyError::InvalidBoardSpace);
+                }
+                SPACE_TYPE_CARD_ALPHA
+                | SPACE_TYPE_CARD_GOVERNANCE
+                | SPACE_TYPE_TAX
+                | SPACE_TYPE_RUGPULL
+                | SPACE_TYPE_GO_TO_JAIL
+                | SPACE_TYPE_FREE_PARKING
+                | SPACE_TYPE_GENESIS => {
+                    require!(
+                        space.group == GROUP_NONE && space.price == 0,
+                        BlockpolyError::InvalidBoardSpace
+                    );
+                }
+                _ => return err!(BlockpolyError::InvalidBoardSpace),
+            }
+
+            if space.is_ownable() {
+                require!(
+                    space.price > 0 && space.mortgage_value <= space.price,
+                    BlockpolyError::InvalidBoardSpace
+                );
+                ownable += 1;
+            }
+        }
+
+        // PlayerState.properties_owned holds at most MAX_OWNABLE_SPACES
+        require!(ownable <= MAX_OWNABLE_SPACES, BlockpolyError::InvalidBoardSpace);
+
+        // A colour group needs at least two spaces for a monopoly to mean anything;
+        // no group can exceed the four accounts rent and building read
+        for group in GROUP_BROWN..=GROUP_UTILITY {
+            let size = self.group_spaces(group).len();
+            let min = if group <= GROUP_DARK_BLUE { 2 } else { 1 };
+            require!(
+                size == 0 || (min..=MAX_GROUP_SIZE).contains(&size),
+                BlockpolyError::InvalidBoardSpace
+            );
+        }
+
+        Ok(())
+    }
+}
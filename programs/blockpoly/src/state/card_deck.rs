@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::BlockpolyError;
+use super::{CardModifier, EffectModifier, EffectTarget, MarketEffectKind};
+
+/// What a card does when resolved. Amounts are BPOLY micro-units; "bank"
+/// amounts move between the player and the bank vault.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CardEffect {
+    /// Pay the bank a flat amount
+    PayBank(u64),
+    /// Collect a flat amount from the bank
+    CollectFromBank(u64),
+    /// Pay every other player
+    PayEachPlayer(u64),
+    /// Every other player pays the drawer (recorded as a debt to settle)
+    CollectFromEachPlayer(u64),
+    /// Pay the bank per LP and per Full Protocol owned
+    PayPerBuilding { per_lp: u64, per_protocol: u64 },
+    /// Collect from the bank per LP and per Full Protocol owned
+    CollectPerBuilding { per_lp: u64, per_protocol: u64 },
+    /// Collect from the bank per complete colour group owned
+    CollectPerCompleteGroup(u64),
+    /// Pay the bank per point on the dice that landed on the card
+    PayPerDicePoint(u64),
+    /// Pay the bank a share of the BPOLY balance, in basis points
+    PayBalanceBps(u16),
+    /// Advance to a space, passing Genesis if it is behind
+    MoveTo { space: u8, modifier: CardModifier },
+    /// Advance to the nearest space of a type
+    MoveToNearest { space_type: u8, modifier: CardModifier },
+    /// Advance to the nearest space of a colour group, bridges or utilities
+    MoveToNearestInGroup { group: u8, modifier: CardModifier },
+    /// Move back a number of spaces; never pays salary
+    MoveBack(u8),
+    /// Straight to the Rug Pull Zone
+    GoToJail,
+    /// Get Out of Rug Pull Free, kept until used
+    JailFreeCard,
+    /// Leave the Rug Pull Zone if in it, otherwise collect from the bank
+    LeaveJailOrCollect(u64),
+    /// Start a market effect lasting `rounds` rounds, including the current one
+    AddGlobalEffect {
+        kind: MarketEffectKind,
+        target: EffectTarget,
+        modifier: EffectModifier,
+        rounds: u8,
+    },
+    /// Collect the game's last rent payment from the bank
+    CollectLastRent,
+    /// Take the RuleSet flash loan; declined (FlashLoanDeclined) while one is open
+    FlashLoan,
+    /// The cheapest unmortgaged property in a group without buildings goes
+    /// back to the bank
+    LoseCheapestProperty,
+    /// The most expensive property in a group without buildings is sold to
+    /// the bank for `bps` of its price
+    SellMostExpensiveProperty { bps: u16 },
+    /// A bridge goes back to the bank; without one, collect from the bank
+    LoseBridgeOrCollect(u64),
+    /// Advance to the nearest space of an ownable type the bank still owns;
+    /// nothing happens if every one is owned
+    MoveToNearestUnowned { space_type: u8, modifier: CardModifier },
+}
+
+impl CardEffect {
+    /// Largest variant: PayPerBuilding / CollectPerBuilding
+    pub const SIZE: usize = 1 + 8 + 8;
+
+    pub fn validate(&self) -> Result<()> {
+        let valid = match *self {
+            CardEffect::PayBalanceBps(bps) | CardEffect::SellMostExpensiveProperty { bps } => {
+                bps <= 10_000
+            }
+            CardEffect::MoveTo { space, .. } => space < BOARD_SIZE,
+            CardEffect::MoveToNearest { space_type, .. } => space_type <= SPACE_TYPE_GENESIS,
+            CardEffect::MoveToNearestInGroup { group, .. } => group <= GROUP_UTILITY,
+            CardEffect::MoveToNearestUnowned { space_type, .. } => matches!(
+                space_type,
+                SPACE_TYPE_PROPERTY | SPACE_TYPE_BRIDGE | SPACE_TYPE_UTILITY
+            ),
+            CardEffect::MoveBack(steps) => (1..BOARD_SIZE).contains(&steps),
+            CardEffect::AddGlobalEffect { target, rounds, .. } => {
+                rounds > 0 && !matches!(target, EffectTarget::GroupRent(g) if g > GROUP_UTILITY)
+            }
+            _ => true,
+        };
+        require!(valid, BlockpolyError::InvalidCardDeck);
+        Ok(())
+    }
+}
+
+/// One card of a CardDeck; its index in the deck is the card_id
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct CardDef {
+    /// Display name, emitted in CardResolved (max MAX_CARD_NAME_LEN bytes)
+    pub name: String,
+    pub effect: CardEffect,
+}
+
+impl CardDef {
+    pub const SIZE: usize = 4 + MAX_CARD_NAME_LEN + CardEffect::SIZE;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(self.name.len() <= MAX_CARD_NAME_LEN, BlockpolyError::InvalidCardDeck);
+        self.effect.validate()
+    }
+}
+
+/// The Alpha Call and Governance Vote cards games draw from. Created with the
+/// default decks, optionally rewritten by its authority, then validated and
+/// locked by finalize_card_deck. Only finalized decks can be used by a game.
+#[account]
+pub struct CardDeck {
+    pub authority: Pubkey,
+    pub deck_id: [u8; 32],
+    /// Validated and immutable
+    pub finalized: bool,
+    /// 1..=MAX_DECK_CARDS cards each once finalized
+    pub alpha_call: Vec<CardDef>,
+    pub governance: Vec<CardDef>,
+    pub bump: u8,
+}
+
+impl CardDeck {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        32 +        // authority
+        32 +        // deck_id
+        1 +         // finalized
+        4 + MAX_DECK_CARDS * CardDef::SIZE + // alpha_call
+        4 + MAX_DECK_CARDS * CardDef::SIZE + // governance
+        1;          // bump
+
+    /// Cards of DECK_ALPHA_CALL or DECK_GOVERNANCE
+    pub fn cards(&self, deck_type: u8) -> &[CardDef] {
+        if deck_type == DECK_ALPHA_CALL {
+            &self.alpha_call
+        } else {
+            &self.governance
+        }
+    }
+
+    pub fn cards_mut(&mut self, deck_type: u8) -> &mut Vec<CardDef> {
+        if deck_type == DECK_ALPHA_CALL {
+            &mut self.alpha_call
+        } else {
+            &mut self.governance
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        for cards in [&self.alpha_call, &self.governance] {
+            require!(
+                (1..=MAX_DECK_CARDS).contains(&cards.len()),
+                BlockpolyError::InvalidCardDeck
+            );
+            for card in cards {
+                card.validate()?;
+            }
+            // Held Get Out of Rug Pull Free cards leave the deck; something
+            // must always be left to draw
+            require!(
+                cards.iter().any(|c| c.effect != CardEffect::JailFreeCard),
+                BlockpolyError::InvalidCardDeck
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+/// Commit-reveal record for a single roll in a DiceMode::CommitReveal game.
+/// Seeded by the game's turn_number; closed back to the roller once settled.
+#[account]
+#[derive(Default)]
+pub struct DiceCommitment {
+    /// Reference to the game
+    pub game: Pubkey,
+    /// GameState.turn_number this roll belongs to
+    pub turn_number: u32,
+    /// Player whose roll this is (paid the rent, refunded on close)
+    pub roller: Pubkey,
+    /// Players expected to commit, snapshot of GameState.players
+    pub players: Vec<Pubkey>,
+    /// sha256(wallet || secret), indexed like `players`
+    pub commitments: [[u8; 32]; 8],
+    /// Bit i set once players[i] has committed
+    pub committed_mask: u8,
+    /// Bit i set once players[i] has revealed
+    pub revealed_mask: u8,
+    /// XOR of all revealed secrets
+    pub seed: [u8; 32],
+    /// False while collecting commitments, true while collecting reveals
+    pub reveal_open: bool,
+    /// Slot after which resolve_dice_timeout may close the current stage
+    pub deadline_slot: u64,
+    pub bump: u8,
+}
+
+impl DiceCommitment {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        32 +        // game
+        4 +         // turn_number
+        32 +        // roller
+        4 + 8 * 32 + // players vec
+        8 * 32 +    // commitments
+        1 +         // committed_mask
+        1 +         // revealed_mask
+        32 +        // seed
+        1 +         // reveal_open
+        8 +         // deadline_slot
+        1;          // bump
+
+    /// Commitment a player submits: sha256(wallet || secret). Binding the
+    /// wallet stops a player from copying someone else's commitment.
+    pub fn hash_secret(wallet: &Pubkey, secret: &[u8; 32]) -> [u8; 32] {
+        solana_sha256_hasher::hashv(&[wallet.as_ref(), secret]).to_bytes()
+    }
+
+    pub fn player_slot(&self, wallet: &Pubkey) -> Option<usize> {
+        self.players.iter().position(|p| p == wallet)
+    }
+
+    /// Mask with one bit per expected player
+    pub fn all_mask(&self) -> u8 {
+        ((1u16 << self.players.len()) - 1) as u8
+    }
+
+    pub fn has_committed(&self, slot: usize) -> bool {
+        self.committed_mask & (1 << slot) != 0
+    }
+
+    pub fn has_revealed(&self, slot: usize) -> bool {
+        self.revealed_mask & (1 << slot) != 0
+    }
+}
@@ -1,4 +1,12 @@
 use anchor_lang::prelude::*;
+use crate::constants::{
+    BUILDING_AUCTION_CLAIM_SLOTS, BUILDING_LP, DECK_ALPHA_CALL, MAX_DECK_CARDS,
+    MAX_MARKET_EFFECTS, MAX_PLAYERS,
+};
+use crate::errors::BlockpolyError;
+use crate::events::{MarketEffectExpired, MarketEventDrawn};
+use crate::market_events::draw_market_event;
+use super::{EffectSubject, MarketEffect, PropertyState, RuleSet};
 
 #[account]
 #[derive(Default)]
@@ -22,21 +30,45 @@ pub struct GameState {
     pub player_count: u8,
     pub max_players: u8,
 
+    /// How dice are rolled in this game
+    pub dice_mode: DiceMode,
     /// Switchboard VRF request account (Some while awaiting randomness)
     pub vrf_request: Option<Pubkey>,
+    /// Seed slot of the committed randomness (guards against re-commits)
+    pub vrf_seed_slot: u64,
+    /// Only signer allowed to call consume_randomness, unless it is the
+    /// default (crank) authority
+    pub randomness_authority: Pubkey,
     /// Dice result set by consume_randomness callback
     pub pending_dice: Option<[u8; 2]>,
+    /// Current player rolled doubles and rolls again instead of passing the turn
+    pub extra_roll: bool,
 
-    /// Alpha Call deck (shuffled indices 0-15)
-    pub alpha_call_deck: [u8; 16],
+    /// Alpha Call draw order: shuffled indices into CardDeck.alpha_call
+    /// (max MAX_DECK_CARDS)
+    pub alpha_call_deck: Vec<u8>,
     pub alpha_call_index: u8,
-    /// Governance Vote deck (shuffled indices 0-15)
-    pub governance_deck: [u8; 16],
+    /// Governance Vote draw order: shuffled indices into CardDeck.governance
+    /// (max MAX_DECK_CARDS)
+    pub governance_deck: Vec<u8>,
     pub governance_index: u8,
+    /// [deck_type, card_id] set by draw_card, taken by resolve_card
+    /// (deck_type 0 = Alpha Call, 1 = Governance Vote)
+    pub drawn_card: Option<[u8; 2]>,
+    /// [deck_type, card_id] of Get Out of Rug Pull Free cards players hold;
+    /// they are out of their decks until used (max MAX_PLAYERS)
+    pub held_cards: Vec<[u8; 2]>,
 
-    /// Global effect: bull run doubles rent
-    pub bull_run_active: bool,
-    pub bull_run_ends_round: u32,
+    /// Timed global effects on rent, taxes, salary and build costs
+    /// (max MAX_MARKET_EFFECTS)
+    pub market_effects: Vec<MarketEffect>,
+    /// Randomness left over by the latest roll; seeds the next market event draw
+    pub last_roll_entropy: u64,
+    /// Market event drawn for market_event_round (index into MARKET_EVENTS)
+    pub market_event: Option<u8>,
+    pub market_event_round: u32,
+    /// The event's global effect has been started by the first player to apply it
+    pub market_event_started: bool,
 
     /// Inline auction state (avoids extra PDA)
     pub auction_space: Option<u8>,
@@ -44,6 +76,13 @@ pub struct GameState {
     pub auction_highest_bidder: Option<Pubkey>,
     pub auction_end_turn: u32,
 
+    /// Bank stock of LPs and Full Protocols; building takes from it, selling
+    /// and liquidation put back
+    pub lps_available: u8,
+    pub protocols_available: u8,
+    /// Shortage auction for the next building of a kind (bid_building)
+    pub building_auction: Option<BuildingAuction>,
+
     /// SOL prize pool (entry fees)
     pub prize_pool_lamports: u64,
     /// Winner, set on game end
@@ -55,11 +94,21 @@ pub struct GameState {
     pub bpoly_mint: Pubkey,
     /// Bank's BPOLY associated token account
     pub bank_bpoly_ata: Pubkey,
+    /// BoardConfig this game is played on
+    pub board_config: Pubkey,
+    /// CardDeck whose cards this game draws
+    pub card_deck: Pubkey,
 
     /// Last rent payment amount (for 51% Attack card)
     pub last_rent_payer: Option<Pubkey>,
     pub last_rent_amount: u64,
 
+    /// Set by a movement card for the landing it leads to; cleared by advance_turn
+    pub card_modifier: CardModifier,
+
+    /// House rules for this game
+    pub rules: RuleSet,
+
     pub bump: u8,
 }
 
@@ -75,53 +124,257 @@ impl GameState {
         4 + 8 * 32 + // players vec
         1 +         // player_count
         1 +         // max_players
+        1 +         // dice_mode
         1 + 32 +    // vrf_request Option<Pubkey>
+        8 +         // vrf_seed_slot
+        32 +        // randomness_authority
         1 + 2 +     // pending_dice Option<[u8;2]>
-        16 +        // alpha_call_deck
+        1 +         // extra_roll
+        4 + MAX_DECK_CARDS + // alpha_call_deck
         1 +         // alpha_call_index
-        16 +        // governance_deck
+        4 + MAX_DECK_CARDS + // governance_deck
         1 +         // governance_index
-        1 +         // bull_run_active
-        4 +         // bull_run_ends_round
+        1 + 2 +     // drawn_card Option<[u8;2]>
+        4 + MAX_PLAYERS as usize * 2 + // held_cards
+        4 + MAX_MARKET_EFFECTS * MarketEffect::SIZE + // market_effects
+        8 +         // last_roll_entropy
+        1 + 1 +     // market_event Option<u8>
+        4 +         // market_event_round
+        1 +         // market_event_started
         1 + 1 +     // auction_space Option<u8>
         8 +         // auction_highest_bid
         1 + 32 +    // auction_highest_bidder Option<Pubkey>
         4 +         // auction_end_turn
+        1 +         // lps_available
+        1 +         // protocols_available
+        1 + BuildingAuction::SIZE + // building_auction
         8 +         // prize_pool_lamports
         1 + 32 +    // winner Option<Pubkey>
         32 +        // nft_collection
         32 +        // bpoly_mint
         32 +        // bank_bpoly_ata
+        32 +        // board_config
+        32 +        // card_deck
         1 + 32 +    // last_rent_payer
         8 +         // last_rent_amount
+        1 +         // card_modifier
+        RuleSet::SIZE + // rules
         1;          // bump
 
     pub fn current_player(&self) -> Option<Pubkey> {
         self.players.get(self.current_player_index as usize).copied()
     }
 
+    /// Ends the current roll. A pending doubles extra roll keeps the same
+    /// player on RollDice; otherwise play passes to the next player.
     pub fn advance_turn(&mut self) {
+        self.card_modifier = CardModifier::None;
+        if self.extra_roll {
+            self.extra_roll = false;
+            self.turn_phase = TurnPhase::RollDice;
+            self.pending_dice = None;
+            return;
+        }
         self.turn_number += 1;
         let next = (self.current_player_index + 1) % self.player_count;
         if next == 0 {
             self.round_number += 1;
+            self.expire_market_effects();
+            if self.rules.market_events {
+                self.draw_market_event();
+            }
         }
         self.current_player_index = next;
         self.turn_phase = TurnPhase::RollDice;
         self.pending_dice = None;
     }
 
+    /// Starts an effect. One of the same kind is replaced (refreshing its
+    /// duration); with the list full, the effect closest to expiring goes.
+    pub fn add_market_effect(&mut self, effect: MarketEffect) {
+        self.market_effects.retain(|e| e.kind != effect.kind);
+        if self.market_effects.len() >= MAX_MARKET_EFFECTS {
+            if let Some(soonest) = self
+                .market_effects
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, e)| e.ends_round)
+                .map(|(i, _)| i)
+            {
+                self.market_effects.remove(soonest);
+            }
+        }
+        self.market_effects.push(effect);
+    }
+
+    /// Applies every active effect covering `subject` to `amount`, in the
+    /// order they started. All rent, tax, salary and build charges go through here.
+    pub fn apply_market_effects(&self, subject: EffectSubject, amount: u64) -> u64 {
+        self.market_effects
+            .iter()
+            .filter(|e| self.round_number <= e.ends_round && e.target.covers(subject))
+            .fold(amount, |acc, e| e.modifier.apply(acc))
+    }
+
+    /// Every player must apply `market_event` before their next roll
+    pub fn market_event_due(&self, player_event_round: u32) -> bool {
+        self.market_event.is_some() && player_event_round < self.market_event_round
+    }
+
+    fn draw_market_event(&mut self) {
+        let event_id = draw_market_event(self.last_roll_entropy);
+        self.market_event = Some(event_id);
+        self.market_event_round = self.round_number;
+        self.market_event_started = false;
+        emit!(MarketEventDrawn {
+            game_id: self.game_id,
+            event_id,
+            round: self.round_number,
+        });
+    }
+
+    fn expire_market_effects(&mut self) {
+        let round = self.round_number;
+        let game_id = self.game_id;
+        self.market_effects.retain(|e| {
+            let active = round <= e.ends_round;
+            if !active {
+                emit!(MarketEffectExpired { game_id, kind: e.kind });
+            }
+            active
+        });
+    }
+
     pub fn next_alpha_card(&mut self) -> u8 {
         let card = self.alpha_call_deck[self.alpha_call_index as usize];
-        self.alpha_call_index = (self.alpha_call_index + 1) % 16;
+        self.alpha_call_index = ((self.alpha_call_index as usize + 1) % self.alpha_call_deck.len()) as u8;
         card
     }
 
     pub fn next_governance_card(&mut self) -> u8 {
         let card = self.governance_deck[self.governance_index as usize];
-        self.governance_index = (self.governance_index + 1) % 16;
+        self.governance_index = ((self.governance_index as usize + 1) % self.governance_deck.len()) as u8;
         card
     }
+
+    /// Draw order and next index of DECK_ALPHA_CALL or DECK_GOVERNANCE
+    fn deck_mut(&mut self, deck_type: u8) -> (&mut Vec<u8>, &mut u8) {
+        if deck_type == DECK_ALPHA_CALL {
+            (&mut self.alpha_call_deck, &mut self.alpha_call_index)
+        } else {
+            (&mut self.governance_deck, &mut self.governance_index)
+        }
+    }
+
+    /// Takes a drawn card a player keeps out of its deck, so it can't be drawn
+    /// again while held
+    pub fn hold_card(&mut self, deck_type: u8, card_id: u8) {
+        let (deck, index) = self.deck_mut(deck_type);
+        let Some(position) = deck.iter().position(|&c| c == card_id) else {
+            return;
+        };
+        deck.remove(position);
+        if position < *index as usize {
+            *index -= 1;
+        }
+        if *index as usize >= deck.len() {
+            *index = 0;
+        }
+        self.held_cards.push([deck_type, card_id]);
+    }
+
+    /// Puts a held card of `deck_type` back under its deck: it is drawn after
+    /// every card now in the deck
+    pub fn return_held_card(&mut self, deck_type: u8) {
+        let Some(position) = self.held_cards.iter().position(|c| c[0] == deck_type) else {
+            return;
+        };
+        let [_, card_id] = self.held_cards.remove(position);
+        let (deck, index) = self.deck_mut(deck_type);
+        deck.insert(*index as usize, card_id);
+        *index += 1;
+    }
+
+    /// Bank stock of BUILDING_LP or BUILDING_PROTOCOL
+    pub fn buildings_available(&self, kind: u8) -> u8 {
+        if kind == BUILDING_LP {
+            self.lps_available
+        } else {
+            self.protocols_available
+        }
+    }
+
+    /// Takes a building of `kind` from the bank for `builder`, returning the
+    /// winning bid they already paid into escrow for it (0 if none). While a
+    /// shortage auction for the kind is open nobody may build it; once it
+    /// closes only the winner may, until their claim lapses.
+    pub fn take_building(&mut self, kind: u8, builder: Pubkey, slot: u64) -> Result<u64> {
+        let mut winning_bid = 0;
+        if let Some(auction) = self.building_auction.filter(|a| a.kind == kind) {
+            require!(slot > auction.end_slot, BlockpolyError::BuildingAuctionActive);
+            if let Some(winner) = auction.highest_bidder {
+                if slot <= auction.end_slot + BUILDING_AUCTION_CLAIM_SLOTS {
+                    require_keys_eq!(builder, winner, BlockpolyError::BuildingAuctionActive);
+                    winning_bid = auction.highest_bid;
+                }
+            }
+            self.building_auction = None;
+        }
+
+        require!(self.buildings_available(kind) > 0, BlockpolyError::BuildingShortage);
+        if kind == BUILDING_LP {
+            self.lps_available -= 1;
+        } else {
+            self.protocols_available -= 1;
+            // The 4 LPs it replaces go back to the bank
+            self.lps_available += 4;
+        }
+        Ok(winning_bid)
+    }
+
+    /// Sells a property's buildings down to `level`, putting them back in the
+    /// stock. A Full Protocol broken into LPs takes those LPs out of the
+    /// stock, so the bank must have them. Returns the number of levels sold.
+    pub fn sell_buildings_to(&mut self, property: &mut PropertyState, level: u8) -> Result<u8> {
+        let sold = property.building_level().saturating_sub(level);
+        if sold == 0 {
+            return Ok(0);
+        }
+        if property.is_full_protocol {
+            require!(self.lps_available >= level, BlockpolyError::BuildingShortage);
+            self.lps_available -= level;
+            self.protocols_available += 1;
+            property.is_full_protocol = false;
+        } else {
+            self.lps_available += sold;
+        }
+        property.liquidity_pools = level;
+        Ok(sold)
+    }
+
+    /// Puts back everything built on a property leaving play
+    pub fn return_buildings_of(&mut self, property: &PropertyState) {
+        if property.is_full_protocol {
+            self.protocols_available += 1;
+        } else {
+            self.lps_available += property.liquidity_pools;
+        }
+    }
+}
+
+/// Bidding for the next building of a kind while the bank is running out
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct BuildingAuction {
+    /// BUILDING_LP or BUILDING_PROTOCOL
+    pub kind: u8,
+    pub highest_bid: u64,
+    pub highest_bidder: Option<Pubkey>,
+    /// Bidding closes after this slot
+    pub end_slot: u64,
+}
+
+impl BuildingAuction {
+    pub const SIZE: usize = 1 + 8 + (1 + 32) + 8;
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
@@ -132,6 +385,26 @@ pub enum GameStatus {
     Finished,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
+pub enum DiceMode {
+    /// Switchboard On-Demand randomness (request_dice_roll / consume_randomness)
+    #[default]
+    Oracle,
+    /// Every active player commits and reveals a secret (commit_dice / reveal_dice)
+    CommitReveal,
+}
+
+/// How a movement card changes the landing that follows it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CardModifier {
+    #[default]
+    None,
+    /// Advance to Nearest Bridge: an owned bridge charges double rent
+    DoubleBridgeRent,
+    /// Airdrop Season: a bank-owned space sells for half its price
+    HalfPricePurchase,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
 pub enum TurnPhase {
     #[default]
@@ -143,4 +416,9 @@ pub enum TurnPhase {
     AuctionPhase,
     BuyDecision,
     Finished,
+    /// Landed on an opponent's unmortgaged space; only pay_rent can follow
+    PayRent,
+    /// Current player owes more than they hold; they may only mortgage, sell
+    /// LPs or trade until settle_debt or declare_bankruptcy
+    RaiseFunds,
 }
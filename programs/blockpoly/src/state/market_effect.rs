@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+/// Named source of a market effect; one of each can be active at a time
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MarketEffectKind {
+    /// Alpha Call: unimproved rent doubled
+    BullRun,
+    /// Rent halved
+    BearMarket,
+    /// Taxes raised
+    GasSpike,
+    /// Market event: rent on one colour group raised
+    SectorRally,
+}
+
+/// Which amounts an effect changes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EffectTarget {
+    /// Every rent payment
+    Rent,
+    /// Rent on spaces without LPs or a Full Protocol
+    UnimprovedRent,
+    /// Rent on one colour group
+    GroupRent(u8),
+    /// Gas Fees Tax and Protocol Fee
+    Tax,
+    /// Genesis Block salary
+    Salary,
+    /// LP and Full Protocol build costs
+    BuildCost,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EffectModifier {
+    /// Scale by basis points (20_000 = double, 5_000 = half)
+    MultiplyBps(u32),
+    /// Add a flat BPOLY amount (negative lowers it, never below zero)
+    Delta(i64),
+}
+
+/// A timed global effect stored on GameState
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MarketEffect {
+    pub kind: MarketEffectKind,
+    pub target: EffectTarget,
+    pub modifier: EffectModifier,
+    /// Last round the effect applies to; removed by advance_turn after it
+    pub ends_round: u32,
+}
+
+/// An amount about to be charged or paid, described for matching effects
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EffectSubject {
+    Rent { group: u8, improved: bool },
+    Tax,
+    Salary,
+    BuildCost,
+}
+
+impl EffectTarget {
+    pub fn covers(&self, subject: EffectSubject) -> bool {
+        match (*self, subject) {
+            (EffectTarget::Rent, EffectSubject::Rent { .. }) => true,
+            (EffectTarget::UnimprovedRent, EffectSubject::Rent { improved, .. }) => !improved,
+            (EffectTarget::GroupRent(target), EffectSubject::Rent { group, .. }) => target == group,
+            (EffectTarget::Tax, EffectSubject::Tax) => true,
+            (EffectTarget::Salary, EffectSubject::Salary) => true,
+            (EffectTarget::BuildCost, EffectSubject::BuildCost) => true,
+            _ => false,
+        }
+    }
+}
+
+impl EffectModifier {
+    pub fn apply(&self, amount: u64) -> u64 {
+        match *self {
+            EffectModifier::MultiplyBps(bps) => {
+                let scaled = amount as u128 * bps as u128 / 10_000;
+                scaled.min(u64::MAX as u128) as u64
+            }
+            EffectModifier::Delta(delta) if delta >= 0 => amount.saturating_add(delta as u64),
+            EffectModifier::Delta(delta) => amount.saturating_sub(delta.unsigned_abs()),
+        }
+    }
+}
+
+impl MarketEffect {
+    pub const SIZE: usize =
+        1 +         // kind
+        1 + 1 +     // target (GroupRent carries a u8)
+        1 + 8 +     // modifier (Delta carries an i64)
+        4;          // ends_round
+}
@@ -1,9 +1,19 @@
+pub mod board_config;
+pub mod card_deck;
+pub mod dice_commitment;
 pub mod game_state;
+pub mod market_effect;
 pub mod player_state;
 pub mod property_state;
+pub mod rule_set;
 pub mod trade_offer;
 
+pub use board_config::*;
+pub use card_deck::*;
+pub use dice_commitment::*;
 pub use game_state::*;
+pub use market_effect::*;
 pub use player_state::*;
 pub use property_state::*;
+pub use rule_set::*;
 pub use trade_offer::*;
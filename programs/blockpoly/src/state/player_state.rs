@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constants::DEBT_REASON_NONE;
 
 #[account]
 #[derive(Default)]
@@ -13,10 +14,14 @@ pub struct PlayerState {
     pub status: PlayerStatus,
     /// Current board position (0–39)
     pub position: u8,
+    /// Passed (or landed on) Genesis Block since the last landing; salary paid in resolve_landing
+    pub passed_genesis_pending: bool,
     /// Number of consecutive doubles this turn
     pub doubles_streak: u8,
     /// Turns remaining in Rug Pull Zone (0 = not in jail)
     pub rugpull_turns_remaining: u8,
+    /// Released after a third failed doubles attempt; bail collected in resolve_landing
+    pub rugpull_bail_due: bool,
     /// Holds a "Get Out of Rug Pull Free" card
     pub has_jail_free_card: bool,
     /// Which deck the card came from: 0=none, 1=alpha_call, 2=governance
@@ -31,6 +36,14 @@ pub struct PlayerState {
     pub is_bankrupt: bool,
     /// BPOLY balance mirror (authoritative copy is the ATA; this for quick reads)
     pub bpoly_balance: u64,
+    /// Unpaid obligation; while non-zero the game sits in RaiseFunds
+    pub debt_amount: u64,
+    /// Who the debt is owed to (None = bank)
+    pub debt_creditor: Option<Pubkey>,
+    /// DEBT_REASON_* code of the debt
+    pub debt_reason: u8,
+    /// Last round whose market event this player has applied
+    pub market_event_round: u32,
     pub bump: u8,
 }
 
@@ -41,8 +54,10 @@ impl PlayerState {
         1 +         // player_index
         1 +         // status
         1 +         // position
+        1 +         // passed_genesis_pending
         1 +         // doubles_streak
         1 +         // rugpull_turns_remaining
+        1 +         // rugpull_bail_due
         1 +         // has_jail_free_card
         1 +         // jail_free_card_type
         4 + 28 +    // properties_owned vec (max 28)
@@ -51,12 +66,68 @@ impl PlayerState {
         4 +         // flash_loan_due_turn
         1 +         // is_bankrupt
         8 +         // bpoly_balance
+        8 +         // debt_amount
+        1 + 32 +    // debt_creditor (Option<Pubkey>)
+        1 +         // debt_reason
+        4 +         // market_event_round
         1;          // bump
 
     pub fn is_in_rugpull(&self) -> bool {
         self.rugpull_turns_remaining > 0
     }
 
+    /// Moves forward to `space`; wrapping past the end of the board owes Genesis salary.
+    pub fn advance_to(&mut self, space: u8) {
+        if space < self.position {
+            self.passed_genesis_pending = true;
+        }
+        self.position = space;
+    }
+
+    pub fn has_debt(&self) -> bool {
+        self.debt_amount > 0
+    }
+
+    pub fn clear_debt(&mut self) {
+        self.debt_amount = 0;
+        self.debt_creditor = None;
+        self.debt_reason = DEBT_REASON_NONE;
+    }
+
+    /// A Flash Loan falls due at the start of the borrower's next turn and
+    /// blocks their roll until repaid
+    pub fn flash_loan_overdue(&self, turn_number: u32) -> bool {
+        self.flash_loan_active && turn_number >= self.flash_loan_due_turn
+    }
+
+    pub fn clear_flash_loan(&mut self) {
+        self.flash_loan_active = false;
+        self.flash_loan_repay_amount = 0;
+        self.flash_loan_due_turn = 0;
+    }
+
+    /// Keeps a Get Out of Rug Pull Free card from `deck_type`. A player holds
+    /// one at a time; returns false if they already do.
+    pub fn keep_jail_free_card(&mut self, deck_type: u8) -> bool {
+        if self.has_jail_free_card {
+            return false;
+        }
+        self.has_jail_free_card = true;
+        self.jail_free_card_type = deck_type + 1;
+        true
+    }
+
+    /// Gives up the held Get Out of Rug Pull Free card, returning its deck_type
+    pub fn take_jail_free_card(&mut self) -> Option<u8> {
+        if !self.has_jail_free_card {
+            return None;
+        }
+        let deck_type = self.jail_free_card_type - 1;
+        self.has_jail_free_card = false;
+        self.jail_free_card_type = 0;
+        Some(deck_type)
+    }
+
     pub fn owns_property(&self, space: u8) -> bool {
         self.properties_owned.contains(&space)
     }
@@ -32,6 +32,12 @@ impl PropertyState {
         32 +  // nft_asset
         1;    // bump
 
+    /// LPs built, plus one for Full Protocol (0–5); the even-building rule
+    /// compares these across a colour group
+    pub fn building_level(&self) -> u8 {
+        self.liquidity_pools + self.is_full_protocol as u8
+    }
+
     pub fn has_buildings(&self) -> bool {
         self.liquidity_pools > 0 || self.is_full_protocol
     }
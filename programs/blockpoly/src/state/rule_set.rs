@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::BlockpolyError;
+use crate::state::BoardSpace;
+
+/// House rules fixed at initialize_game and stored on GameState.
+/// Default reproduces the standard Blockpoly rules from constants.rs.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct RuleSet {
+    /// BPOLY each player receives on join
+    pub starting_balance: u64,
+    /// Paid for passing or landing on Genesis Block
+    pub genesis_salary: u64,
+    /// Cost to leave the Rug Pull Zone
+    pub rugpull_bail: u64,
+    /// Overrides the Gas Fees Tax (space 4); 0 charges the board's amount
+    pub gas_fee_tax: u64,
+    /// Overrides the Protocol Fee (space 38); 0 charges the board's amount
+    pub protocol_fee_tax: u64,
+    /// Turns an auction stays open
+    pub auction_duration_turns: u32,
+    /// Turns spent in the Rug Pull Zone before bail is forced
+    pub rugpull_max_turns: u8,
+    /// Flash Loan card terms
+    pub flash_loan_amount: u64,
+    pub flash_loan_repay: u64,
+    pub flash_loan_penalty: u64,
+    /// Landing exactly on Genesis Block pays double salary
+    pub double_salary_on_genesis: bool,
+    /// Declining to buy starts an auction; otherwise the space stays with the bank
+    pub auction_on_decline: bool,
+    /// Owners in the Rug Pull Zone collect no rent
+    pub no_rent_in_rugpull: bool,
+    /// Draw a market event at the start of every round
+    pub market_events: bool,
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self {
+            starting_balance: STARTING_BALANCE,
+            genesis_salary: GENESIS_SALARY,
+            rugpull_bail: RUGPULL_BAIL_AMOUNT,
+            gas_fee_tax: 0,
+            protocol_fee_tax: 0,
+            auction_duration_turns: AUCTION_DURATION_TURNS,
+            rugpull_max_turns: RUGPULL_MAX_TURNS,
+            flash_loan_amount: FLASH_LOAN_AMOUNT,
+            flash_loan_repay: FLASH_LOAN_REPAY,
+            flash_loan_penalty: FLASH_LOAN_PENALTY,
+            double_salary_on_genesis: false,
+            auction_on_decline: true,
+            no_rent_in_rugpull: false,
+            market_events: false,
+        }
+    }
+}
+
+impl RuleSet {
+    pub const SIZE: usize =
+        8 +         // starting_balance
+        8 +         // genesis_salary
+        8 +         // rugpull_bail
+        8 +         // gas_fee_tax
+        8 +         // protocol_fee_tax
+        4 +         // auction_duration_turns
+        1 +         // rugpull_max_turns
+        8 +         // flash_loan_amount
+        8 +         // flash_loan_repay
+        8 +         // flash_loan_penalty
+        1 +         // double_salary_on_genesis
+        1 +         // auction_on_decline
+        1 +         // no_rent_in_rugpull
+        1;          // market_events
+
+    pub fn validate(&self) -> Result<()> {
+        require!(self.starting_balance > 0, BlockpolyError::InvalidRuleSet);
+        require!(self.auction_duration_turns > 0, BlockpolyError::InvalidRuleSet);
+        require!(self.rugpull_max_turns > 0, BlockpolyError::InvalidRuleSet);
+        require!(
+            self.flash_loan_repay >= self.flash_loan_amount,
+            BlockpolyError::InvalidRuleSet
+        );
+        Ok(())
+    }
+
+    /// Tax charged on the tax space at `index`: the amount the board gives
+    /// it (its base_rent), unless this RuleSet overrides that space
+    pub fn tax_for(&self, index: u8, space: &BoardSpace) -> u64 {
+        let tax_override = match index {
+            SPACE_GAS_FEE_TAX => self.gas_fee_tax,
+            SPACE_PROTOCOL_FEE => self.protocol_fee_tax,
+            _ => 0,
+        };
+        if tax_override > 0 {
+            tax_override
+        } else {
+            space.base_rent
+        }
+    }
+
+    /// Salary owed for a move ending on `position`
+    pub fn salary_for(&self, position: u8) -> u64 {
+        if self.double_salary_on_genesis && position == SPACE_GENESIS {
+            self.genesis_salary.saturating_mul(2)
+        } else {
+            self.genesis_salary
+        }
+    }
+}
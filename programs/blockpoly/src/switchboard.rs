@@ -0,0 +1,120 @@
+/// Minimal Switchboard On-Demand randomness integration.
+/// Reads `RandomnessAccountData` by raw layout and commits via raw CPI,
+/// so the program does not pull in the Switchboard SDK crate.
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke};
+use crate::constants::SEED_RANDOMNESS_AUTHORITY;
+use crate::errors::BlockpolyError;
+
+/// Switchboard On-Demand program ID
+#[cfg(not(feature = "mock-oracle"))]
+pub const SWITCHBOARD_ON_DEMAND_PROGRAM: Pubkey =
+    pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+/// Local mock oracle (programs/mock-oracle) used by the program tests
+#[cfg(feature = "mock-oracle")]
+pub const SWITCHBOARD_ON_DEMAND_PROGRAM: Pubkey =
+    pubkey!("4AHSnZ8vW5D4VLMbmY9tGVpMnzMP6ekwJ2uo22WtGaQS");
+
+/// Default randomness authority of a game: a Blockpoly PDA that never signs.
+/// It marks the game as cranked: anyone may call consume_randomness once the
+/// oracle has revealed, since the value was fixed at commit. Games that
+/// settle rolls through their own keeper set randomness_authority explicitly
+/// in initialize_game instead.
+pub fn default_randomness_authority() -> Pubkey {
+    Pubkey::find_program_address(&[SEED_RANDOMNESS_AUTHORITY], &crate::ID).0
+}
+
+/// sha256("account:RandomnessAccountData")[..8]
+const RANDOMNESS_ACCOUNT_DISCRIMINATOR: [u8; 8] = [10, 66, 229, 135, 220, 239, 217, 114];
+/// sha256("global:randomness_commit")[..8]
+const RANDOMNESS_COMMIT_DISCRIMINATOR: [u8; 8] = [52, 170, 152, 201, 179, 133, 242, 141];
+
+/// Decoded view of Switchboard's `RandomnessAccountData` (trailing reserved bytes skipped).
+pub struct RandomnessAccountData {
+    pub authority: Pubkey,
+    pub queue: Pubkey,
+    pub seed_slothash: [u8; 32],
+    pub seed_slot: u64,
+    pub oracle: Pubkey,
+    pub reveal_slot: u64,
+    pub value: [u8; 32],
+}
+
+impl RandomnessAccountData {
+    /// discriminator + authority + queue + seed_slothash + seed_slot + oracle + reveal_slot + value
+    const MIN_LEN: usize = 8 + 32 + 32 + 32 + 8 + 32 + 8 + 32;
+
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(
+            *account.owner,
+            SWITCHBOARD_ON_DEMAND_PROGRAM,
+            BlockpolyError::InvalidRandomnessAccount
+        );
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= Self::MIN_LEN && data[..8] == RANDOMNESS_ACCOUNT_DISCRIMINATOR,
+            BlockpolyError::InvalidRandomnessAccount
+        );
+
+        let pubkey_at = |o: usize| Pubkey::new_from_array(data[o..o + 32].try_into().unwrap());
+        let bytes_at = |o: usize| -> [u8; 32] { data[o..o + 32].try_into().unwrap() };
+        let u64_at = |o: usize| u64::from_le_bytes(data[o..o + 8].try_into().unwrap());
+
+        Ok(Self {
+            authority: pubkey_at(8),
+            queue: pubkey_at(40),
+            seed_slothash: bytes_at(72),
+            seed_slot: u64_at(104),
+            oracle: pubkey_at(112),
+            reveal_slot: u64_at(144),
+            value: bytes_at(152),
+        })
+    }
+
+    pub fn is_revealed(&self) -> bool {
+        self.reveal_slot != 0 && self.reveal_slot >= self.seed_slot
+    }
+
+    /// Revealed value. Unlike the SDK this is not limited to the reveal's own
+    /// slot: the seed is fixed at commit, so reading the value later gives
+    /// nobody a choice, and a missed slot must not strand the roll.
+    pub fn get_value(&self) -> Result<[u8; 32]> {
+        require!(self.is_revealed(), BlockpolyError::RandomnessNotResolved);
+        Ok(self.value)
+    }
+}
+
+/// CPI into `randomness_commit`. The randomness authority must sign the outer transaction.
+pub fn randomness_commit<'info>(
+    switchboard_program: &AccountInfo<'info>,
+    randomness: &AccountInfo<'info>,
+    queue: &AccountInfo<'info>,
+    oracle: &AccountInfo<'info>,
+    recent_slothashes: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: SWITCHBOARD_ON_DEMAND_PROGRAM,
+        accounts: vec![
+            AccountMeta::new(randomness.key(), false),
+            AccountMeta::new_readonly(queue.key(), false),
+            AccountMeta::new(oracle.key(), false),
+            AccountMeta::new_readonly(recent_slothashes.key(), false),
+            AccountMeta::new_readonly(authority.key(), true),
+        ],
+        // RandomnessCommitParams {} serializes to zero bytes
+        data: RANDOMNESS_COMMIT_DISCRIMINATOR.to_vec(),
+    };
+    invoke(
+        &ix,
+        &[
+            randomness.clone(),
+            queue.clone(),
+            oracle.clone(),
+            recent_slothashes.clone(),
+            authority.clone(),
+            switchboard_program.clone(),
+        ],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,233 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed};
+
+declare_id!("4AHSnZ8vW5D4VLMbmY9tGVpMnzMP6ekwJ2uo22WtGaQS");
+
+/// Local stand-in for the Switchboard On-Demand randomness program.
+///
+/// Mirrors the parts of the on-demand interface Blockpoly relies on:
+/// the `RandomnessAccountData` layout and the `randomness_commit` instruction
+/// (same discriminator and account order). Instead of an off-chain oracle
+/// enclave, `randomness_reveal` takes the value from the assigned oracle
+/// signer so tests can script exact dice without network access.
+///
+/// `randomness_reveal_with_callback` additionally invokes a consumer
+/// program signed by this program's `oracle_authority` PDA, which the tests
+/// set as a Blockpoly game's randomness authority (keeper).
+///
+/// Build Blockpoly with `--features mock-oracle` to make it accept
+/// randomness accounts owned by this program.
+#[program]
+pub mod mock_oracle {
+    use super::*;
+
+    pub fn randomness_init(ctx: Context<RandomnessInit>) -> Result<()> {
+        let randomness = &mut ctx.accounts.randomness;
+        randomness.authority = ctx.accounts.authority.key();
+        randomness.queue = ctx.accounts.queue.key();
+        randomness.seed_slothash = [0; 32];
+        randomness.seed_slot = 0;
+        randomness.oracle = Pubkey::default();
+        randomness.reveal_slot = 0;
+        randomness.value = [0; 32];
+        randomness.ebuf2 = [0; 96];
+        randomness.ebuf1 = [0; 128];
+        Ok(())
+    }
+
+    pub fn randomness_commit(
+        ctx: Context<RandomnessCommit>,
+        _params: RandomnessCommitParams,
+    ) -> Result<()> {
+        let randomness = &mut ctx.accounts.randomness;
+        require_keys_eq!(
+            randomness.authority,
+            ctx.accounts.authority.key(),
+            MockOracleError::InvalidAuthority
+        );
+        require_keys_eq!(
+            randomness.queue,
+            ctx.accounts.queue.key(),
+            MockOracleError::InvalidQueue
+        );
+
+        // Switchboard seeds the commitment with the most recent slot hash,
+        // which belongs to the previous slot.
+        let seed_slot = Clock::get()?.slot.saturating_sub(1);
+        randomness.seed_slot = seed_slot;
+        randomness.seed_slothash = [0; 32];
+        randomness.seed_slothash[..8].copy_from_slice(&seed_slot.to_le_bytes());
+        randomness.oracle = ctx.accounts.oracle.key();
+        randomness.reveal_slot = 0;
+        randomness.value = [0; 32];
+        Ok(())
+    }
+
+    pub fn randomness_reveal(ctx: Context<RandomnessReveal>, value: [u8; 32]) -> Result<()> {
+        let randomness = &mut ctx.accounts.randomness;
+        require!(randomness.seed_slot > 0, MockOracleError::NotCommitted);
+        require!(randomness.reveal_slot == 0, MockOracleError::AlreadyRevealed);
+        require_keys_eq!(
+            randomness.oracle,
+            ctx.accounts.oracle.key(),
+            MockOracleError::InvalidOracle
+        );
+
+        randomness.value = value;
+        randomness.reveal_slot = Clock::get()?.slot;
+        Ok(())
+    }
+
+    /// Reveal, then invoke `callback_program` with `callback_data` and the
+    /// remaining accounts, signing as the `oracle_authority` PDA.
+    pub fn randomness_reveal_with_callback<'info>(
+        ctx: Context<'_, '_, '_, 'info, RandomnessRevealWithCallback<'info>>,
+        value: [u8; 32],
+        callback_data: Vec<u8>,
+    ) -> Result<()> {
+        {
+            let randomness = &mut ctx.accounts.randomness;
+            require!(randomness.seed_slot > 0, MockOracleError::NotCommitted);
+            require!(randomness.reveal_slot == 0, MockOracleError::AlreadyRevealed);
+            require_keys_eq!(
+                randomness.oracle,
+                ctx.accounts.oracle.key(),
+                MockOracleError::InvalidOracle
+            );
+            randomness.value = value;
+            randomness.reveal_slot = Clock::get()?.slot;
+        }
+        // Persist the reveal before the consumer reads the account
+        ctx.accounts.randomness.exit(&crate::ID)?;
+
+        let oracle_authority = ctx.accounts.oracle_authority.key();
+        let accounts = ctx
+            .remaining_accounts
+            .iter()
+            .map(|acc| AccountMeta {
+                pubkey: acc.key(),
+                is_signer: acc.is_signer || acc.key() == oracle_authority,
+                is_writable: acc.is_writable,
+            })
+            .collect();
+        let ix = Instruction {
+            program_id: ctx.accounts.callback_program.key(),
+            accounts,
+            data: callback_data,
+        };
+
+        let mut infos = ctx.remaining_accounts.to_vec();
+        infos.push(ctx.accounts.oracle_authority.to_account_info());
+        infos.push(ctx.accounts.callback_program.to_account_info());
+
+        let bump = ctx.bumps.oracle_authority;
+        invoke_signed(&ix, &infos, &[&[SEED_ORACLE_AUTHORITY, &[bump]]])?;
+        Ok(())
+    }
+}
+
+pub const SEED_ORACLE_AUTHORITY: &[u8] = b"oracle_authority";
+
+#[derive(Accounts)]
+pub struct RandomnessInit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(init, payer = payer, space = RandomnessAccountData::MAX_SIZE)]
+    pub randomness: Account<'info, RandomnessAccountData>,
+
+    /// CHECK: any key; recorded as the oracle queue
+    pub queue: UncheckedAccount<'info>,
+
+    /// CHECK: any key; recorded as the commit authority
+    pub authority: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Same account order as Switchboard's `randomness_commit`.
+#[derive(Accounts)]
+pub struct RandomnessCommit<'info> {
+    #[account(mut)]
+    pub randomness: Account<'info, RandomnessAccountData>,
+
+    /// CHECK: verified against randomness.queue
+    pub queue: UncheckedAccount<'info>,
+
+    /// CHECK: any key; becomes the oracle allowed to reveal
+    #[account(mut)]
+    pub oracle: UncheckedAccount<'info>,
+
+    /// CHECK: slot hashes sysvar (unused by the mock)
+    pub recent_slothashes: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RandomnessReveal<'info> {
+    #[account(mut)]
+    pub randomness: Account<'info, RandomnessAccountData>,
+
+    pub oracle: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RandomnessRevealWithCallback<'info> {
+    #[account(mut)]
+    pub randomness: Account<'info, RandomnessAccountData>,
+
+    pub oracle: Signer<'info>,
+
+    /// CHECK: PDA that signs the callback
+    #[account(seeds = [SEED_ORACLE_AUTHORITY], bump)]
+    pub oracle_authority: UncheckedAccount<'info>,
+
+    /// CHECK: any executable program; receives the callback
+    #[account(executable)]
+    pub callback_program: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RandomnessCommitParams {}
+
+/// Byte-for-byte copy of Switchboard's `RandomnessAccountData`.
+#[account]
+pub struct RandomnessAccountData {
+    pub authority: Pubkey,
+    pub queue: Pubkey,
+    pub seed_slothash: [u8; 32],
+    pub seed_slot: u64,
+    pub oracle: Pubkey,
+    pub reveal_slot: u64,
+    pub value: [u8; 32],
+    pub ebuf2: [u8; 96],
+    pub ebuf1: [u8; 128],
+}
+
+impl RandomnessAccountData {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        32 +        // authority
+        32 +        // queue
+        32 +        // seed_slothash
+        8 +         // seed_slot
+        32 +        // oracle
+        8 +         // reveal_slot
+        32 +        // value
+        96 +        // ebuf2
+        128;        // ebuf1
+}
+
+#[error_code]
+pub enum MockOracleError {
+    #[msg("Signer is not the randomness authority")]
+    InvalidAuthority,
+    #[msg("Queue does not match the randomness account")]
+    InvalidQueue,
+    #[msg("Signer is not the assigned oracle")]
+    InvalidOracle,
+    #[msg("Randomness has not been committed")]
+    NotCommitted,
+    #[msg("Randomness has already been revealed")]
+    AlreadyRevealed,
+}
@@ -1,6 +1,12 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program, BN } from "@coral-xyz/anchor";
-import { PublicKey, Keypair, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+  SYSVAR_SLOT_HASHES_PUBKEY,
+} from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -9,12 +15,16 @@ import {
   getAssociatedTokenAddress,
 } from "@solana/spl-token";
 import { assert } from "chai";
+import { createHash } from "crypto";
 
 const PROGRAM_ID = new PublicKey("AicXQXhiHgzaxTXpbxYEriXSQdBRQNbqWgcMU1N57q9n");
 const SEED_GAME_STATE    = Buffer.from("game_state");
 const SEED_PLAYER_STATE  = Buffer.from("player_state");
 const SEED_PROPERTY_STATE = Buffer.from("property_state");
 const SEED_BANK_VAULT    = Buffer.from("bank_vault");
+const SEED_DICE_COMMITMENT = Buffer.from("dice_commitment");
+const SEED_BOARD_CONFIG  = Buffer.from("board_config");
+const SEED_CARD_DECK     = Buffer.from("card_deck");
 
 // ──────────────────────────────────────────────────────────────────────────────
 // Helpers
@@ -30,6 +40,11 @@ function findPDA(seeds: Buffer[], programId: PublicKey) {
   return PublicKey.findProgramAddressSync(seeds, programId);
 }
 
+/** Commit-reveal dice commitment: sha256(wallet || secret) */
+function diceCommitment(wallet: PublicKey, secret: Buffer): number[] {
+  return Array.from(createHash("sha256").update(wallet.toBuffer()).update(secret).digest());
+}
+
 async function airdropIfNeeded(
   connection: anchor.web3.Connection,
   pubkey: PublicKey,
@@ -51,6 +66,8 @@ describe("blockpoly", () => {
   anchor.setProvider(provider);
 
   const program = anchor.workspace.Blockpoly as Program<any>;
+  // Stand-in for Switchboard On-Demand (blockpoly built with --features mock-oracle)
+  const mockOracle = anchor.workspace.MockOracle as Program<any>;
   const connection = provider.connection;
   const payer = (provider.wallet as anchor.Wallet).payer;
 
@@ -67,8 +84,81 @@ describe("blockpoly", () => {
   let player2Ata: PublicKey;
 
   const GID = gameId("test-game-001");
+  const BOARD_ID = gameId("default-board");
+  const [boardConfig] = findPDA([SEED_BOARD_CONFIG, Buffer.from(BOARD_ID)], PROGRAM_ID);
+  const DECK_ID = gameId("default-deck");
+  const [cardDeck] = findPDA([SEED_CARD_DECK, Buffer.from(DECK_ID)], PROGRAM_ID);
   const DUMMY_NFT_COLLECTION = Keypair.generate().publicKey;
 
+  // Mock oracle setup: payer acts as the oracle that reveals values, and the
+  // oracle's callback PDA is the game's keeper (randomness authority)
+  const randomnessQueue = Keypair.generate().publicKey;
+  const [oracleAuthority] = findPDA([Buffer.from("oracle_authority")], mockOracle.programId);
+  const player1Randomness = Keypair.generate();
+  const player2Randomness = Keypair.generate();
+
+  async function initRandomness(randomness: Keypair, authority: PublicKey) {
+    await mockOracle.methods
+      .randomnessInit()
+      .accounts({
+        payer: payer.publicKey,
+        randomness: randomness.publicKey,
+        queue: randomnessQueue,
+        authority,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer, randomness])
+      .rpc();
+  }
+
+  /** request_dice_roll: commits the player's randomness account via CPI */
+  async function requestRoll(player: Keypair, playerState: PublicKey, randomness: Keypair) {
+    await program.methods
+      .requestDiceRoll(Array.from(GID))
+      .accounts({
+        player: player.publicKey,
+        gameState: gameStatePDA,
+        playerState,
+        randomnessAccount: randomness.publicKey,
+        randomnessQueue,
+        randomnessOracle: payer.publicKey,
+        recentSlothashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        switchboardProgram: mockOracle.programId,
+      })
+      .signers([player])
+      .rpc();
+  }
+
+  /** Oracle reveals and calls back consume_randomness, signed by its authority PDA */
+  async function revealAndConsume(
+    playerState: PublicKey,
+    randomness: Keypair,
+    value: number[]
+  ) {
+    const consumeIx = await program.methods
+      .consumeRandomness(Array.from(GID))
+      .accounts({
+        authority: oracleAuthority,
+        gameState: gameStatePDA,
+        boardConfig,
+        playerState,
+        randomnessAccount: randomness.publicKey,
+      })
+      .instruction();
+
+    await mockOracle.methods
+      .randomnessRevealWithCallback(value, consumeIx.data)
+      .accounts({
+        randomness: randomness.publicKey,
+        oracle: payer.publicKey,
+        oracleAuthority,
+        callbackProgram: program.programId,
+      })
+      .remainingAccounts(consumeIx.keys.map((k) => ({ ...k, isSigner: false })))
+      .signers([payer])
+      .rpc();
+  }
+
   before(async () => {
     // Airdrop to test wallets
     await airdropIfNeeded(connection, payer.publicKey, 10e9);
@@ -91,6 +181,42 @@ describe("blockpoly", () => {
     player1Ata = await getAssociatedTokenAddress(bpolyMint, player1.publicKey);
     player2Ata = await getAssociatedTokenAddress(bpolyMint, player2.publicKey);
 
+    // Games are played on a finalized BoardConfig; the default one is the standard board
+    await program.methods
+      .createBoardConfig(Array.from(BOARD_ID))
+      .accounts({
+        authority: payer.publicKey,
+        boardConfig,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc();
+    await program.methods
+      .finalizeBoardConfig(Array.from(BOARD_ID))
+      .accounts({ authority: payer.publicKey, boardConfig })
+      .signers([payer])
+      .rpc();
+
+    // ...and draw from a finalized CardDeck; the default one holds the standard cards
+    await program.methods
+      .createCardDeck(Array.from(DECK_ID))
+      .accounts({
+        authority: payer.publicKey,
+        cardDeck,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc();
+    await program.methods
+      .finalizeCardDeck(Array.from(DECK_ID))
+      .accounts({ authority: payer.publicKey, cardDeck })
+      .signers([payer])
+      .rpc();
+
+    // One reusable randomness account per player (re-committed every roll)
+    await initRandomness(player1Randomness, player1.publicKey);
+    await initRandomness(player2Randomness, player2.publicKey);
+
     console.log("Setup complete:");
     console.log("  BPOLY Mint:", bpolyMint.toString());
     console.log("  Bank Vault:", bankVaultPDA.toString());
@@ -105,11 +231,16 @@ describe("blockpoly", () => {
         Array.from(GID),
         4,               // max_players
         new BN(10_000_000), // entry_fee_lamports = 0.01 SOL
-        DUMMY_NFT_COLLECTION
+        DUMMY_NFT_COLLECTION,
+        oracleAuthority, // randomness_authority: the oracle callback settles rolls
+        { oracle: {} },  // dice_mode
+        null             // rules → standard RuleSet
       )
       .accounts({
         host: payer.publicKey,
         gameState: gameStatePDA,
+        boardConfig,
+        cardDeck,
         bpolyMint,
         bankVault: bankVaultPDA,
         bankBpolyAta: bankAta,
@@ -135,7 +266,15 @@ describe("blockpoly", () => {
 
     const gameState = await program.account.gameState.fetch(gameStatePDA);
     assert.equal(gameState.playerCount, 0);
+    assert.equal(gameState.randomnessAuthority.toString(), oracleAuthority.toString());
     assert.equal(gameState.maxPlayers, 4);
+    assert.equal(gameState.rules.startingBalance.toString(), "1500000000");
+    assert.equal(gameState.boardConfig.toString(), boardConfig.toString());
+    assert.equal(gameState.cardDeck.toString(), cardDeck.toString());
+    assert.equal(gameState.alphaCallDeck.length, 16);
+    assert.equal(gameState.lpsAvailable, 32);
+    assert.equal(gameState.protocolsAvailable, 12);
+    assert.isNull(gameState.buildingAuction);
     assert.ok(gameState.status.waitingForPlayers !== undefined, "Status should be WaitingForPlayers");
     console.log("  Game status: WaitingForPlayers ✓");
   });
@@ -229,41 +368,52 @@ describe("blockpoly", () => {
 
   // ── request_dice_roll + consume_randomness ─────────────────────────────────
 
-  it("player1 rolls dice (mock VRF — die1=4, die2=2, land on space 6 Pyth Network)", async () => {
+  it("player1 rolls dice (mock oracle — die1=4, die2=2, land on space 6 Pyth Network)", async () => {
     const [player1StatePDA] = findPDA(
       [SEED_PLAYER_STATE, Buffer.from(GID), player1.publicKey.toBuffer()],
       PROGRAM_ID
     );
 
-    // request_dice_roll
-    await program.methods
-      .requestDiceRoll(Array.from(GID))
-      .accounts({
-        player: player1.publicKey,
-        gameState: gameStatePDA,
-        playerState: player1StatePDA,
-      })
-      .signers([player1])
-      .rpc();
+    await requestRoll(player1, player1StatePDA, player1Randomness);
 
     let gs = await program.account.gameState.fetch(gameStatePDA);
     assert.ok(gs.turnPhase.awaitingVrf !== undefined, "Should be AwaitingVRF");
+    assert.equal(
+      gs.vrfRequest.toString(),
+      player1Randomness.publicKey.toString(),
+      "vrf_request should point at the committed randomness account"
+    );
     console.log("  Phase: AwaitingVRF ✓");
 
-    // consume_randomness (mock: die1=4, die2=2 → total 6, land on space 6)
+    // Oracle reveals die1=4, die2=2 → total 6, land on space 6
     const randomBytes = new Array(32).fill(0);
     randomBytes[0] = 3;  // die1 = (3 % 6) + 1 = 4
     randomBytes[1] = 1;  // die2 = (1 % 6) + 1 = 2
 
-    await program.methods
-      .consumeRandomness(Array.from(GID), randomBytes)
-      .accounts({
-        authority: player1.publicKey,
-        gameState: gameStatePDA,
-        playerState: player1StatePDA,
-      })
-      .signers([player1])
-      .rpc();
+    // The player cannot settle their own roll with a hand-picked value
+    const revealIx = await mockOracle.methods
+      .randomnessReveal(randomBytes)
+      .accounts({ randomness: player1Randomness.publicKey, oracle: payer.publicKey })
+      .instruction();
+    try {
+      await program.methods
+        .consumeRandomness(Array.from(GID))
+        .accounts({
+          authority: player1.publicKey,
+          gameState: gameStatePDA,
+          boardConfig,
+          playerState: player1StatePDA,
+          randomnessAccount: player1Randomness.publicKey,
+        })
+        .preInstructions([revealIx])
+        .signers([player1, payer])
+        .rpc();
+      assert.fail("consume_randomness should reject the player as authority");
+    } catch (e: any) {
+      assert.include(e.toString(), "UnauthorizedRandomnessAuthority");
+    }
+
+    await revealAndConsume(player1StatePDA, player1Randomness, randomBytes);
 
     const ps = await program.account.playerState.fetch(player1StatePDA);
     gs = await program.account.gameState.fetch(gameStatePDA);
@@ -288,7 +438,9 @@ describe("blockpoly", () => {
       .accounts({
         player: player1.publicKey,
         gameState: gameStatePDA,
+        boardConfig,
         playerState: player1StatePDA,
+        propertyState: findPDA([SEED_PROPERTY_STATE, Buffer.from(GID), Buffer.from([6])], PROGRAM_ID)[0],
         bankVault: bankVaultPDA,
         bankBpolyAta: bankAta,
         playerBpolyAta: player1Ata,
@@ -323,6 +475,7 @@ describe("blockpoly", () => {
       .accounts({
         player: player1.publicKey,
         gameState: gameStatePDA,
+        boardConfig,
         playerState: player1StatePDA,
         propertyState: propPDA,
         bankVault: bankVaultPDA,
@@ -364,31 +517,14 @@ describe("blockpoly", () => {
     // Need total = 1, but minimum is 2. So let's pick space 3 (dogwifhat): bytes[0]=1, bytes[1]=1 → 2+2=4? No.
     // bytes[0]=1 → die1=(1%6)+1=2, bytes[1]=0 → die2=(0%6)+1=1 → total=3 → space 3 (dogwifhat) is a Property ✓
 
-    // requestDiceRoll
-    await program.methods
-      .requestDiceRoll(Array.from(GID))
-      .accounts({
-        player: player2.publicKey,
-        gameState: gameStatePDA,
-        playerState: player2StatePDA,
-      })
-      .signers([player2])
-      .rpc();
+    await requestRoll(player2, player2StatePDA, player2Randomness);
 
-    // consumeRandomness: die1=2, die2=1 → total=3 → space 3 (dogwifhat, Brown property)
+    // Oracle reveals die1=2, die2=1 → total=3 → space 3 (dogwifhat, Brown property)
     const randomBytes = new Array(32).fill(0);
     randomBytes[0] = 1; // die1 = (1 % 6) + 1 = 2
     randomBytes[1] = 0; // die2 = (0 % 6) + 1 = 1
 
-    await program.methods
-      .consumeRandomness(Array.from(GID), randomBytes)
-      .accounts({
-        authority: player2.publicKey,
-        gameState: gameStatePDA,
-        playerState: player2StatePDA,
-      })
-      .signers([player2])
-      .rpc();
+    await revealAndConsume(player2StatePDA, player2Randomness, randomBytes);
 
     const ps = await program.account.playerState.fetch(player2StatePDA);
     assert.equal(ps.position, 3, "Player2 should be on space 3 (dogwifhat)");
@@ -399,7 +535,9 @@ describe("blockpoly", () => {
       .accounts({
         player: player2.publicKey,
         gameState: gameStatePDA,
+        boardConfig,
         playerState: player2StatePDA,
+        propertyState: findPDA([SEED_PROPERTY_STATE, Buffer.from(GID), Buffer.from([3])], PROGRAM_ID)[0],
         bankVault: bankVaultPDA,
         bankBpolyAta: bankAta,
         playerBpolyAta: player2Ata,
@@ -414,6 +552,7 @@ describe("blockpoly", () => {
       .accounts({
         player: player2.publicKey,
         gameState: gameStatePDA,
+        boardConfig,
         playerState: player2StatePDA,
       })
       .signers([player2])
@@ -425,6 +564,228 @@ describe("blockpoly", () => {
     console.log("  declineBuy → auction started for space 3 (dogwifhat) ✓");
   });
 
+  // ── commit-reveal dice mode ────────────────────────────────────────────────
+
+  describe("commit-reveal dice mode", () => {
+    const CR_GID = gameId("test-game-commit-reveal");
+    const [crGameState] = findPDA([SEED_GAME_STATE, Buffer.from(CR_GID)], PROGRAM_ID);
+    const [crBankVault] = findPDA([SEED_BANK_VAULT, Buffer.from(CR_GID)], PROGRAM_ID);
+    const [p1State] = findPDA(
+      [SEED_PLAYER_STATE, Buffer.from(CR_GID), player1.publicKey.toBuffer()],
+      PROGRAM_ID
+    );
+    const [p2State] = findPDA(
+      [SEED_PLAYER_STATE, Buffer.from(CR_GID), player2.publicKey.toBuffer()],
+      PROGRAM_ID
+    );
+
+    let crBankAta: PublicKey;
+
+    // XOR of the two secrets: byte0=1 → die1=2, byte1=1 → die2=2 → space 4 (Gas Fees Tax)
+    const secret1 = Buffer.from(crypto.getRandomValues(new Uint8Array(32)));
+    const secret2 = Buffer.from(secret1);
+    secret2[0] ^= 1;
+    secret2[1] ^= 1;
+
+    function commitmentPDA(turnNumber: number) {
+      const turn = Buffer.alloc(4);
+      turn.writeUInt32LE(turnNumber);
+      return findPDA([SEED_DICE_COMMITMENT, Buffer.from(CR_GID), turn], PROGRAM_ID)[0];
+    }
+
+    before(async () => {
+      crBankAta = await getAssociatedTokenAddress(bpolyMint, crBankVault, true);
+      await program.methods
+        .initializeGame(
+          Array.from(CR_GID),
+          2,
+          new BN(0),
+          DUMMY_NFT_COLLECTION,
+          null,
+          { commitReveal: {} },
+          null
+        )
+        .accounts({
+          host: payer.publicKey,
+          gameState: crGameState,
+          boardConfig,
+          cardDeck,
+          bpolyMint,
+          bankVault: crBankVault,
+          bankBpolyAta: crBankAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([payer])
+        .rpc();
+      await mintTo(connection, payer, bpolyMint, crBankAta, payer, BigInt(10_000_000 * 1_000_000));
+
+      for (const [player, playerState, playerAta] of [
+        [player1, p1State, player1Ata],
+        [player2, p2State, player2Ata],
+      ] as [Keypair, PublicKey, PublicKey][]) {
+        await program.methods
+          .joinGame(Array.from(CR_GID))
+          .accounts({
+            player: player.publicKey,
+            gameState: crGameState,
+            playerState,
+            playerBpolyAta: playerAta,
+            bpolyMint,
+            bankVault: crBankVault,
+            bankBpolyAta: crBankAta,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            rent: SYSVAR_RENT_PUBKEY,
+          })
+          .signers([player])
+          .rpc();
+      }
+
+      await program.methods
+        .startGame(Array.from(CR_GID), Array.from(crypto.getRandomValues(new Uint8Array(32))))
+        .accounts({ host: payer.publicKey, gameState: crGameState })
+        .signers([payer])
+        .rpc();
+    });
+
+    async function commit(player: Keypair, secret: Buffer, turnNumber: number) {
+      await program.methods
+        .commitDice(Array.from(CR_GID), diceCommitment(player.publicKey, secret))
+        .accounts({
+          player: player.publicKey,
+          gameState: crGameState,
+          playerState: player === player1 ? p1State : p2State,
+          diceCommitment: commitmentPDA(turnNumber),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player])
+        .rpc();
+    }
+
+    async function reveal(player: Keypair, secret: Buffer, turnNumber: number) {
+      await program.methods
+        .revealDice(Array.from(CR_GID), Array.from(secret))
+        .accounts({
+          player: player.publicKey,
+          gameState: crGameState,
+          boardConfig,
+          diceCommitment: commitmentPDA(turnNumber),
+          rollerState: p1State,
+          roller: player1.publicKey,
+        })
+        .signers([player])
+        .rpc();
+    }
+
+    it("rejects request_dice_roll in a commit-reveal game", async () => {
+      try {
+        await program.methods
+          .requestDiceRoll(Array.from(CR_GID))
+          .accounts({
+            player: player1.publicKey,
+            gameState: crGameState,
+            playerState: p1State,
+            randomnessAccount: player1Randomness.publicKey,
+            randomnessQueue,
+            randomnessOracle: payer.publicKey,
+            recentSlothashes: SYSVAR_SLOT_HASHES_PUBKEY,
+            switchboardProgram: mockOracle.programId,
+          })
+          .signers([player1])
+          .rpc();
+        assert.fail("request_dice_roll should be disabled");
+      } catch (err: any) {
+        assert.include(err.toString(), "WrongDiceMode");
+      }
+    });
+
+    it("only the current player can open the roll", async () => {
+      const gs = await program.account.gameState.fetch(crGameState);
+      try {
+        await commit(player2, secret2, gs.turnNumber);
+        assert.fail("player2 should not open player1's roll");
+      } catch (err: any) {
+        assert.include(err.toString(), "NotYourTurn");
+      }
+    });
+
+    it("every player commits, reveals, and the XOR decides the roll", async () => {
+      const gs = await program.account.gameState.fetch(crGameState);
+      const turn = gs.turnNumber;
+
+      await commit(player1, secret1, turn);
+      let record = await program.account.diceCommitment.fetch(commitmentPDA(turn));
+      assert.equal(record.roller.toString(), player1.publicKey.toString());
+      assert.isFalse(record.revealOpen, "Reveals open only after everyone commits");
+
+      try {
+        await reveal(player1, secret1, turn);
+        assert.fail("reveal before all commitments should fail");
+      } catch (err: any) {
+        assert.include(err.toString(), "RevealNotOpen");
+      }
+
+      await commit(player2, secret2, turn);
+      record = await program.account.diceCommitment.fetch(commitmentPDA(turn));
+      assert.isTrue(record.revealOpen);
+
+      try {
+        await reveal(player2, secret1, turn);
+        assert.fail("a secret that does not match the commitment should fail");
+      } catch (err: any) {
+        assert.include(err.toString(), "InvalidReveal");
+      }
+
+      await reveal(player2, secret2, turn);
+      await reveal(player1, secret1, turn);
+
+      const ps = await program.account.playerState.fetch(p1State);
+      assert.equal(ps.position, 4, "die1=2 + die2=2 → Gas Fees Tax");
+      const after = await program.account.gameState.fetch(crGameState);
+      assert.ok(after.turnPhase.landingEffect !== undefined, "Should be LandingEffect");
+      assert.deepEqual(after.pendingDice, [2, 2]);
+      assert.isTrue(after.extraRoll, "Doubles earn another roll");
+      assert.isNull(
+        await connection.getAccountInfo(commitmentPDA(turn)),
+        "Commitment record is closed once the roll settles"
+      );
+      console.log("  Commit-reveal roll 2+2 → space 4 ✓");
+    });
+
+    it("doubles keep the turn after the landing resolves", async () => {
+      const before = await program.account.gameState.fetch(crGameState);
+
+      await program.methods
+        .resolveLanding(Array.from(CR_GID))
+        .accounts({
+          player: player1.publicKey,
+          gameState: crGameState,
+          boardConfig,
+          playerState: p1State,
+          propertyState: null, // Gas Fees Tax is not ownable
+          bankVault: crBankVault,
+          bankBpolyAta: crBankAta,
+          playerBpolyAta: player1Ata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([player1])
+        .rpc();
+
+      const gs = await program.account.gameState.fetch(crGameState);
+      assert.ok(gs.turnPhase.rollDice !== undefined, "Should be RollDice again");
+      assert.equal(gs.currentPlayerIndex, 0, "Player1 keeps the turn");
+      assert.equal(gs.turnNumber, before.turnNumber, "Turn does not advance");
+      assert.isFalse(gs.extraRoll);
+      const ps = await program.account.playerState.fetch(p1State);
+      assert.equal(ps.doublesStreak, 1, "Streak carries into the extra roll");
+      console.log("  Doubles → player1 rolls again ✓");
+    });
+  });
+
   // ── Summary ────────────────────────────────────────────────────────────────
 
   after(async () => {
@@ -4,21 +4,55 @@
  *
  * Board data / constant checks run without any SVM.
  * Full instruction tests require `anchor build` first (loads the .so).
+ * pnpm test:unit builds without the mock-oracle feature, so oracle dice run
+ * against Switchboard-owned randomness accounts as in production.
  *
  * Run: pnpm test:unit
  */
 
 import { assert } from "chai";
-import { PublicKey } from "@solana/web3.js";
+import { createHash } from "crypto";
+import { existsSync, readFileSync } from "fs";
+import {
+  Keypair,
+  PublicKey,
+  SYSVAR_RENT_PUBKEY,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  ACCOUNT_SIZE,
+  AccountLayout,
+  MINT_SIZE,
+  MintLayout,
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { FailedTransactionMetadata, LiteSVM } from "litesvm";
 
 // ── Constants mirrored from programs/blockpoly/src/ ──────────────────────────
 
-const PROGRAM_ID = new PublicKey("Bp1ypXF8ggBd7f6sWuXEsWs8iSU9L3dGAD9DPpAZ7bHm");
+const PROGRAM_ID = new PublicKey("AicXQXhiHgzaxTXpbxYEriXSQdBRQNbqWgcMU1N57q9n");
+const PROGRAM_SO = "target/deploy/blockpoly.so";
 
 const SEED_GAME_STATE = Buffer.from("game_state");
 const SEED_PLAYER_STATE = Buffer.from("player_state");
 const SEED_PROPERTY_STATE = Buffer.from("property_state");
 const SEED_BANK_VAULT = Buffer.from("bank_vault");
+const SEED_BOARD_CONFIG = Buffer.from("board_config");
+const SEED_CARD_DECK = Buffer.from("card_deck");
+const SEED_TRADE_OFFER = Buffer.from("trade_offer");
+const SEED_DICE_COMMITMENT = Buffer.from("dice_commitment");
+
+// switchboard.rs: a build with --features mock-oracle reads randomness
+// accounts owned by the mock oracle instead of Switchboard On-Demand
+const SWITCHBOARD_PROGRAM_ID = new PublicKey("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+const MOCK_ORACLE_PROGRAM_ID = new PublicKey("4AHSnZ8vW5D4VLMbmY9tGVpMnzMP6ekwJ2uo22WtGaQS");
+const ORACLE_PROGRAM_ID =
+  existsSync(PROGRAM_SO) && readFileSync(PROGRAM_SO).includes(MOCK_ORACLE_PROGRAM_ID.toBuffer())
+    ? MOCK_ORACLE_PROGRAM_ID
+    : SWITCHBOARD_PROGRAM_ID;
 
 // Space type constants (must match constants.rs)
 const SPACE_TYPE_PROPERTY = 0;
@@ -33,14 +67,17 @@ const SPACE_TYPE_FREE_PARKING = 8;
 const SPACE_TYPE_GENESIS = 9;
 
 // Color group constants (must match constants.rs)
-const GROUP_BROWN = 1;
-const GROUP_LIGHT_BLUE = 2;
-const GROUP_PINK = 3;
-const GROUP_ORANGE = 4;
-const GROUP_RED = 5;
+const GROUP_BROWN = 0;
+const GROUP_LIGHT_BLUE = 1;
+const GROUP_PINK = 2;
+const GROUP_ORANGE = 3;
+const GROUP_RED = 4;
+const GROUP_YELLOW = 5;
 const GROUP_GREEN = 6;
 const GROUP_DARK_BLUE = 7;
 const GROUP_BRIDGE = 8;
+const GROUP_UTILITY = 9;
+const GROUP_NONE = 255;
 
 const BPOLY = (n: number) => BigInt(n) * 1_000_000n;
 
@@ -61,16 +98,16 @@ interface SpaceData {
 }
 
 const BOARD: SpaceData[] = [
-  { index: 0,  name: "Genesis Block",  spaceType: SPACE_TYPE_GENESIS,          group: 0, price: BPOLY(0),   baseRent: BPOLY(0),   lpRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)],     protocolRent: BPOLY(0),    mortgageValue: BPOLY(0),  lpCost: BPOLY(0), bridgeRents: [BPOLY(25), BPOLY(50), BPOLY(100), BPOLY(200)] },
+  { index: 0,  name: "Genesis Block",  spaceType: SPACE_TYPE_GENESIS,          group: GROUP_NONE, price: BPOLY(0),   baseRent: BPOLY(0),   lpRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)],     protocolRent: BPOLY(0),    mortgageValue: BPOLY(0),  lpCost: BPOLY(0), bridgeRents: [BPOLY(25), BPOLY(50), BPOLY(100), BPOLY(200)] },
   { index: 1,  name: "BONK",           spaceType: SPACE_TYPE_PROPERTY,         group: GROUP_BROWN,      price: BPOLY(60),  baseRent: BPOLY(2),   lpRents: [BPOLY(10), BPOLY(30), BPOLY(90), BPOLY(160)],  protocolRent: BPOLY(250),  mortgageValue: BPOLY(30), lpCost: BPOLY(50), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
-  { index: 2,  name: "Alpha Call",     spaceType: SPACE_TYPE_CARD_ALPHA,       group: 0, price: BPOLY(0),   baseRent: BPOLY(0),   lpRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)],     protocolRent: BPOLY(0),    mortgageValue: BPOLY(0),  lpCost: BPOLY(0), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
+  { index: 2,  name: "Alpha Call",     spaceType: SPACE_TYPE_CARD_ALPHA,       group: GROUP_NONE, price: BPOLY(0),   baseRent: BPOLY(0),   lpRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)],     protocolRent: BPOLY(0),    mortgageValue: BPOLY(0),  lpCost: BPOLY(0), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
   { index: 3,  name: "dogwifhat",      spaceType: SPACE_TYPE_PROPERTY,         group: GROUP_BROWN,      price: BPOLY(60),  baseRent: BPOLY(4),   lpRents: [BPOLY(20), BPOLY(60), BPOLY(180), BPOLY(320)], protocolRent: BPOLY(450),  mortgageValue: BPOLY(30), lpCost: BPOLY(50), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
-  { index: 4,  name: "Gas Fees Tax",   spaceType: SPACE_TYPE_TAX,              group: 0, price: BPOLY(0),   baseRent: BPOLY(0),   lpRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)],     protocolRent: BPOLY(0),    mortgageValue: BPOLY(0),  lpCost: BPOLY(0), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
+  { index: 4,  name: "Gas Fees Tax",   spaceType: SPACE_TYPE_TAX,              group: GROUP_NONE, price: BPOLY(0),   baseRent: BPOLY(200),   lpRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)],     protocolRent: BPOLY(0),    mortgageValue: BPOLY(0),  lpCost: BPOLY(0), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
   { index: 5,  name: "Wormhole",       spaceType: SPACE_TYPE_BRIDGE,           group: GROUP_BRIDGE,     price: BPOLY(200), baseRent: BPOLY(0),   lpRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)],     protocolRent: BPOLY(0),    mortgageValue: BPOLY(100),lpCost: BPOLY(0), bridgeRents: [BPOLY(25), BPOLY(50), BPOLY(100), BPOLY(200)] },
   { index: 6,  name: "Pyth Network",   spaceType: SPACE_TYPE_PROPERTY,         group: GROUP_LIGHT_BLUE, price: BPOLY(100), baseRent: BPOLY(6),   lpRents: [BPOLY(30), BPOLY(90), BPOLY(270), BPOLY(400)], protocolRent: BPOLY(550),  mortgageValue: BPOLY(50), lpCost: BPOLY(50), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
-  { index: 10, name: "Rug Pull Zone",  spaceType: SPACE_TYPE_RUGPULL,          group: 0, price: BPOLY(0),   baseRent: BPOLY(0),   lpRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)],     protocolRent: BPOLY(0),    mortgageValue: BPOLY(0),  lpCost: BPOLY(0), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
-  { index: 20, name: "DeFi Summer",    spaceType: SPACE_TYPE_FREE_PARKING,     group: 0, price: BPOLY(0),   baseRent: BPOLY(0),   lpRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)],     protocolRent: BPOLY(0),    mortgageValue: BPOLY(0),  lpCost: BPOLY(0), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
-  { index: 30, name: "SEC Investigation", spaceType: SPACE_TYPE_GO_TO_JAIL,    group: 0, price: BPOLY(0),   baseRent: BPOLY(0),   lpRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)],     protocolRent: BPOLY(0),    mortgageValue: BPOLY(0),  lpCost: BPOLY(0), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
+  { index: 10, name: "Rug Pull Zone",  spaceType: SPACE_TYPE_RUGPULL,          group: GROUP_NONE, price: BPOLY(0),   baseRent: BPOLY(0),   lpRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)],     protocolRent: BPOLY(0),    mortgageValue: BPOLY(0),  lpCost: BPOLY(0), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
+  { index: 20, name: "DeFi Summer",    spaceType: SPACE_TYPE_FREE_PARKING,     group: GROUP_NONE, price: BPOLY(0),   baseRent: BPOLY(0),   lpRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)],     protocolRent: BPOLY(0),    mortgageValue: BPOLY(0),  lpCost: BPOLY(0), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
+  { index: 30, name: "SEC Investigation", spaceType: SPACE_TYPE_GO_TO_JAIL,    group: GROUP_NONE, price: BPOLY(0),   baseRent: BPOLY(0),   lpRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)],     protocolRent: BPOLY(0),    mortgageValue: BPOLY(0),  lpCost: BPOLY(0), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
   { index: 31, name: "Jupiter",        spaceType: SPACE_TYPE_PROPERTY,         group: GROUP_GREEN,      price: BPOLY(300), baseRent: BPOLY(26),  lpRents: [BPOLY(130), BPOLY(390), BPOLY(900), BPOLY(1100)], protocolRent: BPOLY(1275), mortgageValue: BPOLY(150),lpCost: BPOLY(200), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
   { index: 37, name: "Helius",         spaceType: SPACE_TYPE_PROPERTY,         group: GROUP_DARK_BLUE,  price: BPOLY(350), baseRent: BPOLY(35),  lpRents: [BPOLY(175), BPOLY(500), BPOLY(1100), BPOLY(1300)], protocolRent: BPOLY(1500), mortgageValue: BPOLY(175),lpCost: BPOLY(200), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
   { index: 39, name: "Solana",         spaceType: SPACE_TYPE_PROPERTY,         group: GROUP_DARK_BLUE,  price: BPOLY(400), baseRent: BPOLY(50),  lpRents: [BPOLY(200), BPOLY(600), BPOLY(1400), BPOLY(1700)], protocolRent: BPOLY(2000), mortgageValue: BPOLY(200),lpCost: BPOLY(200), bridgeRents: [BPOLY(0), BPOLY(0), BPOLY(0), BPOLY(0)] },
@@ -106,6 +143,24 @@ function bankVaultPDA(gid: Buffer): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([SEED_BANK_VAULT, gid], PROGRAM_ID);
 }
 
+function boardConfigPDA(boardId: Buffer): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([SEED_BOARD_CONFIG, boardId], PROGRAM_ID);
+}
+
+function tradeOfferPDA(gid: Buffer, proposer: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([SEED_TRADE_OFFER, gid, proposer.toBuffer()], PROGRAM_ID);
+}
+
+function cardDeckPDA(deckId: Buffer): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([SEED_CARD_DECK, deckId], PROGRAM_ID);
+}
+
+function diceCommitmentPDA(gid: Buffer, turnNumber: number): [PublicKey, number] {
+  const turn = Buffer.alloc(4);
+  turn.writeUInt32LE(turnNumber);
+  return PublicKey.findProgramAddressSync([SEED_DICE_COMMITMENT, gid, turn], PROGRAM_ID);
+}
+
 // ── Tests ─────────────────────────────────────────────────────────────────────
 
 describe("Blockpoly — unit tests (no validator required)", () => {
@@ -292,3 +347,3178 @@ describe("Blockpoly — unit tests (no validator required)", () => {
     });
   });
 });
+
+// ── LiteSVM fixtures ──────────────────────────────────────────────────────────
+// Accounts are written straight into the SVM with the same Borsh layout as
+// programs/blockpoly/src/state, so instruction tests can start from any
+// board position without playing a whole game to get there.
+
+const TURN_PHASE = {
+  RollDice: 0,
+  AwaitingVRF: 1,
+  LandingEffect: 2,
+  DrawCard: 3,
+  RugPullDecision: 4,
+  AuctionPhase: 5,
+  BuyDecision: 6,
+  Finished: 7,
+  PayRent: 8,
+  RaiseFunds: 9,
+};
+const GAME_STATUS_IN_PROGRESS = 1;
+const GAME_STATUS_FINISHED = 2;
+const DEBT_REASON = { None: 0, Rent: 1, Tax: 2, Card: 3, Bail: 4, MarketEvent: 5, PlayerCard: 6, FlashLoan: 7 };
+const DICE_MODE = { Oracle: 0, CommitReveal: 1 };
+
+function discriminator(namespace: "account" | "global", name: string): Buffer {
+  return createHash("sha256").update(`${namespace}:${name}`).digest().subarray(0, 8);
+}
+
+/** Minimal Borsh writer matching the Anchor account layouts */
+class BorshWriter {
+  private parts: Buffer[] = [];
+
+  u8(v: number) {
+    this.parts.push(Buffer.from([v]));
+    return this;
+  }
+  bool(v: boolean) {
+    return this.u8(v ? 1 : 0);
+  }
+  u32(v: number) {
+    const b = Buffer.alloc(4);
+    b.writeUInt32LE(v);
+    this.parts.push(b);
+    return this;
+  }
+  u64(v: bigint) {
+    const b = Buffer.alloc(8);
+    b.writeBigUInt64LE(v);
+    this.parts.push(b);
+    return this;
+  }
+  i64(v: bigint) {
+    const b = Buffer.alloc(8);
+    b.writeBigInt64LE(v);
+    this.parts.push(b);
+    return this;
+  }
+  bytes(v: Uint8Array | number[]) {
+    this.parts.push(Buffer.from(v));
+    return this;
+  }
+  pubkey(v: PublicKey) {
+    return this.bytes(v.toBuffer());
+  }
+  string(v: string) {
+    const b = Buffer.from(v);
+    this.u32(b.length);
+    return this.bytes(b);
+  }
+  option<T>(v: T | null | undefined, write: (v: T) => void) {
+    if (v === null || v === undefined) return this.u8(0);
+    this.u8(1);
+    write(v);
+    return this;
+  }
+  vec<T>(items: T[], write: (v: T) => void) {
+    this.u32(items.length);
+    items.forEach(write);
+    return this;
+  }
+  /** Anchor accounts are allocated at MAX_SIZE; trailing bytes stay zero */
+  build(size = 0): Buffer {
+    const data = Buffer.concat(this.parts);
+    return data.length >= size ? data : Buffer.concat([data, Buffer.alloc(size - data.length)]);
+  }
+}
+
+interface RuleSetFixture {
+  startingBalance: bigint;
+  genesisSalary: bigint;
+  rugpullBail: bigint;
+  gasFeeTax: bigint;
+  protocolFeeTax: bigint;
+  auctionDurationTurns: number;
+  rugpullMaxTurns: number;
+  flashLoanAmount: bigint;
+  flashLoanRepay: bigint;
+  flashLoanPenalty: bigint;
+  doubleSalaryOnGenesis: boolean;
+  auctionOnDecline: boolean;
+  noRentInRugpull: boolean;
+  marketEvents: boolean;
+}
+
+// RuleSet::default() (constants.rs); a 0 tax charges the board's amount
+const DEFAULT_RULES: RuleSetFixture = {
+  startingBalance: BPOLY(1500),
+  genesisSalary: BPOLY(200),
+  rugpullBail: BPOLY(50),
+  gasFeeTax: 0n,
+  protocolFeeTax: 0n,
+  auctionDurationTurns: 3,
+  rugpullMaxTurns: 3,
+  flashLoanAmount: BPOLY(200),
+  flashLoanRepay: BPOLY(210),
+  flashLoanPenalty: BPOLY(50),
+  doubleSalaryOnGenesis: false,
+  auctionOnDecline: true,
+  noRentInRugpull: false,
+  marketEvents: false,
+};
+
+// BUILDING_* kinds (constants.rs)
+const BUILDING = { LP: 0, Protocol: 1 };
+
+// game_state.rs CardModifier
+const CARD_MODIFIER = { None: 0, DoubleBridgeRent: 1, HalfPricePurchase: 2 };
+
+// market_effect.rs enums
+const EFFECT_KIND = { BullRun: 0, BearMarket: 1, GasSpike: 2, SectorRally: 3 };
+type EffectTargetFixture = "rent" | "unimprovedRent" | { groupRent: number } | "tax" | "salary" | "buildCost";
+
+interface MarketEffectFixture {
+  kind: number;
+  target: EffectTargetFixture;
+  modifier: { multiplyBps: number } | { delta: bigint };
+  endsRound: number;
+}
+
+function writeMarketEffect(w: BorshWriter, e: MarketEffectFixture) {
+  w.u8(e.kind);
+  if (typeof e.target === "object") {
+    w.u8(2).u8(e.target.groupRent);
+  } else {
+    w.u8({ rent: 0, unimprovedRent: 1, tax: 3, salary: 4, buildCost: 5 }[e.target]);
+  }
+  if ("multiplyBps" in e.modifier) {
+    w.u8(0).u32(e.modifier.multiplyBps);
+  } else {
+    w.u8(1).i64(e.modifier.delta);
+  }
+  w.u32(e.endsRound);
+}
+
+interface GameFixture {
+  gid: Buffer;
+  players: PublicKey[];
+  bpolyMint: PublicKey;
+  boardConfig: PublicKey;
+  cardDeck: PublicKey;
+  turnPhase: number;
+  currentPlayerIndex?: number;
+  turnNumber?: number;
+  diceMode?: number;
+  /** Committed randomness account and its seed slot (oracle dice) */
+  vrfRequest?: { account: PublicKey; seedSlot: bigint };
+  randomnessAuthority?: PublicKey;
+  pendingDice?: [number, number] | null;
+  rules?: Partial<RuleSetFixture>;
+  roundNumber?: number;
+  marketEffects?: MarketEffectFixture[];
+  lastRollEntropy?: bigint;
+  /** Drawn market event (market_events.rs MARKET_EVENTS index) and its round */
+  marketEvent?: { id: number; round: number };
+  cardModifier?: number;
+  /** [deck_type, card_id] left by draw_card */
+  drawnCard?: [number, number];
+  /** Governance Vote draw order (default: the 16 cards in order) and next index */
+  governanceDeck?: number[];
+  governanceIndex?: number;
+  /** [deck_type, card_id] of Get Out of Rug Pull Free cards out of their decks */
+  heldCards?: [number, number][];
+  /** Bank building stock (default STARTING_LPS / STARTING_PROTOCOLS) */
+  lpsAvailable?: number;
+  protocolsAvailable?: number;
+  buildingAuction?: BuildingAuctionFixture;
+}
+
+interface BuildingAuctionFixture {
+  kind: number;
+  highestBid: bigint;
+  highestBidder: PublicKey | null;
+  endSlot: bigint;
+}
+
+function encodeGameState(g: GameFixture): Buffer {
+  const [, bump] = gameStatePDA(g.gid);
+  const [vault] = bankVaultPDA(g.gid);
+  const r = { ...DEFAULT_RULES, ...g.rules };
+  const w = new BorshWriter().bytes(discriminator("account", "GameState"));
+  w.bytes(g.gid)
+    .pubkey(g.players[0])                 // host
+    .u8(GAME_STATUS_IN_PROGRESS)          // status
+    .u8(g.turnPhase)                      // turn_phase
+    .u8(g.currentPlayerIndex ?? 0)        // current_player_index
+    .u32(g.turnNumber ?? 1)               // turn_number
+    .u32(g.roundNumber ?? 0)              // round_number
+    .vec(g.players, (p) => w.pubkey(p))   // players
+    .u8(g.players.length)                 // player_count
+    .u8(g.players.length)                 // max_players
+    .u8(g.diceMode ?? DICE_MODE.Oracle)   // dice_mode
+    .option(g.vrfRequest, (r) => w.pubkey(r.account)) // vrf_request
+    .u64(g.vrfRequest?.seedSlot ?? 0n)    // vrf_seed_slot
+    .pubkey(g.randomnessAuthority ?? PublicKey.default) // randomness_authority
+    .option(g.pendingDice, (d) => w.bytes(d)) // pending_dice
+    .bool(false)                          // extra_roll
+    .vec(Array.from({ length: 16 }, (_, i) => i), (c) => w.u8(c)) // alpha_call_deck
+    .u8(0)                                // alpha_call_index
+    .vec(g.governanceDeck ?? Array.from({ length: 16 }, (_, i) => i), (c) => w.u8(c)) // governance_deck
+    .u8(g.governanceIndex ?? 0)           // governance_index
+    .option(g.drawnCard, (c) => w.bytes(c)) // drawn_card
+    .vec(g.heldCards ?? [], (c) => w.bytes(c)) // held_cards
+    .vec(g.marketEffects ?? [], (e) => writeMarketEffect(w, e)) // market_effects
+    .u64(g.lastRollEntropy ?? 0n)         // last_roll_entropy
+    .option(g.marketEvent, (e) => w.u8(e.id)) // market_event
+    .u32(g.marketEvent?.round ?? 0)       // market_event_round
+    .bool(false)                          // market_event_started
+    .option(null, () => {})               // auction_space
+    .u64(0n)                              // auction_highest_bid
+    .option(null, () => {})               // auction_highest_bidder
+    .u32(0)                               // auction_end_turn
+    .u8(g.lpsAvailable ?? 32)             // lps_available
+    .u8(g.protocolsAvailable ?? 12)       // protocols_available
+    .option(g.buildingAuction, (a) => {   // building_auction
+      w.u8(a.kind).u64(a.highestBid).option(a.highestBidder, (b) => w.pubkey(b)).u64(a.endSlot);
+    })
+    .u64(0n)                              // prize_pool_lamports
+    .option(null, () => {})               // winner
+    .pubkey(PublicKey.default)            // nft_collection
+    .pubkey(g.bpolyMint)                  // bpoly_mint
+    .pubkey(getAssociatedTokenAddressSync(g.bpolyMint, vault, true)) // bank_bpoly_ata
+    .pubkey(g.boardConfig)                // board_config
+    .pubkey(g.cardDeck)                   // card_deck
+    .option(null, () => {})               // last_rent_payer
+    .u64(0n)                              // last_rent_amount
+    .u8(g.cardModifier ?? CARD_MODIFIER.None) // card_modifier
+    .u64(r.startingBalance)               // rules
+    .u64(r.genesisSalary)
+    .u64(r.rugpullBail)
+    .u64(r.gasFeeTax)
+    .u64(r.protocolFeeTax)
+    .u32(r.auctionDurationTurns)
+    .u8(r.rugpullMaxTurns)
+    .u64(r.flashLoanAmount)
+    .u64(r.flashLoanRepay)
+    .u64(r.flashLoanPenalty)
+    .bool(r.doubleSalaryOnGenesis)
+    .bool(r.auctionOnDecline)
+    .bool(r.noRentInRugpull)
+    .bool(r.marketEvents)
+    .u8(bump);
+  return w.build(1024);
+}
+
+/** Card deck fields of an encoded GameState, and the offset just past them */
+function readGameDecks(data: Buffer) {
+  let o = 83;
+  o += 4 + 32 * data.readUInt32LE(o); // players
+  o += 3; // player_count, max_players, dice_mode
+  o += data[o] === 1 ? 33 : 1; // vrf_request
+  o += 8 + 32; // vrf_seed_slot, randomness_authority
+  o += data[o] === 1 ? 3 : 1; // pending_dice
+  o += 1; // extra_roll
+  const bytes = () => {
+    const n = data.readUInt32LE(o);
+    const v = Array.from(data.subarray(o + 4, o + 4 + n));
+    o += 4 + n;
+    return v;
+  };
+  const alphaCall = bytes();
+  const alphaCallIndex = data[o++];
+  const governance = bytes();
+  const governanceIndex = data[o++];
+  o += data[o] === 1 ? 3 : 1; // drawn_card
+  const heldCards: [number, number][] = [];
+  const held = data.readUInt32LE(o);
+  o += 4;
+  for (let i = 0; i < held; i++, o += 2) heldCards.push([data[o], data[o + 1]]);
+  return { alphaCall, alphaCallIndex, governance, governanceIndex, heldCards, end: o };
+}
+
+interface PlayerFixture {
+  gid: Buffer;
+  wallet: PublicKey;
+  playerIndex: number;
+  position: number;
+  properties?: number[];
+  balance: bigint;
+  rugpullTurns?: number;
+  debt?: { amount: bigint; creditor: PublicKey | null; reason: number };
+  marketEventRound?: number;
+  /** Held Get Out of Rug Pull Free card: 1 = Alpha Call, 2 = Governance Vote */
+  jailFreeCard?: number;
+  /** Open Flash Loan: amount owed and the turn it falls due */
+  flashLoan?: { repay: bigint; dueTurn: number };
+}
+
+function encodePlayerState(p: PlayerFixture): Buffer {
+  const [game] = gameStatePDA(p.gid);
+  const [, bump] = playerStatePDA(p.gid, p.wallet);
+  const w = new BorshWriter().bytes(discriminator("account", "PlayerState"));
+  w.pubkey(game)
+    .pubkey(p.wallet)
+    .u8(p.playerIndex)
+    .u8(p.rugpullTurns ? 1 : 0)           // status: Active / InRugPullZone
+    .u8(p.position)
+    .bool(false)                          // passed_genesis_pending
+    .u8(0)                                // doubles_streak
+    .u8(p.rugpullTurns ?? 0)              // rugpull_turns_remaining
+    .bool(false)                          // rugpull_bail_due
+    .bool(!!p.jailFreeCard)               // has_jail_free_card
+    .u8(p.jailFreeCard ?? 0)              // jail_free_card_type
+    .vec(p.properties ?? [], (s) => w.u8(s))
+    .bool(!!p.flashLoan)                  // flash_loan_active
+    .u64(p.flashLoan?.repay ?? 0n)        // flash_loan_repay_amount
+    .u32(p.flashLoan?.dueTurn ?? 0)       // flash_loan_due_turn
+    .bool(false)                          // is_bankrupt
+    .u64(p.balance)                       // bpoly_balance
+    .u64(p.debt?.amount ?? 0n)            // debt_amount
+    .option(p.debt?.creditor, (c) => w.pubkey(c)) // debt_creditor
+    .u8(p.debt?.reason ?? 0)              // debt_reason
+    .u32(p.marketEventRound ?? 0)         // market_event_round
+    .u8(bump);
+  return w.build(256);
+}
+
+interface PropertyFixture {
+  gid: Buffer;
+  space: number;
+  owner: PublicKey;
+  liquidityPools?: number;
+  isFullProtocol?: boolean;
+  isMortgaged?: boolean;
+  nftAsset?: PublicKey;
+}
+
+function encodePropertyState(p: PropertyFixture): Buffer {
+  const [game] = gameStatePDA(p.gid);
+  const [, bump] = propertyStatePDA(p.gid, p.space);
+  return new BorshWriter()
+    .bytes(discriminator("account", "PropertyState"))
+    .pubkey(game)
+    .u8(p.space)
+    .pubkey(p.owner)
+    .u8(p.liquidityPools ?? 0)
+    .bool(p.isFullProtocol ?? false)
+    .bool(p.isMortgaged ?? false)
+    .pubkey(p.nftAsset ?? PublicKey.default) // nft_asset
+    .u8(bump)
+    .build();
+}
+
+/** A trade of Get Out of Rug Pull Free cards only, expiring well after turn 1 */
+interface TradeOfferFixture {
+  gid: Buffer;
+  proposer: PublicKey;
+  recipient: PublicKey;
+  offeredProperties?: number[];
+  requestedProperties?: number[];
+  offeredJailFree?: boolean;
+  requestedJailFree?: boolean;
+}
+
+function encodeTradeOffer(t: TradeOfferFixture): Buffer {
+  const [game] = gameStatePDA(t.gid);
+  const [, bump] = tradeOfferPDA(t.gid, t.proposer);
+  const w = new BorshWriter().bytes(discriminator("account", "TradeOffer"));
+  w.pubkey(game)
+    .pubkey(t.proposer)
+    .pubkey(t.recipient)
+    .vec(t.offeredProperties ?? [], (s) => w.u8(s))   // offered_properties
+    .u64(0n)                                          // offered_bpoly
+    .vec(t.requestedProperties ?? [], (s) => w.u8(s)) // requested_properties
+    .u64(0n)                                          // requested_bpoly
+    .u32(100)                                         // expires_turn
+    .bool(t.offeredJailFree ?? false)     // offered_jail_free
+    .bool(t.requestedJailFree ?? false)   // requested_jail_free
+    .u8(bump);
+  return w.build(256);
+}
+
+/** Switchboard RandomnessAccountData committed at `seedSlot`; revealed when `revealSlot` is set */
+interface RandomnessFixture {
+  seedSlot: bigint;
+  revealSlot?: bigint;
+  value?: number[];
+}
+
+function encodeRandomness(r: RandomnessFixture): Buffer {
+  const w = new BorshWriter().bytes(discriminator("account", "RandomnessAccountData"));
+  w.pubkey(PublicKey.default)             // authority
+    .pubkey(PublicKey.default)            // queue
+    .bytes(Buffer.alloc(32))              // seed_slothash
+    .u64(r.seedSlot)                      // seed_slot
+    .pubkey(PublicKey.default)            // oracle
+    .u64(r.revealSlot ?? 0n)              // reveal_slot
+    .bytes(Buffer.from(r.value ?? new Array(32).fill(0))); // value
+  return w.build(8 + 32 * 5 + 16 + 96 + 128);
+}
+
+/** BoardSpace as passed to set_board_spaces */
+function encodeBoardSpaces(spaces: SpaceData[]): Buffer {
+  const w = new BorshWriter();
+  w.vec(spaces, (s) => {
+    w.string(s.name).u8(s.spaceType).u8(s.group).u64(s.price).u64(s.baseRent);
+    s.lpRents.forEach((r) => w.u64(r));
+    w.u64(s.protocolRent).u64(s.mortgageValue).u64(s.lpCost);
+    s.bridgeRents.forEach((r) => w.u64(r));
+  });
+  return w.build();
+}
+
+/** CardEffect variants the tests write with set_deck_cards (state/card_deck.rs order) */
+type CardEffectFixture =
+  | { payBank: bigint }
+  | { collectFromBank: bigint }
+  | { moveTo: { space: number; modifier: number } }
+  | { moveBack: number }
+  | { moveToNearestUnowned: { spaceType: number; modifier: number } };
+
+interface CardFixture {
+  name: string;
+  effect: CardEffectFixture;
+}
+
+function encodeCards(cards: CardFixture[]): Buffer {
+  const w = new BorshWriter();
+  w.vec(cards, (c) => {
+    w.string(c.name);
+    const e = c.effect;
+    if ("payBank" in e) w.u8(0).u64(e.payBank);
+    else if ("collectFromBank" in e) w.u8(1).u64(e.collectFromBank);
+    else if ("moveTo" in e) w.u8(9).u8(e.moveTo.space).u8(e.moveTo.modifier);
+    else if ("moveBack" in e) w.u8(12).u8(e.moveBack);
+    else w.u8(22).u8(e.moveToNearestUnowned.spaceType).u8(e.moveToNearestUnowned.modifier);
+  });
+  return w.build();
+}
+
+const DECK = { AlphaCall: 0, Governance: 1 };
+
+const DEFAULT_BOARD_ID = gameId("default-board");
+const DEFAULT_DECK_ID = gameId("default-deck");
+
+/**
+ * A LiteSVM instance with Blockpoly loaded, a BPOLY mint, a finalized default
+ * board and card deck, and helpers to seed state
+ */
+class BlockpolySvm {
+  readonly svm = new LiteSVM();
+  readonly mint = Keypair.generate().publicKey;
+  readonly boardAuthority: Keypair;
+  readonly board: PublicKey;
+  readonly deckAuthority: Keypair;
+  readonly deck: PublicKey;
+
+  constructor(readonly gid: Buffer) {
+    this.svm.addProgramFromFile(PROGRAM_ID, PROGRAM_SO);
+    const data = Buffer.alloc(MINT_SIZE);
+    MintLayout.encode(
+      {
+        mintAuthorityOption: 0,
+        mintAuthority: PublicKey.default,
+        supply: 0n,
+        decimals: 6,
+        isInitialized: true,
+        freezeAuthorityOption: 0,
+        freezeAuthority: PublicKey.default,
+      },
+      data
+    );
+    this.setAccount(this.mint, data, TOKEN_PROGRAM_ID);
+
+    this.boardAuthority = this.wallet();
+    this.board = this.createBoard(DEFAULT_BOARD_ID);
+    const logs = this.finalizeBoard(DEFAULT_BOARD_ID);
+    if (logs) throw new Error(`default board failed to finalize:\n${logs}`);
+
+    this.deckAuthority = this.wallet();
+    this.deck = this.createDeck(DEFAULT_DECK_ID);
+    const deckLogs = this.finalizeDeck(DEFAULT_DECK_ID);
+    if (deckLogs) throw new Error(`default deck failed to finalize:\n${deckLogs}`);
+  }
+
+  setAccount(address: PublicKey, data: Buffer, owner = PROGRAM_ID) {
+    this.svm.setAccount(address, {
+      lamports: 1_000_000_000,
+      data,
+      owner,
+      executable: false,
+    });
+  }
+
+  wallet(): Keypair {
+    const kp = Keypair.generate();
+    this.svm.airdrop(kp.publicKey, BigInt(10_000_000_000));
+    return kp;
+  }
+
+  /**
+   * Creates (or overwrites) `owner`'s BPOLY ATA holding `amount`. A player's ATA
+   * approves the bank vault as delegate, as join_game does, unless `approveBank` is false.
+   */
+  tokenAccount(owner: PublicKey, amount: bigint, approveBank = true): PublicKey {
+    const ata = getAssociatedTokenAddressSync(this.mint, owner, true);
+    const [vault] = bankVaultPDA(this.gid);
+    const delegated = approveBank && !owner.equals(vault);
+    const data = Buffer.alloc(ACCOUNT_SIZE);
+    AccountLayout.encode(
+      {
+        mint: this.mint,
+        owner,
+        amount,
+        delegateOption: delegated ? 1 : 0,
+        delegate: delegated ? vault : PublicKey.default,
+        state: 1,
+        isNativeOption: 0,
+        isNative: 0n,
+        delegatedAmount: delegated ? 0xffff_ffff_ffff_ffffn : 0n,
+        closeAuthorityOption: 0,
+        closeAuthority: PublicKey.default,
+      },
+      data
+    );
+    this.setAccount(ata, data, TOKEN_PROGRAM_ID);
+    return ata;
+  }
+
+  tokenBalance(ata: PublicKey): bigint {
+    const account = this.svm.getAccount(ata);
+    return AccountLayout.decode(Buffer.from(account!.data)).amount;
+  }
+
+  game(
+    g: Omit<GameFixture, "gid" | "bpolyMint" | "boardConfig" | "cardDeck"> & {
+      boardConfig?: PublicKey;
+      cardDeck?: PublicKey;
+    }
+  ) {
+    const [pda] = gameStatePDA(this.gid);
+    this.setAccount(
+      pda,
+      encodeGameState({
+        boardConfig: this.board,
+        cardDeck: this.deck,
+        ...g,
+        gid: this.gid,
+        bpolyMint: this.mint,
+      })
+    );
+    return pda;
+  }
+
+  player(p: Omit<PlayerFixture, "gid">) {
+    const [pda] = playerStatePDA(this.gid, p.wallet);
+    this.setAccount(pda, encodePlayerState({ ...p, gid: this.gid }));
+    return pda;
+  }
+
+  property(p: Omit<PropertyFixture, "gid">) {
+    const [pda] = propertyStatePDA(this.gid, p.space);
+    this.setAccount(pda, encodePropertyState({ ...p, gid: this.gid }));
+    return pda;
+  }
+
+  tradeOffer(t: Omit<TradeOfferFixture, "gid">) {
+    const [pda] = tradeOfferPDA(this.gid, t.proposer);
+    this.setAccount(pda, encodeTradeOffer({ ...t, gid: this.gid }));
+    return pda;
+  }
+
+  /** Writes a randomness account owned by the oracle program this build reads */
+  randomness(address: PublicKey, r: RandomnessFixture) {
+    this.setAccount(address, encodeRandomness(r), ORACLE_PROGRAM_ID);
+  }
+
+  /** Creates a BoardConfig holding the default board; returns its address */
+  createBoard(boardId: Buffer): PublicKey {
+    const [pda] = boardConfigPDA(boardId);
+    const logs = this.send(
+      new TransactionInstruction({
+        programId: PROGRAM_ID,
+        keys: [
+          { pubkey: this.boardAuthority.publicKey, isSigner: true, isWritable: true },
+          { pubkey: pda, isSigner: false, isWritable: true },
+          { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        ],
+        data: Buffer.concat([discriminator("global", "create_board_config"), boardId]),
+      }),
+      [this.boardAuthority]
+    );
+    if (logs) throw new Error(`create_board_config failed:\n${logs}`);
+    return pda;
+  }
+
+  setBoardSpaces(boardId: Buffer, start: number, spaces: SpaceData[], authority = this.boardAuthority) {
+    return this.send(
+      new TransactionInstruction({
+        programId: PROGRAM_ID,
+        keys: [
+          { pubkey: authority.publicKey, isSigner: true, isWritable: false },
+          { pubkey: boardConfigPDA(boardId)[0], isSigner: false, isWritable: true },
+        ],
+        data: Buffer.concat([
+          discriminator("global", "set_board_spaces"),
+          boardId,
+          Buffer.from([start]),
+          encodeBoardSpaces(spaces),
+        ]),
+      }),
+      [authority]
+    );
+  }
+
+  finalizeBoard(boardId: Buffer): string | null {
+    return this.send(
+      new TransactionInstruction({
+        programId: PROGRAM_ID,
+        keys: [
+          { pubkey: this.boardAuthority.publicKey, isSigner: true, isWritable: false },
+          { pubkey: boardConfigPDA(boardId)[0], isSigner: false, isWritable: true },
+        ],
+        data: Buffer.concat([discriminator("global", "finalize_board_config"), boardId]),
+      }),
+      [this.boardAuthority]
+    );
+  }
+
+  /** Creates a CardDeck holding the default decks; returns its address */
+  createDeck(deckId: Buffer): PublicKey {
+    const [pda] = cardDeckPDA(deckId);
+    const logs = this.send(
+      new TransactionInstruction({
+        programId: PROGRAM_ID,
+        keys: [
+          { pubkey: this.deckAuthority.publicKey, isSigner: true, isWritable: true },
+          { pubkey: pda, isSigner: false, isWritable: true },
+          { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        ],
+        data: Buffer.concat([discriminator("global", "create_card_deck"), deckId]),
+      }),
+      [this.deckAuthority]
+    );
+    if (logs) throw new Error(`create_card_deck failed:\n${logs}`);
+    return pda;
+  }
+
+  setDeckCards(
+    deckId: Buffer,
+    deckType: number,
+    start: number,
+    cards: CardFixture[],
+    authority = this.deckAuthority
+  ) {
+    return this.send(
+      new TransactionInstruction({
+        programId: PROGRAM_ID,
+        keys: [
+          { pubkey: authority.publicKey, isSigner: true, isWritable: false },
+          { pubkey: cardDeckPDA(deckId)[0], isSigner: false, isWritable: true },
+        ],
+        data: Buffer.concat([
+          discriminator("global", "set_deck_cards"),
+          deckId,
+          Buffer.from([deckType, start]),
+          encodeCards(cards),
+        ]),
+      }),
+      [authority]
+    );
+  }
+
+  finalizeDeck(deckId: Buffer): string | null {
+    return this.send(
+      new TransactionInstruction({
+        programId: PROGRAM_ID,
+        keys: [
+          { pubkey: this.deckAuthority.publicKey, isSigner: true, isWritable: false },
+          { pubkey: cardDeckPDA(deckId)[0], isSigner: false, isWritable: true },
+        ],
+        data: Buffer.concat([discriminator("global", "finalize_card_deck"), deckId]),
+      }),
+      [this.deckAuthority]
+    );
+  }
+
+  /** Sends one instruction; returns the failure logs, or null on success */
+  send(ix: TransactionInstruction, signers: Keypair[]): string | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = this.svm.latestBlockhash();
+    tx.feePayer = signers[0].publicKey;
+    tx.add(ix);
+    tx.sign(...signers);
+    const result = this.svm.sendTransaction(tx);
+    this.svm.expireBlockhash();
+    if (result instanceof FailedTransactionMetadata) {
+      return result.meta().logs().join("\n");
+    }
+    return null;
+  }
+}
+
+const describeSvm = existsSync(PROGRAM_SO) ? describe : describe.skip;
+
+// Spaces of each color group on the default board
+const GROUP_SPACES: Record<string, number[]> = {
+  brown: [1, 3],
+  lightBlue: [6, 8, 9],
+  pink: [11, 13, 14],
+  darkBlue: [37, 39],
+};
+
+describeSvm("Blockpoly — oracle dice on LiteSVM (requires anchor build)", () => {
+  const SEED_SLOT = 1_000n;
+  const RUGPULL_ZONE = 10;
+
+  /** Default randomness authority (switchboard.rs default_randomness_authority) */
+  const [CRANK_AUTHORITY] = PublicKey.findProgramAddressSync([Buffer.from("randomness_authority")], PROGRAM_ID);
+
+  /**
+   * Player 0 has committed `randomness` at SEED_SLOT and awaits the reveal;
+   * `keeper` settles rolls unless the game is on the default authority
+   */
+  function setup(
+    label: string,
+    opts: { position?: number; rugpullTurns?: number; defaultAuthority?: boolean } = {}
+  ) {
+    const env = new BlockpolySvm(gameId(`oracle-${label}`));
+    env.svm.warpToSlot(SEED_SLOT + 1n);
+    const [roller, other, keeper] = [env.wallet(), env.wallet(), env.wallet()];
+    const randomness = Keypair.generate().publicKey;
+    const gameState = env.game({
+      players: [roller.publicKey, other.publicKey],
+      turnPhase: TURN_PHASE.AwaitingVRF,
+      vrfRequest: { account: randomness, seedSlot: SEED_SLOT },
+      randomnessAuthority: opts.defaultAuthority ? CRANK_AUTHORITY : keeper.publicKey,
+    });
+    const rollerState = env.player({
+      wallet: roller.publicKey,
+      playerIndex: 0,
+      position: opts.position ?? 0,
+      balance: BPOLY(1500),
+      rugpullTurns: opts.rugpullTurns,
+    });
+    env.player({ wallet: other.publicKey, playerIndex: 1, position: 0, balance: BPOLY(1500) });
+    return { env, roller, other, keeper, randomness, gameState, rollerState };
+  }
+
+  /** Reveals `dice` (raw bytes 0 and 1: die = byte % 6 + 1) at `revealSlot` */
+  function reveal(s: ReturnType<typeof setup>, dice: [number, number], revealSlot = SEED_SLOT + 1n) {
+    const value = new Array(32).fill(0);
+    [value[0], value[1]] = dice;
+    s.env.randomness(s.randomness, { seedSlot: SEED_SLOT, revealSlot, value });
+  }
+
+  function consume(s: ReturnType<typeof setup>, authority = s.keeper) {
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: authority.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.env.board, isSigner: false, isWritable: false },
+        { pubkey: s.rollerState, isSigner: false, isWritable: true },
+        { pubkey: s.randomness, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([discriminator("global", "consume_randomness"), s.env.gid]),
+    });
+    return s.env.send(ix, [authority]);
+  }
+
+  function expire(s: ReturnType<typeof setup>) {
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: s.other.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.rollerState, isSigner: false, isWritable: true },
+        { pubkey: s.randomness, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([discriminator("global", "expire_dice_roll"), s.env.gid]),
+    });
+    return s.env.send(ix, [s.other]);
+  }
+
+  /** VRF_TIMEOUT_SLOTS past the commit */
+  function timeOut(s: ReturnType<typeof setup>) {
+    s.env.svm.warpToSlot(SEED_SLOT + 151n);
+  }
+
+  function gameBytes(s: ReturnType<typeof setup>) {
+    // GameState layout: disc(8) game_id(32) host(32) status(1) turn_phase(1) current_player_index(1)
+    const data = Buffer.from(s.env.svm.getAccount(s.gameState)!.data);
+    return { phase: data[73], currentPlayer: data[74] };
+  }
+
+  // PlayerState layout: disc(8) game(32) wallet(32) player_index(1) status(1) position(1)
+  // passed_genesis_pending(1) doubles_streak(1) rugpull_turns_remaining(1) rugpull_bail_due(1)
+  function rollerBytes(s: ReturnType<typeof setup>) {
+    const data = Buffer.from(s.env.svm.getAccount(s.rollerState)!.data);
+    return { status: data[73], position: data[74], rugpullTurns: data[77], bailDue: data[78] === 1 };
+  }
+
+  it("only the keeper settles a keeper game's rolls", () => {
+    const s = setup("keeper");
+    reveal(s, [3, 1]);
+    assert.include(consume(s, s.roller) ?? "", "UnauthorizedRandomnessAuthority");
+    assert.isNull(consume(s));
+  });
+
+  it("with the default authority anyone can crank the revealed roll", () => {
+    const s = setup("crank", { defaultAuthority: true });
+    reveal(s, [3, 1]);
+    const logs = consume(s, s.other);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(rollerBytes(s).position, 6);
+    assert.equal(gameBytes(s).phase, TURN_PHASE.LandingEffect);
+  });
+
+  describe("Rug Pull Zone turns", () => {
+    // PlayerStatus: Active 0, InRugPullZone 1
+    it("a failed attempt uses up a turn and stays in the zone", () => {
+      const s = setup("rugpull-fail", { position: RUGPULL_ZONE, rugpullTurns: 3 });
+      reveal(s, [3, 1]);
+      const logs = consume(s);
+      assert.isNull(logs, logs ?? "");
+      assert.deepEqual(rollerBytes(s), { status: 1, position: RUGPULL_ZONE, rugpullTurns: 2, bailDue: false });
+      assert.equal(gameBytes(s).phase, TURN_PHASE.LandingEffect);
+    });
+
+    it("doubles roll out of the zone and move the player", () => {
+      const s = setup("rugpull-doubles", { position: RUGPULL_ZONE, rugpullTurns: 2 });
+      reveal(s, [1, 1]);
+      const logs = consume(s);
+      assert.isNull(logs, logs ?? "");
+      assert.deepEqual(rollerBytes(s), { status: 0, position: RUGPULL_ZONE + 4, rugpullTurns: 0, bailDue: false });
+    });
+
+    it("the third failure releases the player with bail due and moves them", () => {
+      const s = setup("rugpull-bail", { position: RUGPULL_ZONE, rugpullTurns: 1 });
+      reveal(s, [3, 1]);
+      const logs = consume(s);
+      assert.isNull(logs, logs ?? "");
+      assert.deepEqual(rollerBytes(s), { status: 0, position: RUGPULL_ZONE + 6, rugpullTurns: 0, bailDue: true });
+      assert.equal(gameBytes(s).phase, TURN_PHASE.LandingEffect);
+    });
+  });
+
+  it("settles a reveal in a later slot than the reveal", () => {
+    const s = setup("late");
+    assert.include(consume(s) ?? "", "RandomnessNotResolved");
+
+    reveal(s, [3, 1]);
+    s.env.svm.warpToSlot(SEED_SLOT + 5n);
+    const logs = consume(s);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(rollerBytes(s).position, 6);
+    assert.equal(gameBytes(s).phase, TURN_PHASE.LandingEffect);
+  });
+
+  it("expire_dice_roll sends the roller back to RollDice when the oracle never revealed", () => {
+    const s = setup("unrevealed");
+    s.env.randomness(s.randomness, { seedSlot: SEED_SLOT });
+    assert.include(expire(s) ?? "", "DiceTimeoutNotReached");
+
+    timeOut(s);
+    const logs = expire(s);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(gameBytes(s).phase, TURN_PHASE.RollDice);
+    assert.equal(gameBytes(s).currentPlayer, 0, "Still their turn");
+    assert.equal(rollerBytes(s).position, 0);
+  });
+
+  it("a revealed roll can only be settled, never expired", () => {
+    const s = setup("revealed");
+    reveal(s, [3, 1]);
+    timeOut(s);
+    assert.include(expire(s) ?? "", "RandomnessRevealed");
+    assert.isNull(consume(s));
+  });
+
+  it("a roller who re-commits the randomness forfeits the roll to the Rug Pull Zone", () => {
+    const s = setup("recommitted", { position: 5 });
+    s.env.randomness(s.randomness, { seedSlot: SEED_SLOT + 40n, revealSlot: SEED_SLOT + 41n });
+    timeOut(s);
+    assert.include(consume(s) ?? "", "RandomnessNotCommitted");
+
+    const logs = expire(s);
+    assert.isNull(logs, logs ?? "");
+    assert.deepInclude(rollerBytes(s), { position: RUGPULL_ZONE, rugpullTurns: 3 });
+    assert.deepEqual(gameBytes(s), { phase: TURN_PHASE.RollDice, currentPlayer: 1 });
+  });
+});
+
+describeSvm("Blockpoly — commit-reveal dice on LiteSVM (requires anchor build)", () => {
+  const RUGPULL_ZONE = 10;
+
+  /** Player 0 is up in a two-player commit-reveal game on turn 1 */
+  function setup(label: string) {
+    const env = new BlockpolySvm(gameId(`commit-reveal-${label}`));
+    const [roller, other] = [env.wallet(), env.wallet()];
+    const gameState = env.game({
+      players: [roller.publicKey, other.publicKey],
+      turnPhase: TURN_PHASE.RollDice,
+      diceMode: DICE_MODE.CommitReveal,
+    });
+    const rollerState = env.player({ wallet: roller.publicKey, playerIndex: 0, position: 0, balance: BPOLY(1500) });
+    const otherState = env.player({ wallet: other.publicKey, playerIndex: 1, position: 0, balance: BPOLY(1500) });
+    const [record] = diceCommitmentPDA(env.gid, 1);
+    return { env, roller, other, gameState, rollerState, otherState, record };
+  }
+
+  function secretOf(player: Keypair): Buffer {
+    return createHash("sha256").update(player.publicKey.toBuffer()).digest();
+  }
+
+  function commit(s: ReturnType<typeof setup>, player: Keypair, playerState: PublicKey) {
+    const commitment = createHash("sha256").update(player.publicKey.toBuffer()).update(secretOf(player)).digest();
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: player.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: playerState, isSigner: false, isWritable: true },
+        { pubkey: s.record, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([discriminator("global", "commit_dice"), s.env.gid, commitment]),
+    });
+    return s.env.send(ix, [player]);
+  }
+
+  /** reveal_dice, or resolve_dice_timeout when `player` is omitted */
+  function close(s: ReturnType<typeof setup>, player?: Keypair, offenders: PublicKey[] = []) {
+    const signer = player ?? s.other;
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: signer.publicKey, isSigner: true, isWritable: false },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.env.board, isSigner: false, isWritable: false },
+        { pubkey: s.record, isSigner: false, isWritable: true },
+        { pubkey: s.rollerState, isSigner: false, isWritable: true },
+        { pubkey: s.roller.publicKey, isSigner: false, isWritable: true },
+        ...offenders.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+      ],
+      data: player
+        ? Buffer.concat([discriminator("global", "reveal_dice"), s.env.gid, secretOf(player)])
+        : Buffer.concat([discriminator("global", "resolve_dice_timeout"), s.env.gid]),
+    });
+    return s.env.send(ix, [signer]);
+  }
+
+  function phaseOf(s: ReturnType<typeof setup>) {
+    // GameState layout: disc(8) game_id(32) host(32) status(1) turn_phase(1) current_player_index(1)
+    const data = Buffer.from(s.env.svm.getAccount(s.gameState)!.data);
+    return { phase: data[73], currentPlayer: data[74] };
+  }
+
+  // PlayerState layout: disc(8) game(32) wallet(32) player_index(1) status(1) position(1)
+  const positionOf = (s: ReturnType<typeof setup>, state: PublicKey) => s.env.svm.getAccount(state)!.data[74];
+
+  it("a withheld reveal jails the offender and the roller rolls again instead of settling", () => {
+    const s = setup("withheld");
+    assert.isNull(commit(s, s.roller, s.rollerState));
+    assert.isNull(commit(s, s.other, s.otherState));
+    assert.isNull(close(s, s.roller));
+    assert.include(close(s, undefined, [s.otherState]) ?? "", "DiceTimeoutNotReached");
+
+    // DICE_REVEAL_TIMEOUT_SLOTS
+    s.env.svm.warpToSlot(s.env.svm.getClock().slot + 151n);
+    const logs = close(s, undefined, [s.otherState]);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(positionOf(s, s.otherState), RUGPULL_ZONE);
+    assert.equal(positionOf(s, s.rollerState), 0, "The partial XOR is never rolled");
+    assert.deepEqual(phaseOf(s), { phase: TURN_PHASE.RollDice, currentPlayer: 0 });
+    assert.isNull(s.env.svm.getAccount(s.record), "Record closed for a fresh roll");
+
+    const reopen = commit(s, s.roller, s.rollerState);
+    assert.isNull(reopen, reopen ?? "");
+    assert.equal(phaseOf(s).phase, TURN_PHASE.AwaitingVRF);
+  });
+
+  it("a roller who withholds their own reveal forfeits the roll", () => {
+    const s = setup("roller-withheld");
+    assert.isNull(commit(s, s.roller, s.rollerState));
+    assert.isNull(commit(s, s.other, s.otherState));
+    assert.isNull(close(s, s.other));
+
+    s.env.svm.warpToSlot(s.env.svm.getClock().slot + 151n);
+    assert.isNull(close(s));
+    assert.equal(positionOf(s, s.rollerState), RUGPULL_ZONE);
+    assert.deepEqual(phaseOf(s), { phase: TURN_PHASE.RollDice, currentPlayer: 1 });
+  });
+});
+
+describeSvm("Blockpoly — pay_rent on LiteSVM (requires anchor build)", () => {
+  const STARTING = BPOLY(1500);
+
+  /**
+   * Seeds a game where `owner` holds `owned` (optionally mortgaged) and the
+   * payer has just landed on `space`, then pays rent. Returns the rent paid,
+   * or the failure logs.
+   */
+  function payRent(opts: {
+    space: number;
+    group: number[];
+    owned: number[];
+    mortgaged?: number[];
+    groupAccounts?: number[];
+    ownerJailed?: boolean;
+    rules?: Partial<RuleSetFixture>;
+    roundNumber?: number;
+    marketEffects?: MarketEffectFixture[];
+    liquidityPools?: number;
+    cardModifier?: number;
+    /** Play on a board with these spaces replaced */
+    spaces?: SpaceData[];
+  }): { rent: bigint | null; logs: string | null } {
+    const gid = gameId(`rent-${opts.space}-${opts.owned.join("-")}-${!!opts.ownerJailed}`);
+    const env = new BlockpolySvm(gid);
+    const payer = env.wallet();
+    const owner = env.wallet();
+
+    let board = env.board;
+    if (opts.spaces) {
+      const boardId = gameId("themed-board");
+      board = env.createBoard(boardId);
+      for (const space of opts.spaces) {
+        assert.isNull(env.setBoardSpaces(boardId, space.index, [space]));
+      }
+      assert.isNull(env.finalizeBoard(boardId));
+    }
+
+    const gameState = env.game({
+      players: [payer.publicKey, owner.publicKey],
+      turnPhase: TURN_PHASE.PayRent,
+      pendingDice: [3, 4],
+      rules: opts.rules,
+      roundNumber: opts.roundNumber,
+      marketEffects: opts.marketEffects,
+      cardModifier: opts.cardModifier,
+      boardConfig: board,
+    });
+    const payerState = env.player({
+      wallet: payer.publicKey,
+      playerIndex: 0,
+      position: opts.space,
+      balance: STARTING,
+    });
+    const ownerState = env.player({
+      wallet: owner.publicKey,
+      playerIndex: 1,
+      position: opts.ownerJailed ? 10 : 0,
+      properties: opts.owned,
+      balance: STARTING,
+      rugpullTurns: opts.ownerJailed ? 2 : 0,
+    });
+    for (const space of opts.owned) {
+      env.property({
+        space,
+        owner: owner.publicKey,
+        isMortgaged: (opts.mortgaged ?? []).includes(space),
+        liquidityPools: space === opts.space ? opts.liquidityPools : 0,
+      });
+    }
+    const payerAta = env.tokenAccount(payer.publicKey, STARTING);
+    const ownerAta = env.tokenAccount(owner.publicKey, 0n);
+
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: payer.publicKey, isSigner: true, isWritable: true },
+        { pubkey: gameState, isSigner: false, isWritable: true },
+        { pubkey: board, isSigner: false, isWritable: false },
+        { pubkey: payerState, isSigner: false, isWritable: true },
+        { pubkey: propertyStatePDA(gid, opts.space)[0], isSigner: false, isWritable: false },
+        { pubkey: ownerState, isSigner: false, isWritable: false },
+        { pubkey: payerAta, isSigner: false, isWritable: true },
+        { pubkey: ownerAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        ...(opts.groupAccounts ?? opts.group).map((space) => ({
+          pubkey: propertyStatePDA(gid, space)[0],
+          isSigner: false,
+          isWritable: false,
+        })),
+      ],
+      data: Buffer.concat([discriminator("global", "pay_rent"), gid]),
+    });
+
+    const logs = env.send(ix, [payer]);
+    return { rent: logs ? null : env.tokenBalance(ownerAta), logs };
+  }
+
+  const baseRent = (space: number) => BOARD.find((s) => s.index === space)!.baseRent;
+
+  describe("two-property groups", () => {
+    it("Brown: owning BONK and dogwifhat doubles base rent", () => {
+      const { rent, logs } = payRent({ space: 3, group: GROUP_SPACES.brown, owned: [1, 3] });
+      assert.isNull(logs, logs ?? "");
+      assert.equal(rent, baseRent(3) * 2n);
+    });
+
+    it("Brown: owning only dogwifhat charges base rent", () => {
+      const { rent } = payRent({ space: 3, group: GROUP_SPACES.brown, owned: [3] });
+      assert.equal(rent, baseRent(3));
+    });
+
+    it("Dark Blue: owning Helius and Solana doubles base rent", () => {
+      const { rent, logs } = payRent({ space: 39, group: GROUP_SPACES.darkBlue, owned: [37, 39] });
+      assert.isNull(logs, logs ?? "");
+      assert.equal(rent, baseRent(39) * 2n);
+    });
+
+    it("Dark Blue: a mortgaged Helius breaks the monopoly", () => {
+      const { rent } = payRent({
+        space: 39,
+        group: GROUP_SPACES.darkBlue,
+        owned: [37, 39],
+        mortgaged: [37],
+      });
+      assert.equal(rent, baseRent(39));
+    });
+  });
+
+  describe("three-property groups", () => {
+    it("Light Blue: owning all three doubles base rent", () => {
+      const { rent, logs } = payRent({ space: 6, group: GROUP_SPACES.lightBlue, owned: [6, 8, 9] });
+      assert.isNull(logs, logs ?? "");
+      assert.equal(rent, baseRent(6) * 2n);
+    });
+
+    it("Light Blue: owning two of three charges base rent", () => {
+      const { rent } = payRent({ space: 6, group: GROUP_SPACES.lightBlue, owned: [6, 8] });
+      assert.equal(rent, baseRent(6));
+    });
+
+    it("Pink: one mortgaged member breaks the monopoly", () => {
+      const { rent } = payRent({
+        space: 11,
+        group: GROUP_SPACES.pink,
+        owned: [11, 13, 14],
+        mortgaged: [14],
+      });
+      assert.equal(rent, BPOLY(10));
+    });
+
+    it("rejects a group account list that leaves out a member", () => {
+      const { logs } = payRent({
+        space: 6,
+        group: GROUP_SPACES.lightBlue,
+        owned: [6, 8, 9],
+        groupAccounts: [6, 8],
+      });
+      assert.include(logs ?? "", "InvalidGroupAccounts");
+    });
+  });
+
+  describe("house rules", () => {
+    it("no_rent_in_rugpull: a jailed owner collects nothing", () => {
+      const { rent, logs } = payRent({
+        space: 3,
+        group: GROUP_SPACES.brown,
+        owned: [3],
+        ownerJailed: true,
+        rules: { noRentInRugpull: true },
+      });
+      assert.isNull(logs, logs ?? "");
+      assert.equal(rent, 0n);
+    });
+
+    it("standard rules: a jailed owner still collects", () => {
+      const { rent } = payRent({ space: 3, group: GROUP_SPACES.brown, owned: [3], ownerJailed: true });
+      assert.equal(rent, baseRent(3));
+    });
+  });
+
+  describe("market effects", () => {
+    const bullRun = (endsRound: number): MarketEffectFixture => ({
+      kind: EFFECT_KIND.BullRun,
+      target: "unimprovedRent",
+      modifier: { multiplyBps: 20_000 },
+      endsRound,
+    });
+
+    it("Bull Run doubles unimproved rent", () => {
+      const { rent, logs } = payRent({
+        space: 3,
+        group: GROUP_SPACES.brown,
+        owned: [3],
+        marketEffects: [bullRun(1)],
+      });
+      assert.isNull(logs, logs ?? "");
+      assert.equal(rent, baseRent(3) * 2n);
+    });
+
+    it("Bull Run leaves rent on spaces with LPs alone", () => {
+      const { rent } = payRent({
+        space: 3,
+        group: GROUP_SPACES.brown,
+        owned: [1, 3],
+        liquidityPools: 1,
+        marketEffects: [bullRun(1)],
+      });
+      assert.equal(rent, BOARD.find((s) => s.index === 3)!.lpRents[0]);
+    });
+
+    it("an effect past its last round no longer applies", () => {
+      const { rent } = payRent({
+        space: 3,
+        group: GROUP_SPACES.brown,
+        owned: [3],
+        roundNumber: 2,
+        marketEffects: [bullRun(1)],
+      });
+      assert.equal(rent, baseRent(3));
+    });
+
+    it("effects stack in order and only hit their target group", () => {
+      const effects: MarketEffectFixture[] = [
+        { kind: EFFECT_KIND.BearMarket, target: "rent", modifier: { multiplyBps: 5_000 }, endsRound: 3 },
+        { kind: EFFECT_KIND.GasSpike, target: { groupRent: GROUP_BROWN }, modifier: { delta: BPOLY(10) }, endsRound: 3 },
+      ];
+      const brown = payRent({ space: 3, group: GROUP_SPACES.brown, owned: [3], marketEffects: effects });
+      assert.equal(brown.rent, baseRent(3) / 2n + BPOLY(10));
+
+      const lightBlue = payRent({ space: 6, group: GROUP_SPACES.lightBlue, owned: [6], marketEffects: effects });
+      assert.equal(lightBlue.rent, baseRent(6) / 2n);
+    });
+  });
+
+  describe("card modifiers", () => {
+    const bridges = [5, 15, 25, 35];
+
+    it("Advance to Nearest Bridge doubles the bridge rent", () => {
+      const { rent, logs } = payRent({
+        space: 5,
+        group: bridges,
+        owned: [5, 15],
+        cardModifier: CARD_MODIFIER.DoubleBridgeRent,
+      });
+      assert.isNull(logs, logs ?? "");
+      assert.equal(rent, BPOLY(100));
+    });
+
+    it("the bridge modifier leaves other rent alone", () => {
+      const { rent } = payRent({
+        space: 3,
+        group: GROUP_SPACES.brown,
+        owned: [3],
+        cardModifier: CARD_MODIFIER.DoubleBridgeRent,
+      });
+      assert.equal(rent, baseRent(3));
+    });
+  });
+
+  describe("custom boards", () => {
+    it("charges the rent written to the game's BoardConfig", () => {
+      const pyth = BOARD.find((s) => s.index === 6)!;
+      const { rent, logs } = payRent({
+        space: 6,
+        group: GROUP_SPACES.lightBlue,
+        owned: [6],
+        spaces: [{ ...pyth, name: "Arbitrum", baseRent: BPOLY(9) }],
+      });
+      assert.isNull(logs, logs ?? "");
+      assert.equal(rent, BPOLY(9));
+    });
+
+    it("derives groups from the board: a third Brown space breaks the BONK/dogwifhat set", () => {
+      const { rent, logs } = payRent({
+        space: 3,
+        group: [1, 3, 6],
+        owned: [1, 3],
+        spaces: [{ ...BOARD.find((s) => s.index === 6)!, group: GROUP_BROWN }],
+      });
+      assert.isNull(logs, logs ?? "");
+      assert.equal(rent, baseRent(3));
+    });
+  });
+});
+
+describeSvm("Blockpoly — resolve_landing routing on LiteSVM (requires anchor build)", () => {
+  /**
+   * Lands player 0 on `space` (optionally owned by `owner`) and resolves the
+   * landing. Returns the resulting turn phase and current player index.
+   */
+  function land(opts: {
+    space: number;
+    owner?: "self" | "opponent";
+    mortgaged?: boolean;
+    omitProperty?: boolean;
+    /** Pass a different finalized board than the one the game was created on */
+    otherBoard?: boolean;
+  }): { phase: number; currentPlayer: number; logs: string | null } {
+    const gid = gameId(`landing-${opts.space}-${opts.owner ?? "bank"}-${!!opts.mortgaged}`);
+    const env = new BlockpolySvm(gid);
+    const player = env.wallet();
+    const opponent = env.wallet();
+
+    const gameState = env.game({
+      players: [player.publicKey, opponent.publicKey],
+      turnPhase: TURN_PHASE.LandingEffect,
+      pendingDice: [2, 4],
+    });
+    const playerState = env.player({
+      wallet: player.publicKey,
+      playerIndex: 0,
+      position: opts.space,
+      balance: BPOLY(1500),
+    });
+    if (opts.owner) {
+      env.property({
+        space: opts.space,
+        owner: opts.owner === "self" ? player.publicKey : opponent.publicKey,
+        isMortgaged: opts.mortgaged,
+      });
+    }
+    const [vault] = bankVaultPDA(gid);
+    const bankAta = env.tokenAccount(vault, BPOLY(1_000_000));
+    const playerAta = env.tokenAccount(player.publicKey, BPOLY(1500));
+
+    let board = env.board;
+    if (opts.otherBoard) {
+      const boardId = gameId("other-board");
+      board = env.createBoard(boardId);
+      assert.isNull(env.finalizeBoard(boardId));
+    }
+
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: player.publicKey, isSigner: true, isWritable: true },
+        { pubkey: gameState, isSigner: false, isWritable: true },
+        { pubkey: board, isSigner: false, isWritable: false },
+        { pubkey: playerState, isSigner: false, isWritable: true },
+        {
+          // Anchor encodes a None optional account as the program ID
+          pubkey: opts.omitProperty ? PROGRAM_ID : propertyStatePDA(gid, opts.space)[0],
+          isSigner: false,
+          isWritable: false,
+        },
+        { pubkey: vault, isSigner: false, isWritable: false },
+        { pubkey: bankAta, isSigner: false, isWritable: true },
+        { pubkey: playerAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([discriminator("global", "resolve_landing"), gid]),
+    });
+
+    const logs = env.send(ix, [player]);
+    // GameState layout: disc(8) game_id(32) host(32) status(1) turn_phase(1) current_player_index(1)
+    const data = Buffer.from(env.svm.getAccount(gameState)!.data);
+    return { phase: data[73], currentPlayer: data[74], logs };
+  }
+
+  it("bank-owned property → BuyDecision", () => {
+    const { phase, logs } = land({ space: 6 });
+    assert.isNull(logs, logs ?? "");
+    assert.equal(phase, TURN_PHASE.BuyDecision);
+  });
+
+  it("opponent's property → PayRent", () => {
+    const { phase, logs } = land({ space: 6, owner: "opponent" });
+    assert.isNull(logs, logs ?? "");
+    assert.equal(phase, TURN_PHASE.PayRent);
+  });
+
+  it("opponent's mortgaged property → next player's turn", () => {
+    const { phase, currentPlayer } = land({ space: 6, owner: "opponent", mortgaged: true });
+    assert.equal(phase, TURN_PHASE.RollDice);
+    assert.equal(currentPlayer, 1);
+  });
+
+  it("own property → next player's turn", () => {
+    const { phase, currentPlayer } = land({ space: 5, owner: "self" });
+    assert.equal(phase, TURN_PHASE.RollDice);
+    assert.equal(currentPlayer, 1);
+  });
+
+  it("rejects an ownable space without its PropertyState account", () => {
+    const { logs } = land({ space: 6, owner: "opponent", omitProperty: true });
+    assert.include(logs ?? "", "InvalidPropertyAccount");
+  });
+
+  it("rejects a board other than the game's", () => {
+    const { logs } = land({ space: 6, otherBoard: true });
+    assert.include(logs ?? "", "InvalidBoardConfig");
+  });
+});
+
+describeSvm("Blockpoly — even building on LiteSVM (requires anchor build)", () => {
+  const LP_COST = BOARD.find((b) => b.index === 6)!.lpCost;
+
+  /**
+   * `owner` holds Light Blue with `levels` LPs on spaces 6, 8, 9 (5 = Full Protocol);
+   * with `otherBrown`, `other` holds Brown (1, 3) unimproved
+   */
+  function setup(
+    label: string,
+    levels: number[],
+    opts: { mortgaged?: number; lpsAvailable?: number; otherBrown?: boolean; spaces?: SpaceData[] } = {}
+  ) {
+    const env = new BlockpolySvm(gameId(`build-${label}`));
+    const owner = env.wallet();
+    const other = env.wallet();
+
+    let board = env.board;
+    if (opts.spaces) {
+      const boardId = gameId(`build-board-${label}`);
+      board = env.createBoard(boardId);
+      for (const space of opts.spaces) {
+        assert.isNull(env.setBoardSpaces(boardId, space.index, [space]));
+      }
+      assert.isNull(env.finalizeBoard(boardId));
+    }
+
+    const gameState = env.game({
+      players: [owner.publicKey, other.publicKey],
+      turnPhase: TURN_PHASE.RollDice,
+      lpsAvailable: opts.lpsAvailable,
+      boardConfig: board,
+    });
+    const ownerState = env.player({
+      wallet: owner.publicKey,
+      playerIndex: 0,
+      position: 0,
+      properties: GROUP_SPACES.lightBlue,
+      balance: BPOLY(1500),
+    });
+    const otherState = env.player({
+      wallet: other.publicKey,
+      playerIndex: 1,
+      position: 0,
+      properties: opts.otherBrown ? GROUP_SPACES.brown : [],
+      balance: BPOLY(1500),
+    });
+    if (opts.otherBrown) {
+      GROUP_SPACES.brown.forEach((space) => env.property({ space, owner: other.publicKey }));
+    }
+    GROUP_SPACES.lightBlue.forEach((space, i) =>
+      env.property({
+        space,
+        owner: owner.publicKey,
+        liquidityPools: Math.min(levels[i], 4),
+        isFullProtocol: levels[i] === 5,
+        isMortgaged: opts.mortgaged === space,
+      })
+    );
+    const [vault] = bankVaultPDA(env.gid);
+    const bankAta = env.tokenAccount(vault, BPOLY(1_000_000));
+    const ownerAta = env.tokenAccount(owner.publicKey, BPOLY(1500));
+    const otherAta = env.tokenAccount(other.publicKey, BPOLY(1500));
+    return { env, board, owner, other, gameState, ownerState, otherState, vault, bankAta, ownerAta, otherAta };
+  }
+
+  function siblingsOf(s: ReturnType<typeof setup>, space: number): PublicKey[] {
+    return GROUP_SPACES.lightBlue.filter((g) => g !== space).map((g) => propertyStatePDA(s.env.gid, g)[0]);
+  }
+
+  function send(
+    s: ReturnType<typeof setup>,
+    name: "build_lp" | "build_protocol" | "sell_lp" | "sell_protocol",
+    space: number,
+    siblings = siblingsOf(s, space),
+    lpCount?: number
+  ) {
+    const selling = name === "sell_lp" || name === "sell_protocol";
+    const keys = [
+      { pubkey: s.owner.publicKey, isSigner: true, isWritable: true },
+      { pubkey: s.gameState, isSigner: false, isWritable: true },
+      { pubkey: s.board, isSigner: false, isWritable: false },
+      ...(selling ? [] : [{ pubkey: s.ownerState, isSigner: false, isWritable: false }]),
+      { pubkey: propertyStatePDA(s.env.gid, space)[0], isSigner: false, isWritable: true },
+      { pubkey: s.vault, isSigner: false, isWritable: false },
+      { pubkey: s.bankAta, isSigner: false, isWritable: true },
+      { pubkey: s.ownerAta, isSigner: false, isWritable: true },
+      { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      ...siblings.map((pubkey) => ({ pubkey, isSigner: false, isWritable: name === "sell_protocol" })),
+    ];
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys,
+      data: Buffer.concat([
+        discriminator("global", name),
+        s.env.gid,
+        Buffer.from(lpCount === undefined ? [space] : [space, lpCount]),
+      ]),
+    });
+    return s.env.send(ix, [s.owner]);
+  }
+
+  /**
+   * Bids for `kind` as the owner on Pyth (6), or as `other` on BONK (1); `outbid` is the
+   * ATA refunded the bid this one tops
+   */
+  function bid(
+    s: ReturnType<typeof setup>,
+    kind: number,
+    amount: bigint,
+    opts: { asOther?: boolean; outbid?: PublicKey; rival?: boolean } = {}
+  ) {
+    const [bidder, bidderState, bidderAta, space, group] = opts.asOther
+      ? [s.other, s.otherState, s.otherAta, 1, GROUP_SPACES.brown]
+      : [s.owner, s.ownerState, s.ownerAta, 6, GROUP_SPACES.lightBlue];
+    // The other player's property the building could go on, and its colour group
+    const [rivalSpace, rivalGroup] = opts.asOther ? [6, GROUP_SPACES.lightBlue] : [1, GROUP_SPACES.brown];
+    const rival = opts.rival ?? true;
+    const siblings = (space: number, group: number[]) =>
+      group
+        .filter((g) => g !== space)
+        .map((g) => ({ pubkey: propertyStatePDA(s.env.gid, g)[0], isSigner: false, isWritable: false }));
+    const amountBytes = Buffer.alloc(8);
+    amountBytes.writeBigUInt64LE(amount);
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: bidder.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.board, isSigner: false, isWritable: false },
+        { pubkey: bidderState, isSigner: false, isWritable: false },
+        { pubkey: propertyStatePDA(s.env.gid, space)[0], isSigner: false, isWritable: false },
+        { pubkey: s.vault, isSigner: false, isWritable: false },
+        { pubkey: s.bankAta, isSigner: false, isWritable: true },
+        { pubkey: bidderAta, isSigner: false, isWritable: true },
+        { pubkey: opts.outbid ?? PROGRAM_ID, isSigner: false, isWritable: !!opts.outbid }, // outbid_bpoly_ata
+        { pubkey: rival ? propertyStatePDA(s.env.gid, rivalSpace)[0] : PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        ...siblings(space, group),
+        ...(rival ? siblings(rivalSpace, rivalGroup) : []),
+      ],
+      data: Buffer.concat([discriminator("global", "bid_building"), s.env.gid, Buffer.from([kind]), amountBytes]),
+    });
+    return s.env.send(ix, [bidder]);
+  }
+
+  // PropertyState layout: disc(8) game(32) space_index(1) owner(32) liquidity_pools(1) is_full_protocol(1)
+  function levelOf(s: ReturnType<typeof setup>, space: number): number {
+    const data = s.env.svm.getAccount(propertyStatePDA(s.env.gid, space)[0])!.data;
+    return data[73] + data[74];
+  }
+
+  it("builds only on a property no sibling is behind", () => {
+    const s = setup("even", [1, 0, 1]);
+    assert.include(send(s, "build_lp", 6) ?? "", "UnevenBuilding");
+
+    const logs = send(s, "build_lp", 8);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(levelOf(s, 8), 1);
+    assert.equal(s.env.tokenBalance(s.ownerAta), BPOLY(1500) - LP_COST);
+    assert.isNull(send(s, "build_lp", 6));
+    assert.equal(levelOf(s, 6), 2);
+  });
+
+  it("needs every sibling at 4 LPs before a Full Protocol", () => {
+    const s = setup("protocol", [4, 3, 4]);
+    assert.include(send(s, "build_protocol", 6) ?? "", "UnevenBuilding");
+    assert.isNull(send(s, "build_lp", 8));
+    const logs = send(s, "build_protocol", 6);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(levelOf(s, 6), 5);
+  });
+
+  it("rejects building while a sibling is mortgaged", () => {
+    const s = setup("mortgaged", [0, 0, 0], { mortgaged: 9 });
+    assert.include(send(s, "build_lp", 6) ?? "", "PropertyMortgaged");
+  });
+
+  it("reads the whole group on-chain: missing, reordered or foreign siblings are rejected", () => {
+    const s = setup("accounts", [0, 0, 0]);
+    const siblings = siblingsOf(s, 6);
+    assert.include(send(s, "build_lp", 6, siblings.slice(1)) ?? "", "InvalidGroupAccounts");
+    assert.include(send(s, "build_lp", 6, [...siblings].reverse()) ?? "", "InvalidGroupAccounts");
+
+    s.env.property({ space: 9, owner: s.other.publicKey });
+    assert.include(send(s, "build_lp", 6) ?? "", "IncompleteColorSet");
+  });
+
+  it("sells only from a property no sibling is ahead of", () => {
+    const s = setup("sell", [4, 3, 3]);
+    assert.include(send(s, "sell_lp", 8) ?? "", "UnevenSelling");
+
+    const logs = send(s, "sell_lp", 6);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(levelOf(s, 6), 3);
+    assert.equal(s.env.tokenBalance(s.ownerAta), BPOLY(1500) + LP_COST / 2n);
+    assert.isNull(send(s, "sell_lp", 8));
+    assert.equal(levelOf(s, 8), 2);
+  });
+
+  it("sells a Full Protocol only through sell_protocol, back down to 4 LPs", () => {
+    const s = setup("sell-protocol", [5, 4, 4]);
+    assert.include(send(s, "sell_lp", 6) ?? "", "FullProtocolBuilt");
+    assert.include(send(s, "sell_protocol", 8, siblingsOf(s, 8), 4) ?? "", "NoFullProtocol");
+    assert.include(send(s, "sell_protocol", 6, siblingsOf(s, 6), 5) ?? "", "InvalidLpCount");
+
+    const logs = send(s, "sell_protocol", 6, siblingsOf(s, 6), 4);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(levelOf(s, 6), 4);
+    assert.equal(s.env.tokenBalance(s.ownerAta), BPOLY(1500) + LP_COST / 2n);
+  });
+
+  it("sell_protocol sells the whole group down evenly, refunding half the LP cost per level", () => {
+    const s = setup("liquidate", [5, 5, 4]);
+    const logs = send(s, "sell_protocol", 6, siblingsOf(s, 6), 0);
+    assert.isNull(logs, logs ?? "");
+    assert.deepEqual([6, 8, 9].map((space) => levelOf(s, space)), [0, 1, 1]);
+    // 5 + 4 + 3 levels sold
+    assert.equal(s.env.tokenBalance(s.ownerAta), BPOLY(1500) + (LP_COST / 2n) * 12n);
+  });
+
+  it("sell_protocol refunds each sibling at half its own LP cost", () => {
+    const pyth = BOARD.find((b) => b.index === 6)!;
+    const switchboard = { ...pyth, index: 8, name: "Switchboard", lpCost: BPOLY(80) };
+    const s = setup("liquidate-costs", [5, 5, 5], { spaces: [switchboard] });
+    const logs = send(s, "sell_protocol", 6, siblingsOf(s, 6), 0);
+    assert.isNull(logs, logs ?? "");
+    assert.deepEqual([6, 8, 9].map((space) => levelOf(s, space)), [0, 1, 1]);
+    // 5 + 4 levels off Pyth and Clockwork at the Light Blue LP cost, 4 off Switchboard at 80
+    assert.equal(s.env.tokenBalance(s.ownerAta), BPOLY(1500) + (LP_COST / 2n) * 9n + BPOLY(40) * 4n);
+  });
+
+  it("sell_lp needs a game in progress", () => {
+    const s = setup("sell-lp-finished", [1, 1, 1]);
+    const game = Buffer.from(s.env.svm.getAccount(s.gameState)!.data);
+    game[72] = 2; // GameStatus::Finished
+    s.env.setAccount(s.gameState, game);
+    assert.include(send(s, "sell_lp", 6) ?? "", "GameNotStarted");
+  });
+
+  it("sell_protocol needs a game in progress", () => {
+    const s = setup("sell-finished", [5, 5, 5]);
+    const game = Buffer.from(s.env.svm.getAccount(s.gameState)!.data);
+    game[72] = 2; // GameStatus::Finished
+    s.env.setAccount(s.gameState, game);
+    assert.include(send(s, "sell_protocol", 6, siblingsOf(s, 6), 4) ?? "", "GameNotStarted");
+  });
+
+  it("breaks a Full Protocol into only the LPs the bank has left", () => {
+    const s = setup("protocol-shortage", [5, 5, 5], { lpsAvailable: 2 });
+    assert.include(send(s, "sell_protocol", 6, siblingsOf(s, 6), 4) ?? "", "BuildingShortage");
+    // Siblings are kept within a level: 2 + 2 + 1 LPs needed, then 1 + 1 + 0
+    assert.include(send(s, "sell_protocol", 6, siblingsOf(s, 6), 1) ?? "", "BuildingShortage");
+    assert.isNull(send(s, "sell_protocol", 6, siblingsOf(s, 6), 0));
+    assert.deepEqual([6, 8, 9].map((space) => levelOf(s, space)), [0, 1, 1]);
+  });
+
+  it("builds from the bank's finite LP stock, and sold LPs go back to it", () => {
+    const s = setup("stock", [0, 0, 0], { lpsAvailable: 1 });
+    assert.isNull(send(s, "build_lp", 6));
+    assert.include(send(s, "build_lp", 8) ?? "", "BuildingShortage");
+
+    assert.isNull(send(s, "sell_lp", 6));
+    const logs = send(s, "build_lp", 8);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(levelOf(s, 8), 1);
+  });
+
+  it("auctions buildings only while the bank holds fewer than there are players", () => {
+    const opts = { otherBrown: true };
+    assert.include(bid(setup("no-shortage", [0, 0, 0], opts), BUILDING.LP, LP_COST) ?? "", "NoBuildingShortage");
+    assert.include(
+      bid(setup("sold-out", [0, 0, 0], { ...opts, lpsAvailable: 0 }), BUILDING.LP, LP_COST) ?? "",
+      "BuildingShortage"
+    );
+    assert.include(
+      bid(setup("bad-kind", [0, 0, 0], { ...opts, lpsAvailable: 1 }), 2, LP_COST) ?? "",
+      "InvalidBuildingKind"
+    );
+  });
+
+  it("only a player who could build that kind may bid", () => {
+    const s = setup("bid-eligible", [0, 0, 0], { lpsAvailable: 1, otherBrown: true });
+    assert.include(bid(s, BUILDING.Protocol, LP_COST) ?? "", "UnevenBuilding");
+    s.env.property({ space: 1, owner: s.owner.publicKey });
+    assert.include(bid(s, BUILDING.LP, LP_COST, { asOther: true }) ?? "", "NotPropertyOwner");
+
+    const mortgaged = setup("bid-mortgaged", [0, 0, 0], { lpsAvailable: 1, mortgaged: 9, otherBrown: true });
+    assert.include(bid(mortgaged, BUILDING.LP, LP_COST) ?? "", "PropertyMortgaged");
+  });
+
+  it("no auction opens when nobody else could build; the player just builds", () => {
+    const s = setup("uncontested", [0, 0, 0], { lpsAvailable: 1 });
+    assert.include(bid(s, BUILDING.LP, LP_COST, { rival: false }) ?? "", "UncontestedBuilding");
+
+    // Other's Brown set is mortgaged, so they couldn't build on it
+    GROUP_SPACES.brown.forEach((space) => s.env.property({ space, owner: s.other.publicKey, isMortgaged: space === 3 }));
+    assert.include(bid(s, BUILDING.LP, LP_COST) ?? "", "PropertyMortgaged");
+
+    const logs = send(s, "build_lp", 6);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.ownerAta), BPOLY(1500) - LP_COST);
+  });
+
+  it("the opening bid covers at least the building's cost", () => {
+    const s = setup("opening-bid", [0, 0, 0], { lpsAvailable: 1, otherBrown: true });
+    assert.include(bid(s, BUILDING.LP, LP_COST - 1n) ?? "", "BidTooLow");
+    assert.isNull(bid(s, BUILDING.LP, LP_COST));
+  });
+
+  it("a shortage auction blocks building until it closes; the winner's bid is escrowed up front", () => {
+    const s = setup("auction", [0, 0, 0], { lpsAvailable: 1, otherBrown: true });
+    const logs = bid(s, BUILDING.LP, BPOLY(60));
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.ownerAta), BPOLY(1440));
+    assert.include(bid(s, BUILDING.LP, BPOLY(60), { outbid: s.ownerAta }) ?? "", "BidTooLow");
+    assert.include(send(s, "build_lp", 6) ?? "", "BuildingAuctionActive");
+
+    // BUILDING_AUCTION_SLOTS after the bid
+    s.env.svm.warpToSlot(s.env.svm.getClock().slot + 151n);
+    assert.isNull(send(s, "build_lp", 6));
+    assert.equal(s.env.tokenBalance(s.ownerAta), BPOLY(1440) - LP_COST, "The bid is not charged twice");
+  });
+
+  it("an outbid player is refunded, and the winner alone may build once bidding closes", () => {
+    const s = setup("outbid", [0, 0, 0], { lpsAvailable: 1, otherBrown: true });
+    assert.isNull(bid(s, BUILDING.LP, BPOLY(60)));
+    assert.include(bid(s, BUILDING.LP, BPOLY(70), { asOther: true }) ?? "", "InvalidOutbidAccount");
+    assert.include(
+      bid(s, BUILDING.LP, BPOLY(70), { asOther: true, outbid: s.otherAta }) ?? "",
+      "InvalidOutbidAccount"
+    );
+
+    const logs = bid(s, BUILDING.LP, BPOLY(70), { asOther: true, outbid: s.ownerAta });
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.ownerAta), BPOLY(1500));
+    assert.equal(s.env.tokenBalance(s.otherAta), BPOLY(1430));
+    assert.equal(s.env.tokenBalance(s.bankAta), BPOLY(1_000_070));
+
+    s.env.svm.warpToSlot(s.env.svm.getClock().slot + 151n);
+    assert.include(send(s, "build_lp", 6) ?? "", "BuildingAuctionActive");
+  });
+
+  it("a winner who lets the claim lapse forfeits the escrowed bid", () => {
+    const s = setup("lapsed", [0, 0, 0], { lpsAvailable: 1, otherBrown: true });
+    assert.isNull(bid(s, BUILDING.LP, BPOLY(70), { asOther: true }));
+
+    // Past bidding and BUILDING_AUCTION_CLAIM_SLOTS: the LP goes to whoever builds first
+    s.env.svm.warpToSlot(s.env.svm.getClock().slot + 302n);
+    const logs = send(s, "build_lp", 6);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.ownerAta), BPOLY(1500) - LP_COST);
+    assert.equal(s.env.tokenBalance(s.otherAta), BPOLY(1430));
+  });
+});
+
+describeSvm("Blockpoly — board configs on LiteSVM (requires anchor build)", () => {
+  const BOARD_ID = gameId("seasonal-board");
+  const pyth = () => BOARD.find((s) => s.index === 6)!;
+
+  it("finalize rejects a property whose LP rents decrease", () => {
+    const env = new BlockpolySvm(gameId("board-rents"));
+    env.createBoard(BOARD_ID);
+    const bad = { ...pyth(), lpRents: [BPOLY(90), BPOLY(30), BPOLY(270), BPOLY(400)] as SpaceData["lpRents"] };
+    assert.isNull(env.setBoardSpaces(BOARD_ID, 6, [bad]));
+    assert.include(env.finalizeBoard(BOARD_ID) ?? "", "InvalidBoardSpace");
+  });
+
+  it("finalize rejects a board without Genesis Block at index 0", () => {
+    const env = new BlockpolySvm(gameId("board-genesis"));
+    env.createBoard(BOARD_ID);
+    assert.isNull(env.setBoardSpaces(BOARD_ID, 0, [{ ...pyth(), index: 0 }]));
+    assert.include(env.finalizeBoard(BOARD_ID) ?? "", "InvalidBoardSpace");
+  });
+
+  it("only the authority can edit, and only before finalizing", () => {
+    const env = new BlockpolySvm(gameId("board-locked"));
+    env.createBoard(BOARD_ID);
+    const stranger = env.wallet();
+    assert.include(env.setBoardSpaces(BOARD_ID, 6, [pyth()], stranger) ?? "", "NotBoardAuthority");
+
+    assert.isNull(env.finalizeBoard(BOARD_ID));
+    assert.include(env.setBoardSpaces(BOARD_ID, 6, [pyth()]) ?? "", "BoardConfigFinalized");
+    assert.include(env.finalizeBoard(BOARD_ID) ?? "", "BoardConfigFinalized");
+  });
+
+  it("rejects writes past the last space", () => {
+    const env = new BlockpolySvm(gameId("board-range"));
+    env.createBoard(BOARD_ID);
+    assert.include(env.setBoardSpaces(BOARD_ID, 39, [pyth(), pyth()]) ?? "", "InvalidSpaceIndex");
+  });
+});
+
+describeSvm("Blockpoly — card decks on LiteSVM (requires anchor build)", () => {
+  const DECK_ID = gameId("seasonal-deck");
+  const fee: CardFixture = { name: "Audit Fee", effect: { payBank: BPOLY(40) } };
+
+  it("finalize rejects an empty deck", () => {
+    const env = new BlockpolySvm(gameId("deck-empty"));
+    env.createDeck(DECK_ID);
+    assert.isNull(env.setDeckCards(DECK_ID, DECK.AlphaCall, 0, []));
+    assert.include(env.finalizeDeck(DECK_ID) ?? "", "InvalidCardDeck");
+  });
+
+  it("rejects cards that move off the board or nowhere", () => {
+    const env = new BlockpolySvm(gameId("deck-moves"));
+    env.createDeck(DECK_ID);
+    const offBoard: CardFixture = { name: "Lost", effect: { moveTo: { space: 40, modifier: CARD_MODIFIER.None } } };
+    const standStill: CardFixture = { name: "Stay", effect: { moveBack: 0 } };
+    assert.include(env.setDeckCards(DECK_ID, DECK.Governance, 0, [offBoard]) ?? "", "InvalidCardDeck");
+    assert.include(env.setDeckCards(DECK_ID, DECK.Governance, 0, [standStill]) ?? "", "InvalidCardDeck");
+  });
+
+  it("rejects writes that leave a gap in the deck", () => {
+    const env = new BlockpolySvm(gameId("deck-gap"));
+    env.createDeck(DECK_ID);
+    assert.include(env.setDeckCards(DECK_ID, DECK.AlphaCall, 17, [fee]) ?? "", "InvalidCardDeck");
+    assert.isNull(env.setDeckCards(DECK_ID, DECK.AlphaCall, 16, [fee]), "Appending is allowed");
+  });
+
+  it("only the authority can edit, and only before finalizing", () => {
+    const env = new BlockpolySvm(gameId("deck-locked"));
+    env.createDeck(DECK_ID);
+    const stranger = env.wallet();
+    assert.include(env.setDeckCards(DECK_ID, DECK.AlphaCall, 0, [fee], stranger) ?? "", "NotDeckAuthority");
+
+    assert.isNull(env.finalizeDeck(DECK_ID));
+    assert.include(env.setDeckCards(DECK_ID, DECK.AlphaCall, 0, [fee]) ?? "", "CardDeckFinalized");
+    assert.include(env.finalizeDeck(DECK_ID) ?? "", "CardDeckFinalized");
+  });
+});
+
+describeSvm("Blockpoly — debts and RaiseFunds on LiteSVM (requires anchor build)", () => {
+  // PlayerState layout: disc(8) game(32) wallet(32) nine 1-byte fields, then properties_owned
+  function readDebt(data: Buffer): { amount: bigint; creditor: PublicKey | null; reason: number } {
+    let o = 81;
+    o += 4 + data.readUInt32LE(o); // properties_owned
+    o += 1 + 8 + 4 + 1 + 8; // flash loan fields, is_bankrupt, bpoly_balance
+    const amount = data.readBigUInt64LE(o);
+    const hasCreditor = data[o + 8] === 1;
+    const creditor = hasCreditor ? new PublicKey(data.subarray(o + 9, o + 41)) : null;
+    const reason = data[o + (hasCreditor ? 41 : 9)];
+    return { amount, creditor, reason };
+  }
+
+  function gameBytes(env: BlockpolySvm, gameState: PublicKey) {
+    // GameState layout: disc(8) game_id(32) host(32) status(1) turn_phase(1) current_player_index(1)
+    const data = Buffer.from(env.svm.getAccount(gameState)!.data);
+    return { status: data[72], phase: data[73], currentPlayer: data[74] };
+  }
+
+  function setup(
+    label: string,
+    opts: {
+      turnPhase: number;
+      position: number;
+      balance: bigint;
+      /** A debt to the property owner is given as creditor "owner" */
+      debt?: { amount: bigint; creditor: PublicKey | null | "owner"; reason: number };
+      rules?: Partial<RuleSetFixture>;
+      marketEffects?: MarketEffectFixture[];
+      /** Spaces to rewrite on a custom board the game is played on */
+      spaces?: SpaceData[];
+      /** The debtor's properties, with the LPs on each */
+      holdings?: { space: number; liquidityPools?: number }[];
+    }
+  ) {
+    const env = new BlockpolySvm(gameId(`debt-${label}`));
+    const debtor = env.wallet();
+    const owner = env.wallet();
+
+    let board = env.board;
+    if (opts.spaces) {
+      const boardId = gameId(`debt-board-${label}`);
+      board = env.createBoard(boardId);
+      for (const space of opts.spaces) {
+        assert.isNull(env.setBoardSpaces(boardId, space.index, [space]));
+      }
+      assert.isNull(env.finalizeBoard(boardId));
+    }
+
+    const gameState = env.game({
+      players: [debtor.publicKey, owner.publicKey],
+      turnPhase: opts.turnPhase,
+      pendingDice: [1, 3],
+      rules: opts.rules,
+      marketEffects: opts.marketEffects,
+      boardConfig: board,
+    });
+    const debtorState = env.player({
+      wallet: debtor.publicKey,
+      playerIndex: 0,
+      position: opts.position,
+      balance: opts.balance,
+      debt: opts.debt && {
+        ...opts.debt,
+        creditor: opts.debt.creditor === "owner" ? owner.publicKey : opts.debt.creditor,
+      },
+      properties: (opts.holdings ?? []).map((h) => h.space),
+    });
+    for (const h of opts.holdings ?? []) {
+      env.property({ ...h, owner: debtor.publicKey });
+    }
+    const ownerState = env.player({
+      wallet: owner.publicKey,
+      playerIndex: 1,
+      position: 0,
+      properties: [6],
+      balance: BPOLY(1500),
+    });
+    env.property({ space: 6, owner: owner.publicKey });
+    const [vault] = bankVaultPDA(env.gid);
+    const bankAta = env.tokenAccount(vault, BPOLY(1_000_000));
+    const debtorAta = env.tokenAccount(debtor.publicKey, opts.balance);
+    const ownerAta = env.tokenAccount(owner.publicKey, 0n);
+    return { env, board, debtor, owner, gameState, debtorState, ownerState, vault, bankAta, debtorAta, ownerAta };
+  }
+
+  function settleDebt(s: ReturnType<typeof setup>, creditorAta: PublicKey): string | null {
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: s.debtor.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.debtorState, isSigner: false, isWritable: true },
+        { pubkey: s.debtorAta, isSigner: false, isWritable: true },
+        { pubkey: creditorAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([discriminator("global", "settle_debt"), s.env.gid]),
+    });
+    return s.env.send(ix, [s.debtor]);
+  }
+
+  function resolveLanding(s: ReturnType<typeof setup>): string | null {
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: s.debtor.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.board, isSigner: false, isWritable: false },
+        { pubkey: s.debtorState, isSigner: false, isWritable: true },
+        { pubkey: PROGRAM_ID, isSigner: false, isWritable: false }, // property_state: None
+        { pubkey: s.vault, isSigner: false, isWritable: false },
+        { pubkey: s.bankAta, isSigner: false, isWritable: true },
+        { pubkey: s.debtorAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([discriminator("global", "resolve_landing"), s.env.gid]),
+    });
+    return s.env.send(ix, [s.debtor]);
+  }
+
+  it("short on rent: records the debt instead of failing, then settle_debt pays the owner", () => {
+    const s = setup("rent", { turnPhase: TURN_PHASE.PayRent, position: 6, balance: BPOLY(1) });
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: s.debtor.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.env.board, isSigner: false, isWritable: false },
+        { pubkey: s.debtorState, isSigner: false, isWritable: true },
+        { pubkey: propertyStatePDA(s.env.gid, 6)[0], isSigner: false, isWritable: false },
+        { pubkey: s.ownerState, isSigner: false, isWritable: false },
+        { pubkey: s.debtorAta, isSigner: false, isWritable: true },
+        { pubkey: s.ownerAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        ...GROUP_SPACES.lightBlue.map((space) => ({
+          pubkey: propertyStatePDA(s.env.gid, space)[0],
+          isSigner: false,
+          isWritable: false,
+        })),
+      ],
+      data: Buffer.concat([discriminator("global", "pay_rent"), s.env.gid]),
+    });
+    const logs = s.env.send(ix, [s.debtor]);
+    assert.isNull(logs, logs ?? "");
+
+    const rent = BOARD.find((b) => b.index === 6)!.baseRent;
+    assert.equal(gameBytes(s.env, s.gameState).phase, TURN_PHASE.RaiseFunds);
+    assert.equal(s.env.tokenBalance(s.ownerAta), 0n);
+    const debt = readDebt(Buffer.from(s.env.svm.getAccount(s.debtorState)!.data));
+    assert.equal(debt.amount, rent);
+    assert.isTrue(debt.creditor!.equals(s.owner.publicKey));
+    assert.equal(debt.reason, DEBT_REASON.Rent);
+
+    // Still short: settle_debt refuses
+    assert.include(settleDebt(s, s.ownerAta) ?? "", "InsufficientBalance");
+
+    // Funds raised (e.g. by mortgaging): the debt clears and play passes on
+    s.env.tokenAccount(s.debtor.publicKey, BPOLY(100));
+    const settleLogs = settleDebt(s, s.ownerAta);
+    assert.isNull(settleLogs, settleLogs ?? "");
+    assert.equal(s.env.tokenBalance(s.ownerAta), rent);
+    assert.equal(readDebt(Buffer.from(s.env.svm.getAccount(s.debtorState)!.data)).amount, 0n);
+    const game = gameBytes(s.env, s.gameState);
+    assert.equal(game.phase, TURN_PHASE.RollDice);
+    assert.equal(game.currentPlayer, 1);
+  });
+
+  it("short on tax: the bank is the creditor and only its ATA settles", () => {
+    const s = setup("tax", { turnPhase: TURN_PHASE.LandingEffect, position: 4, balance: BPOLY(10) });
+    const logs = resolveLanding(s);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(gameBytes(s.env, s.gameState).phase, TURN_PHASE.RaiseFunds);
+    const debt = readDebt(Buffer.from(s.env.svm.getAccount(s.debtorState)!.data));
+    assert.equal(debt.amount, BPOLY(200));
+    assert.isNull(debt.creditor);
+    assert.equal(debt.reason, DEBT_REASON.Tax);
+
+    s.env.tokenAccount(s.debtor.publicKey, BPOLY(500));
+    assert.include(settleDebt(s, s.ownerAta) ?? "", "InvalidCreditorAccount");
+    assert.isNull(settleDebt(s, s.bankAta));
+    assert.equal(gameBytes(s.env, s.gameState).currentPlayer, 1);
+  });
+
+  it("a house-rule tax the player can cover is paid, not owed", () => {
+    const s = setup("tax-rule", {
+      turnPhase: TURN_PHASE.LandingEffect,
+      position: 4,
+      balance: BPOLY(10),
+      rules: { gasFeeTax: BPOLY(5) },
+    });
+    const logs = resolveLanding(s);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.debtorAta), BPOLY(5));
+    assert.equal(gameBytes(s.env, s.gameState).currentPlayer, 1);
+  });
+
+  it("charges the tax the game's BoardConfig gives the space", () => {
+    const gasFees = BOARD.find((b) => b.index === 4)!;
+    const s = setup("tax-board", {
+      turnPhase: TURN_PHASE.LandingEffect,
+      position: 7,
+      balance: BPOLY(500),
+      spaces: [
+        { ...gasFees, baseRent: BPOLY(75) },
+        { ...gasFees, index: 7, name: "Priority Fee", baseRent: BPOLY(30) },
+      ],
+    });
+    const logs = resolveLanding(s);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.debtorAta), BPOLY(470), "a tax space moved off 4 and 38 still charges");
+    assert.equal(gameBytes(s.env, s.gameState).currentPlayer, 1);
+  });
+
+  it("a house-rule tax overrides the board's amount", () => {
+    const gasFees = BOARD.find((b) => b.index === 4)!;
+    const s = setup("tax-board-rule", {
+      turnPhase: TURN_PHASE.LandingEffect,
+      position: 4,
+      balance: BPOLY(500),
+      rules: { gasFeeTax: BPOLY(5) },
+      spaces: [{ ...gasFees, baseRent: BPOLY(75) }],
+    });
+    const logs = resolveLanding(s);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.debtorAta), BPOLY(495));
+  });
+
+  it("a Gas Spike effect raises the tax charged", () => {
+    const s = setup("tax-spike", {
+      turnPhase: TURN_PHASE.LandingEffect,
+      position: 4,
+      balance: BPOLY(500),
+      marketEffects: [
+        { kind: EFFECT_KIND.GasSpike, target: "tax", modifier: { multiplyBps: 15_000 }, endsRound: 0 },
+      ],
+    });
+    const logs = resolveLanding(s);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.debtorAta), BPOLY(200));
+  });
+
+  function declareBankruptcy(s: ReturnType<typeof setup>, creditor: "owner" | "bank"): string | null {
+    const [creditorState, creditorAta] = creditor === "owner" ? [s.ownerState, s.ownerAta] : [PROGRAM_ID, s.bankAta];
+    const holdings = propertiesOf(s, s.debtorState).map((space) => ({
+      pubkey: propertyStatePDA(s.env.gid, space)[0],
+      isSigner: false,
+      isWritable: true,
+    }));
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: s.debtor.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.debtorState, isSigner: false, isWritable: true },
+        { pubkey: s.debtorAta, isSigner: false, isWritable: true },
+        { pubkey: creditorState, isSigner: false, isWritable: creditor === "owner" },
+        { pubkey: creditorAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        ...holdings,
+      ],
+      data: Buffer.concat([discriminator("global", "declare_bankruptcy"), s.env.gid]),
+    });
+    return s.env.send(ix, [s.debtor]);
+  }
+
+  // PlayerState layout: disc(8) game(32) wallet(32) nine 1-byte fields, then properties_owned
+  function propertiesOf(s: ReturnType<typeof setup>, playerState: PublicKey): number[] {
+    const data = Buffer.from(s.env.svm.getAccount(playerState)!.data);
+    return [...data.subarray(85, 85 + data.readUInt32LE(81))];
+  }
+
+  it("declare_bankruptcy clears the debt and ends a two-player game", () => {
+    const s = setup("bankrupt", {
+      turnPhase: TURN_PHASE.RaiseFunds,
+      position: 6,
+      balance: 0n,
+      debt: { amount: BPOLY(50), creditor: null, reason: DEBT_REASON.Card },
+    });
+    const logs = declareBankruptcy(s, "bank");
+    assert.isNull(logs, logs ?? "");
+    assert.equal(readDebt(Buffer.from(s.env.svm.getAccount(s.debtorState)!.data)).amount, 0n);
+    assert.equal(gameBytes(s.env, s.gameState).status, GAME_STATUS_FINISHED);
+  });
+
+  it("declare_bankruptcy hands the BPOLY and properties left to the player owed", () => {
+    const s = setup("bankrupt-to-player", {
+      turnPhase: TURN_PHASE.RaiseFunds,
+      position: 6,
+      balance: BPOLY(20),
+      debt: { amount: BPOLY(50), creditor: "owner", reason: DEBT_REASON.Rent },
+      holdings: [{ space: 1, liquidityPools: 2 }, { space: 3 }],
+    });
+    assert.include(declareBankruptcy(s, "bank") ?? "", "InvalidCreditorAccount");
+
+    const logs = declareBankruptcy(s, "owner");
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.ownerAta), BPOLY(20));
+    assert.equal(s.env.tokenBalance(s.debtorAta), 0n);
+    assert.deepEqual(propertiesOf(s, s.ownerState), [6, 1, 3]);
+    assert.deepEqual(propertiesOf(s, s.debtorState), []);
+    for (const space of [1, 3]) {
+      const data = Buffer.from(s.env.svm.getAccount(propertyStatePDA(s.env.gid, space)[0])!.data);
+      assert.isTrue(new PublicKey(data.subarray(41, 73)).equals(s.owner.publicKey));
+      assert.equal(data[73], 0, "buildings go back to the bank's stock");
+    }
+  });
+
+  it("declare_bankruptcy returns the estate of a player owing the bank for sale", () => {
+    const s = setup("bankrupt-to-bank", {
+      turnPhase: TURN_PHASE.RaiseFunds,
+      position: 6,
+      balance: BPOLY(20),
+      debt: { amount: BPOLY(50), creditor: null, reason: DEBT_REASON.Card },
+      holdings: [{ space: 1 }],
+    });
+    const logs = declareBankruptcy(s, "bank");
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.bankAta), BPOLY(1_000_020));
+    const property = s.env.svm.getAccount(propertyStatePDA(s.env.gid, 1)[0]);
+    assert.isTrue(!property || property.lamports === 0, "the bank can sell it again");
+  });
+});
+
+describeSvm("Blockpoly — flash loans on LiteSVM (requires anchor build)", () => {
+  const LOAN = { repay: BPOLY(210), dueTurn: 5 };
+
+  // PlayerState layout: disc(8) game(32) wallet(32) nine 1-byte fields, then properties_owned
+  function readLoan(data: Buffer) {
+    let o = 81;
+    o += 4 + data.readUInt32LE(o); // properties_owned
+    const loan = { active: data[o] === 1, repay: data.readBigUInt64LE(o + 1), dueTurn: data.readUInt32LE(o + 9) };
+    o += 1 + 8 + 4 + 1 + 8; // flash loan fields, is_bankrupt, bpoly_balance
+    const hasCreditor = data[o + 8] === 1;
+    return { ...loan, debt: data.readBigUInt64LE(o), debtReason: data[o + (hasCreditor ? 41 : 9)] };
+  }
+
+  function setup(label: string, opts: { turnNumber: number; balance: bigint; currentPlayerIndex?: number }) {
+    const env = new BlockpolySvm(gameId(`flash-${label}`));
+    const borrower = env.wallet();
+    const other = env.wallet();
+    const gameState = env.game({
+      players: [borrower.publicKey, other.publicKey],
+      turnPhase: TURN_PHASE.RollDice,
+      currentPlayerIndex: opts.currentPlayerIndex ?? 0,
+      turnNumber: opts.turnNumber,
+      diceMode: DICE_MODE.CommitReveal,
+    });
+    const borrowerState = env.player({
+      wallet: borrower.publicKey,
+      playerIndex: 0,
+      position: 0,
+      balance: opts.balance,
+      flashLoan: LOAN,
+    });
+    env.player({ wallet: other.publicKey, playerIndex: 1, position: 0, balance: BPOLY(1500) });
+    const [vault] = bankVaultPDA(env.gid);
+    const bankAta = env.tokenAccount(vault, BPOLY(1_000_000));
+    const borrowerAta = env.tokenAccount(borrower.publicKey, opts.balance);
+    return { env, borrower, gameState, borrowerState, bankAta, borrowerAta, turnNumber: opts.turnNumber };
+  }
+
+  function repay(s: ReturnType<typeof setup>): string | null {
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: s.borrower.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.borrowerState, isSigner: false, isWritable: true },
+        { pubkey: s.bankAta, isSigner: false, isWritable: true },
+        { pubkey: s.borrowerAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([discriminator("global", "repay_flash_loan"), s.env.gid]),
+    });
+    return s.env.send(ix, [s.borrower]);
+  }
+
+  function commitDice(s: ReturnType<typeof setup>): string | null {
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: s.borrower.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.borrowerState, isSigner: false, isWritable: true },
+        { pubkey: diceCommitmentPDA(s.env.gid, s.turnNumber)[0], isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([discriminator("global", "commit_dice"), s.env.gid, Buffer.alloc(32, 7)]),
+    });
+    return s.env.send(ix, [s.borrower]);
+  }
+
+  function phaseOf(s: ReturnType<typeof setup>): number {
+    return s.env.svm.getAccount(s.gameState)!.data[73];
+  }
+
+  it("can be repaid before it falls due, even off-turn", () => {
+    const s = setup("early", { turnNumber: 4, balance: BPOLY(500), currentPlayerIndex: 1 });
+    const logs = repay(s);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.borrowerAta), BPOLY(290));
+    assert.isFalse(readLoan(Buffer.from(s.env.svm.getAccount(s.borrowerState)!.data)).active);
+  });
+
+  it("an early repayment the borrower can't cover fails rather than defaulting", () => {
+    const s = setup("early-short", { turnNumber: 4, balance: BPOLY(100) });
+    assert.include(repay(s) ?? "", "InsufficientBalance");
+    const loan = readLoan(Buffer.from(s.env.svm.getAccount(s.borrowerState)!.data));
+    assert.isTrue(loan.active);
+    assert.equal(loan.debt, 0n);
+  });
+
+  it("repaid once due, the borrower rolls without a penalty", () => {
+    const s = setup("due", { turnNumber: 5, balance: BPOLY(500) });
+    const logs = repay(s);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.borrowerAta), BPOLY(290));
+    const loan = readLoan(Buffer.from(s.env.svm.getAccount(s.borrowerState)!.data));
+    assert.isFalse(loan.active);
+    assert.equal(loan.repay, 0n);
+    assert.equal(loan.debt, 0n);
+
+    const commitLogs = commitDice(s);
+    assert.isNull(commitLogs, commitLogs ?? "");
+    assert.equal(phaseOf(s), TURN_PHASE.AwaitingVRF);
+    assert.include(repay(s) ?? "", "NoFlashLoan");
+  });
+
+  it("rolling with the loan due defaults on it: the penalty is owed before the roll", () => {
+    const s = setup("roll-due", { turnNumber: 5, balance: BPOLY(500) });
+    const logs = commitDice(s);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(phaseOf(s), TURN_PHASE.RaiseFunds, "The roll is not opened");
+    const loan = readLoan(Buffer.from(s.env.svm.getAccount(s.borrowerState)!.data));
+    assert.isFalse(loan.active);
+    assert.equal(loan.debt, BPOLY(260));
+    assert.equal(loan.debtReason, DEBT_REASON.FlashLoan);
+    assert.equal(s.env.tokenBalance(s.borrowerAta), BPOLY(500));
+    assert.include(repay(s) ?? "", "NoFlashLoan");
+  });
+
+  it("a loan the borrower can't cover becomes a debt with the penalty, settled before the roll", () => {
+    const s = setup("default", { turnNumber: 5, balance: BPOLY(100) });
+    const logs = repay(s);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.borrowerAta), BPOLY(100));
+    assert.equal(phaseOf(s), TURN_PHASE.RaiseFunds);
+    const loan = readLoan(Buffer.from(s.env.svm.getAccount(s.borrowerState)!.data));
+    assert.isFalse(loan.active);
+    assert.equal(loan.debt, BPOLY(260));
+    assert.equal(loan.debtReason, DEBT_REASON.FlashLoan);
+
+    s.env.tokenAccount(s.borrower.publicKey, BPOLY(300));
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: s.borrower.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.borrowerState, isSigner: false, isWritable: true },
+        { pubkey: s.borrowerAta, isSigner: false, isWritable: true },
+        { pubkey: s.bankAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([discriminator("global", "settle_debt"), s.env.gid]),
+    });
+    const settleLogs = s.env.send(ix, [s.borrower]);
+    assert.isNull(settleLogs, settleLogs ?? "");
+    assert.equal(s.env.tokenBalance(s.borrowerAta), BPOLY(40));
+    // Still the borrower's turn, now free to roll
+    assert.equal(phaseOf(s), TURN_PHASE.RollDice);
+    assert.equal(s.env.svm.getAccount(s.gameState)!.data[74], 0);
+  });
+});
+
+describeSvm("Blockpoly — market events on LiteSVM (requires anchor build)", () => {
+  // market_events.rs MARKET_EVENTS
+  const MARKET_EVENT = { NetworkCongestion: 0, TreasuryAirdrop: 1, BearMarket: 4 };
+
+  function gameBytes(env: BlockpolySvm, gameState: PublicKey) {
+    // GameState layout: disc(8) game_id(32) host(32) status(1) turn_phase(1) current_player_index(1)
+    const data = Buffer.from(env.svm.getAccount(gameState)!.data);
+    return { phase: data[73], currentPlayer: data[74], data };
+  }
+
+  /** Number of active market effects, walking the fixture's GameState layout */
+  function marketEffectCount(data: Buffer): number {
+    return data.readUInt32LE(readGameDecks(data).end);
+  }
+
+  /**
+   * Two players in round `round`. Player 0 is up with a roll to make; player 1
+   * sits on DeFi Summer ready to end the round when `lastTurn` is set.
+   */
+  function setup(
+    label: string,
+    opts: {
+      marketEvents?: boolean;
+      entropy?: bigint;
+      event?: number;
+      balance?: bigint;
+      lastTurn?: boolean;
+    }
+  ) {
+    const env = new BlockpolySvm(gameId(`market-${label}`));
+    const first = env.wallet();
+    const last = env.wallet();
+    const round = opts.lastTurn ? 0 : 1;
+    const gameState = env.game({
+      players: [first.publicKey, last.publicKey],
+      turnPhase: opts.lastTurn ? TURN_PHASE.LandingEffect : TURN_PHASE.RollDice,
+      currentPlayerIndex: opts.lastTurn ? 1 : 0,
+      pendingDice: opts.lastTurn ? [6, 4] : null,
+      roundNumber: round,
+      rules: { marketEvents: opts.marketEvents ?? true },
+      lastRollEntropy: opts.entropy,
+      marketEvent: opts.event === undefined ? undefined : { id: opts.event, round },
+    });
+    const balance = opts.balance ?? BPOLY(1500);
+    const firstState = env.player({ wallet: first.publicKey, playerIndex: 0, position: 0, balance });
+    const lastState = env.player({ wallet: last.publicKey, playerIndex: 1, position: 20, balance });
+    const [vault] = bankVaultPDA(env.gid);
+    const bankAta = env.tokenAccount(vault, BPOLY(1_000_000));
+    const firstAta = env.tokenAccount(first.publicKey, balance);
+    const lastAta = env.tokenAccount(last.publicKey, balance);
+    return { env, first, last, gameState, firstState, lastState, vault, bankAta, firstAta, lastAta };
+  }
+
+  function apply(s: ReturnType<typeof setup>, player: Keypair, playerState: PublicKey, playerAta: PublicKey) {
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: player.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: playerState, isSigner: false, isWritable: true },
+        { pubkey: s.vault, isSigner: false, isWritable: false },
+        { pubkey: s.bankAta, isSigner: false, isWritable: true },
+        { pubkey: playerAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([discriminator("global", "apply_market_event"), s.env.gid]),
+    });
+    return s.env.send(ix, [player]);
+  }
+
+  /** Player 1 resolves their landing on DeFi Summer, ending the round */
+  function endRound(s: ReturnType<typeof setup>) {
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: s.last.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.env.board, isSigner: false, isWritable: false },
+        { pubkey: s.lastState, isSigner: false, isWritable: true },
+        { pubkey: PROGRAM_ID, isSigner: false, isWritable: false }, // property_state: None
+        { pubkey: s.vault, isSigner: false, isWritable: false },
+        { pubkey: s.bankAta, isSigner: false, isWritable: true },
+        { pubkey: s.lastAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([discriminator("global", "resolve_landing"), s.env.gid]),
+    });
+    return s.env.send(ix, [s.last]);
+  }
+
+  it("a new round draws an event from the last roll's randomness; each player applies it once", () => {
+    const s = setup("draw", { lastTurn: true, entropy: BigInt(MARKET_EVENT.TreasuryAirdrop) });
+    const logs = endRound(s);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(gameBytes(s.env, s.gameState).currentPlayer, 0);
+
+    const applyLogs = apply(s, s.first, s.firstState, s.firstAta);
+    assert.isNull(applyLogs, applyLogs ?? "");
+    assert.equal(s.env.tokenBalance(s.firstAta), BPOLY(1550));
+    assert.include(apply(s, s.first, s.firstState, s.firstAta) ?? "", "NoMarketEvent");
+  });
+
+  it("games without the house rule draw nothing", () => {
+    const s = setup("opt-out", { lastTurn: true, marketEvents: false });
+    assert.isNull(endRound(s));
+    assert.include(apply(s, s.first, s.firstState, s.firstAta) ?? "", "NoMarketEvent");
+  });
+
+  it("only the current player can apply the event", () => {
+    const s = setup("turn", { event: MARKET_EVENT.TreasuryAirdrop });
+    assert.include(apply(s, s.last, s.lastState, s.lastAta) ?? "", "NotYourTurn");
+  });
+
+  it("a network fee the player can't cover becomes a debt that resumes at RollDice", () => {
+    const s = setup("fee", { event: MARKET_EVENT.NetworkCongestion, balance: BPOLY(5) });
+    const logs = apply(s, s.first, s.firstState, s.firstAta);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(gameBytes(s.env, s.gameState).phase, TURN_PHASE.RaiseFunds);
+
+    s.env.tokenAccount(s.first.publicKey, BPOLY(100));
+    const settle = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: s.first.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.firstState, isSigner: false, isWritable: true },
+        { pubkey: s.firstAta, isSigner: false, isWritable: true },
+        { pubkey: s.bankAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([discriminator("global", "settle_debt"), s.env.gid]),
+    });
+    const settleLogs = s.env.send(settle, [s.first]);
+    assert.isNull(settleLogs, settleLogs ?? "");
+    assert.equal(s.env.tokenBalance(s.firstAta), BPOLY(80));
+    const game = gameBytes(s.env, s.gameState);
+    assert.equal(game.phase, TURN_PHASE.RollDice);
+    assert.equal(game.currentPlayer, 0, "The fee doesn't cost the player their roll");
+  });
+
+  it("a global event starts its market effect when first applied", () => {
+    const s = setup("global", { event: MARKET_EVENT.BearMarket });
+    assert.isNull(apply(s, s.first, s.firstState, s.firstAta));
+    assert.equal(marketEffectCount(gameBytes(s.env, s.gameState).data), 1);
+  });
+});
+
+describeSvm("Blockpoly — resolve_card on LiteSVM (requires anchor build)", () => {
+  // resolve_card card ids per deck_type
+  const ALPHA_CALL = {
+    AdvanceToSolana: 1,
+    AdvanceToNearestBridge: 2,
+    MevBotAttack: 6,
+    MarketCrash: 7,
+    ProtocolHack: 11,
+    GoBack3: 12,
+    WhaleDump: 14,
+    FlashLoan: 10,
+    AirdropSeason: 15,
+  };
+  const GOVERNANCE = {
+    TreasuryRelease: 0,
+    DaoAirdrop: 2,
+    GetOutOfRugPullFree: 3,
+    InfrastructureLevy: 6,
+    TokenUnlockCliff: 9,
+    BridgeExploited: 10,
+    DaoBirthday: 11,
+    NftRoyaltyIncome: 14,
+  };
+
+  // PlayerState layout: disc(8) game(32) wallet(32) nine 1-byte fields, then properties_owned
+  function readDebt(data: Buffer): { amount: bigint; creditor: PublicKey | null; reason: number } {
+    let o = 81;
+    o += 4 + data.readUInt32LE(o); // properties_owned
+    o += 1 + 8 + 4 + 1 + 8; // flash loan fields, is_bankrupt, bpoly_balance
+    const amount = data.readBigUInt64LE(o);
+    const hasCreditor = data[o + 8] === 1;
+    const creditor = hasCreditor ? new PublicKey(data.subarray(o + 9, o + 41)) : null;
+    const reason = data[o + (hasCreditor ? 41 : 9)];
+    return { amount, creditor, reason };
+  }
+
+  function gameBytes(env: BlockpolySvm, gameState: PublicKey) {
+    // GameState layout: disc(8) game_id(32) host(32) status(1) turn_phase(1) current_player_index(1)
+    const data = Buffer.from(env.svm.getAccount(gameState)!.data);
+    return { phase: data[73], currentPlayer: data[74] };
+  }
+
+  /** Three players; player 0 owns `holdings` and has drawn a card on Alpha Call (space 2) */
+  function setup(
+    label: string,
+    opts: {
+      balance?: bigint;
+      secondBalance?: bigint;
+      /** Whether the second opponent's ATA still has the bank vault as delegate */
+      secondApprovesBank?: boolean;
+      secondDebt?: PlayerFixture["debt"];
+      holdings?: Omit<PropertyFixture, "gid" | "owner">[];
+      turnPhase?: number;
+      /** Write these Governance Vote cards to a new deck and play with it */
+      governanceCards?: CardFixture[];
+      decks?: Pick<GameFixture, "governanceDeck" | "governanceIndex" | "heldCards">;
+      jailFreeCard?: number;
+      rugpullTurns?: number;
+      /** Spaces to rewrite on a custom board the game is played on */
+      spaces?: SpaceData[];
+    } = {}
+  ) {
+    const env = new BlockpolySvm(gameId(`cards-${label}`));
+    const [holder, first, second] = [env.wallet(), env.wallet(), env.wallet()];
+
+    let board = env.board;
+    if (opts.spaces) {
+      const boardId = gameId(`cards-board-${label}`);
+      board = env.createBoard(boardId);
+      for (const space of opts.spaces) {
+        assert.isNull(env.setBoardSpaces(boardId, space.index, [space]));
+      }
+      assert.isNull(env.finalizeBoard(boardId));
+    }
+
+    let deck = env.deck;
+    if (opts.governanceCards) {
+      const deckId = gameId("custom-deck");
+      deck = env.createDeck(deckId);
+      assert.isNull(env.setDeckCards(deckId, DECK.Governance, 0, opts.governanceCards));
+      assert.isNull(env.finalizeDeck(deckId));
+    }
+
+    const game = {
+      players: [holder.publicKey, first.publicKey, second.publicKey],
+      turnPhase: opts.turnPhase ?? TURN_PHASE.DrawCard,
+      pendingDice: [1, 1] as [number, number],
+      cardDeck: deck,
+      boardConfig: board,
+      ...opts.decks,
+    };
+    const gameState = env.game(game);
+    const balance = opts.balance ?? BPOLY(1500);
+    const holdings = opts.holdings ?? [];
+    const holderState = env.player({
+      wallet: holder.publicKey,
+      playerIndex: 0,
+      position: 2,
+      balance,
+      properties: holdings.map((h) => h.space),
+      jailFreeCard: opts.jailFreeCard,
+      rugpullTurns: opts.rugpullTurns,
+    });
+    const holdingStates = holdings.map((h) => env.property({ ...h, owner: holder.publicKey }));
+    const firstState = env.player({ wallet: first.publicKey, playerIndex: 1, position: 0, balance: BPOLY(1500) });
+    const secondState = env.player({
+      wallet: second.publicKey,
+      playerIndex: 2,
+      position: 0,
+      balance: opts.secondBalance ?? BPOLY(1500),
+      debt: opts.secondDebt,
+    });
+    const [vault] = bankVaultPDA(env.gid);
+    const bankAta = env.tokenAccount(vault, BPOLY(1_000_000));
+    const holderAta = env.tokenAccount(holder.publicKey, balance);
+    const firstAta = env.tokenAccount(first.publicKey, BPOLY(1500));
+    const secondAta = env.tokenAccount(second.publicKey, opts.secondBalance ?? BPOLY(1500), opts.secondApprovesBank);
+    return {
+      env, game, deck, holder, first, second, gameState, holderState, firstState, secondState,
+      holdingStates, vault, bankAta, holderAta, firstAta, secondAta,
+    };
+  }
+
+  /** [PlayerState, ATA] per opponent in GameState.players order */
+  function opponents(s: ReturnType<typeof setup>): PublicKey[] {
+    return [s.firstState, s.firstAta, s.secondState, s.secondAta];
+  }
+
+  /** Leaves `cardId` of `deckType` (0 = Alpha Call, 1 = Governance Vote) drawn, as draw_card would */
+  function drawCard(s: ReturnType<typeof setup>, cardId: number, deckType: number) {
+    s.env.game({ ...s.game, drawnCard: [deckType, cardId] });
+  }
+
+  function resolveCard(
+    s: ReturnType<typeof setup>,
+    cardId: number | null,
+    remaining = opponents(s),
+    deckType = 1
+  ) {
+    if (cardId !== null) drawCard(s, cardId, deckType);
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: s.holder.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.env.board, isSigner: false, isWritable: false },
+        { pubkey: s.deck, isSigner: false, isWritable: false },
+        { pubkey: s.holderState, isSigner: false, isWritable: true },
+        { pubkey: s.vault, isSigner: false, isWritable: false },
+        { pubkey: s.bankAta, isSigner: false, isWritable: true },
+        { pubkey: s.holderAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        ...remaining.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+      ],
+      data: Buffer.concat([discriminator("global", "resolve_card"), s.env.gid]),
+    });
+    return s.env.send(ix, [s.holder]);
+  }
+
+  function settleDebt(
+    s: ReturnType<typeof setup>,
+    debtor: Keypair,
+    debtorState: PublicKey,
+    debtorAta: PublicKey,
+    creditorAta: PublicKey
+  ) {
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: debtor.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: debtorState, isSigner: false, isWritable: true },
+        { pubkey: debtorAta, isSigner: false, isWritable: true },
+        { pubkey: creditorAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([discriminator("global", "settle_debt"), s.env.gid]),
+    });
+    return s.env.send(ix, [debtor]);
+  }
+
+  function resolveLanding(s: ReturnType<typeof setup>, space: number | null) {
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: s.holder.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.env.board, isSigner: false, isWritable: false },
+        { pubkey: s.holderState, isSigner: false, isWritable: true },
+        // property_state: None off ownable spaces
+        { pubkey: space === null ? PROGRAM_ID : propertyStatePDA(s.env.gid, space)[0], isSigner: false, isWritable: false },
+        { pubkey: s.vault, isSigner: false, isWritable: false },
+        { pubkey: s.bankAta, isSigner: false, isWritable: true },
+        { pubkey: s.holderAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([discriminator("global", "resolve_landing"), s.env.gid]),
+    });
+    return s.env.send(ix, [s.holder]);
+  }
+
+  function useJailFreeCard(s: ReturnType<typeof setup>) {
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: s.holder.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.holderState, isSigner: false, isWritable: true },
+        { pubkey: s.vault, isSigner: false, isWritable: false },
+        { pubkey: s.bankAta, isSigner: false, isWritable: true },
+        { pubkey: s.holderAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([discriminator("global", "rugpull_use_jail_free_card"), s.env.gid]),
+    });
+    return s.env.send(ix, [s.holder]);
+  }
+
+  /** The holder offers the first opponent `offeredBpoly` for nothing in return */
+  function proposeTrade(s: ReturnType<typeof setup>, offeredBpoly: bigint) {
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: s.holder.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: false },
+        { pubkey: s.holderState, isSigner: false, isWritable: false },
+        { pubkey: tradeOfferPDA(s.env.gid, s.holder.publicKey)[0], isSigner: false, isWritable: true },
+        { pubkey: s.holderAta, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([
+        discriminator("global", "propose_trade"),
+        s.env.gid,
+        s.first.publicKey.toBuffer(),
+        new BorshWriter()
+          .vec([], () => {})                // offered_properties
+          .u64(offeredBpoly)                // offered_bpoly
+          .vec([], () => {})                // requested_properties
+          .u64(0n)                          // requested_bpoly
+          .bool(false)                      // offered_jail_free
+          .bool(false)                      // requested_jail_free
+          .build(),
+      ]),
+    });
+    return s.env.send(ix, [s.holder]);
+  }
+
+  /** The first opponent accepts the holder's trade offer; `properties` are the traded PropertyStates */
+  function acceptTrade(s: ReturnType<typeof setup>, trade: PublicKey, properties: PublicKey[] = []) {
+    const ix = new TransactionInstruction({
+      programId: PROGRAM_ID,
+      keys: [
+        { pubkey: s.first.publicKey, isSigner: true, isWritable: true },
+        { pubkey: s.gameState, isSigner: false, isWritable: true },
+        { pubkey: s.firstState, isSigner: false, isWritable: true },
+        { pubkey: s.holderState, isSigner: false, isWritable: true },
+        { pubkey: trade, isSigner: false, isWritable: true },
+        { pubkey: s.vault, isSigner: false, isWritable: false },
+        { pubkey: s.bankAta, isSigner: false, isWritable: true },
+        { pubkey: s.firstAta, isSigner: false, isWritable: true },
+        { pubkey: s.holderAta, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        ...properties.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+      ],
+      data: Buffer.concat([discriminator("global", "accept_trade"), s.env.gid]),
+    });
+    return s.env.send(ix, [s.first]);
+  }
+
+  /** Held jail-free card type, 0 = none (PlayerState has_jail_free_card and jail_free_card_type) */
+  function jailFreeCardOf(s: ReturnType<typeof setup>, playerState: PublicKey): number {
+    const data = Buffer.from(s.env.svm.getAccount(playerState)!.data);
+    return data[79] ? data[80] : 0;
+  }
+
+  function decksOf(s: ReturnType<typeof setup>) {
+    return readGameDecks(Buffer.from(s.env.svm.getAccount(s.gameState)!.data));
+  }
+
+  function debtOf(s: ReturnType<typeof setup>, playerState: PublicKey) {
+    return readDebt(Buffer.from(s.env.svm.getAccount(playerState)!.data));
+  }
+
+  /** PlayerState.properties_owned, right after the nine 1-byte fields */
+  function propertiesOwned(s: ReturnType<typeof setup>, playerState: PublicKey): number[] {
+    const data = Buffer.from(s.env.svm.getAccount(playerState)!.data);
+    return [...data.subarray(85, 85 + data.readUInt32LE(81))];
+  }
+
+  function isClosed(s: ReturnType<typeof setup>, address: PublicKey): boolean {
+    const account = s.env.svm.getAccount(address);
+    return account === null || account.lamports === 0;
+  }
+
+  it("DAO Birthday Vote: each opponent pays the holder 50 without signing", () => {
+    const s = setup("birthday");
+    const logs = resolveCard(s, GOVERNANCE.DaoBirthday);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(gameBytes(s.env, s.gameState).currentPlayer, 1);
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1600));
+    assert.equal(s.env.tokenBalance(s.firstAta), BPOLY(1450));
+    assert.equal(s.env.tokenBalance(s.secondAta), BPOLY(1450));
+    assert.equal(s.env.tokenBalance(s.bankAta), BPOLY(1_000_000), "The bank stays out of it");
+    for (const state of [s.firstState, s.secondState]) {
+      assert.equal(debtOf(s, state).amount, 0n);
+    }
+  });
+
+  it("DAO Airdrop: an opponent short of their share owes the holder, outside their turn", () => {
+    const s = setup("airdrop-short", { secondBalance: BPOLY(5) });
+    const logs = resolveCard(s, GOVERNANCE.DaoAirdrop);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1510));
+    assert.equal(s.env.tokenBalance(s.secondAta), BPOLY(5), "Nothing is taken from a short ATA");
+    assert.equal(debtOf(s, s.firstState).amount, 0n);
+    const debt = debtOf(s, s.secondState);
+    assert.equal(debt.amount, BPOLY(10));
+    assert.isTrue(debt.creditor!.equals(s.holder.publicKey));
+    assert.equal(debt.reason, DEBT_REASON.PlayerCard);
+
+    // Player 2 isn't up, but can still pay the holder
+    s.env.tokenAccount(s.second.publicKey, BPOLY(100));
+    assert.include(
+      settleDebt(s, s.second, s.secondState, s.secondAta, s.bankAta) ?? "",
+      "InvalidCreditorAccount"
+    );
+    const settleLogs = settleDebt(s, s.second, s.secondState, s.secondAta, s.holderAta);
+    assert.isNull(settleLogs, settleLogs ?? "");
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1520));
+    const game = gameBytes(s.env, s.gameState);
+    assert.equal(game.currentPlayer, 1, "Settling off-turn leaves the turn alone");
+    assert.equal(game.phase, TURN_PHASE.RollDice);
+  });
+
+  it("DAO Airdrop: an opponent who revoked the bank's delegation owes their share", () => {
+    const s = setup("airdrop-revoked", { secondApprovesBank: false });
+    const logs = resolveCard(s, GOVERNANCE.DaoAirdrop);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.secondAta), BPOLY(1500));
+    assert.equal(debtOf(s, s.secondState).amount, BPOLY(10));
+  });
+
+  it("DAO Airdrop: a short opponent already owing another player skips their share", () => {
+    const creditor = Keypair.generate().publicKey;
+    const s = setup("airdrop-owing", {
+      secondBalance: BPOLY(5),
+      secondDebt: { amount: BPOLY(20), creditor, reason: DEBT_REASON.Rent },
+    });
+    const logs = resolveCard(s, GOVERNANCE.DaoAirdrop);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1510), "The other opponent still pays");
+    const debt = debtOf(s, s.secondState);
+    assert.equal(debt.amount, BPOLY(20));
+    assert.isTrue(debt.creditor!.equals(creditor));
+  });
+
+  it("Token Unlock Cliff: pays each opponent 150 from the holder's ATA", () => {
+    const s = setup("cliff");
+    const logs = resolveCard(s, GOVERNANCE.TokenUnlockCliff);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1200));
+    assert.equal(s.env.tokenBalance(s.firstAta), BPOLY(1650));
+    assert.equal(s.env.tokenBalance(s.secondAta), BPOLY(1650));
+    assert.equal(debtOf(s, s.holderState).amount, 0n);
+  });
+
+  it("Token Unlock Cliff: the bank covers a share the holder can't, and the holder owes the bank", () => {
+    const s = setup("cliff-short", { balance: BPOLY(200) });
+    const logs = resolveCard(s, GOVERNANCE.TokenUnlockCliff);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(50));
+    assert.equal(s.env.tokenBalance(s.firstAta), BPOLY(1650));
+    assert.equal(s.env.tokenBalance(s.secondAta), BPOLY(1650), "Every opponent is paid in full");
+
+    const debt = debtOf(s, s.holderState);
+    assert.equal(debt.amount, BPOLY(150));
+    assert.isNull(debt.creditor);
+    assert.equal(debt.reason, DEBT_REASON.Card);
+    assert.equal(gameBytes(s.env, s.gameState).phase, TURN_PHASE.RaiseFunds);
+  });
+
+  it("rejects opponent accounts that are missing or out of order", () => {
+    const s = setup("accounts");
+    assert.include(
+      resolveCard(s, GOVERNANCE.DaoBirthday, [s.firstState, s.firstAta]) ?? "",
+      "InvalidOpponentAccounts"
+    );
+    assert.include(
+      resolveCard(s, GOVERNANCE.DaoBirthday, [s.secondState, s.secondAta, s.firstState, s.firstAta]) ?? "",
+      "InvalidOpponentAccounts"
+    );
+    assert.include(
+      resolveCard(s, GOVERNANCE.TokenUnlockCliff, [s.firstState, s.secondAta, s.secondState, s.firstAta]) ?? "",
+      "InvalidOpponentAccounts"
+    );
+  });
+
+  it("Protocol Hack: the cheapest unmortgaged property outside a built group goes back to the bank", () => {
+    const s = setup("hack", {
+      holdings: [{ space: 39 }, { space: 1, isMortgaged: true }, { space: 6, liquidityPools: 1 }, { space: 8 }],
+    });
+    const logs = resolveCard(s, ALPHA_CALL.ProtocolHack, s.holdingStates, 0);
+    assert.isNull(logs, logs ?? "");
+    assert.deepEqual(propertiesOwned(s, s.holderState), [1, 6, 8], "Switchboard is safe while Pyth has an LP");
+    assert.isTrue(isClosed(s, s.holdingStates[0]), "Solana is bank-owned again");
+    assert.isFalse(isClosed(s, s.holdingStates[3]));
+  });
+
+  it("Whale Dump: the most expensive property is sold to the bank at half price", () => {
+    const s = setup("whale", { holdings: [{ space: 1 }, { space: 39 }] });
+    const logs = resolveCard(s, ALPHA_CALL.WhaleDump, s.holdingStates, 0);
+    assert.isNull(logs, logs ?? "");
+    assert.deepEqual(propertiesOwned(s, s.holderState), [1]);
+    assert.isTrue(isClosed(s, s.holdingStates[1]));
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1700));
+  });
+
+  it("Whale Dump: a group with buildings is passed over", () => {
+    const s = setup("whale-built", {
+      holdings: [{ space: 1 }, { space: 37 }, { space: 39, liquidityPools: 2 }],
+    });
+    const logs = resolveCard(s, ALPHA_CALL.WhaleDump, s.holdingStates, 0);
+    assert.isNull(logs, logs ?? "");
+    assert.deepEqual(propertiesOwned(s, s.holderState), [37, 39], "BONK goes; the Dark Blue set keeps its LPs");
+    assert.isTrue(isClosed(s, s.holdingStates[0]));
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1530));
+  });
+
+  it("Whale Dump: a mortgaged property's mortgage comes out of the sale", () => {
+    const s = setup("whale-mortgaged", { holdings: [{ space: 39, isMortgaged: true }] });
+    assert.isNull(resolveCard(s, ALPHA_CALL.WhaleDump, s.holdingStates, 0));
+    assert.deepEqual(propertiesOwned(s, s.holderState), []);
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1500), "200 from the sale less the 200 mortgage");
+  });
+
+  it("Flash Loan: declined while one is open, so the loan isn't taken twice", () => {
+    const fresh = setup("flash");
+    assert.isNull(resolveCard(fresh, ALPHA_CALL.FlashLoan, [], 0));
+    assert.equal(fresh.env.tokenBalance(fresh.holderAta), BPOLY(1700));
+
+    const s = setup("flash-open");
+    s.env.player({
+      wallet: s.holder.publicKey,
+      playerIndex: 0,
+      position: 2,
+      balance: BPOLY(1500),
+      flashLoan: { repay: BPOLY(210), dueTurn: 3 },
+    });
+    const logs = resolveCard(s, ALPHA_CALL.FlashLoan, [], 0);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1500));
+    assert.equal(gameBytes(s.env, s.gameState).currentPlayer, 1);
+  });
+
+  it("Bridge Exploited: an owned bridge is lost; without one the player collects 50", () => {
+    const owner = setup("bridge", { holdings: [{ space: 1 }, { space: 5 }] });
+    assert.isNull(resolveCard(owner, GOVERNANCE.BridgeExploited, owner.holdingStates));
+    assert.deepEqual(propertiesOwned(owner, owner.holderState), [1]);
+    assert.isTrue(isClosed(owner, owner.holdingStates[1]));
+    assert.equal(owner.env.tokenBalance(owner.holderAta), BPOLY(1500));
+
+    const none = setup("no-bridge", { holdings: [{ space: 1 }] });
+    assert.isNull(resolveCard(none, GOVERNANCE.BridgeExploited, none.holdingStates));
+    assert.deepEqual(propertiesOwned(none, none.holderState), [1]);
+    assert.equal(none.env.tokenBalance(none.holderAta), BPOLY(1550));
+  });
+
+  it("rejects holdings out of order, or a minted property without its NFT accounts", () => {
+    const s = setup("holdings", { holdings: [{ space: 1 }, { space: 39 }] });
+    assert.include(
+      resolveCard(s, ALPHA_CALL.WhaleDump, [...s.holdingStates].reverse(), 0) ?? "",
+      "InvalidHoldingsAccounts"
+    );
+
+    const minted = setup("minted", { holdings: [{ space: 39, nftAsset: Keypair.generate().publicKey }] });
+    assert.include(
+      resolveCard(minted, ALPHA_CALL.WhaleDump, minted.holdingStates, 0) ?? "",
+      "InvalidHoldingsAccounts"
+    );
+  });
+
+  it("Advance to Solana leaves the landing to resolve_landing, which offers the buy", () => {
+    const s = setup("solana");
+    const logs = resolveCard(s, ALPHA_CALL.AdvanceToSolana, [], 0);
+    assert.isNull(logs, logs ?? "");
+    let game = gameBytes(s.env, s.gameState);
+    assert.equal(game.phase, TURN_PHASE.LandingEffect);
+    assert.equal(game.currentPlayer, 0, "The card doesn't end the turn");
+
+    const landingLogs = resolveLanding(s, 39);
+    assert.isNull(landingLogs, landingLogs ?? "");
+    game = gameBytes(s.env, s.gameState);
+    assert.equal(game.phase, TURN_PHASE.BuyDecision);
+  });
+
+  it("Go Back 3 Spaces wraps backwards past Genesis Block without salary", () => {
+    const s = setup("back-3");
+    assert.isNull(resolveCard(s, ALPHA_CALL.GoBack3, [], 0));
+    assert.equal(gameBytes(s.env, s.gameState).phase, TURN_PHASE.LandingEffect);
+
+    // Space 2 back to 39, Solana, still bank-owned
+    assert.isNull(resolveLanding(s, 39));
+    assert.equal(gameBytes(s.env, s.gameState).phase, TURN_PHASE.BuyDecision);
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1500));
+  });
+
+  it("Airdrop Season skips owned properties for the nearest bank-owned one", () => {
+    // Ahead of Alpha Call (space 2): dogwifhat (3) is the holder's, Pyth Network (6) an opponent's
+    const s = setup("airdrop", { holdings: [{ space: 3 }] });
+    s.env.property({ space: 6, owner: s.first.publicKey });
+    const checked = [3, 6, 8].map((space) => propertyStatePDA(s.env.gid, space)[0]);
+
+    assert.include(
+      resolveCard(s, ALPHA_CALL.AirdropSeason, checked.slice(1), 0) ?? "",
+      "InvalidPropertyAccount"
+    );
+    assert.include(
+      resolveCard(s, ALPHA_CALL.AirdropSeason, checked.slice(0, 2), 0) ?? "",
+      "InvalidPropertyAccount"
+    );
+
+    const logs = resolveCard(s, ALPHA_CALL.AirdropSeason, checked, 0);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(gameBytes(s.env, s.gameState).phase, TURN_PHASE.LandingEffect);
+    assert.isNull(resolveLanding(s, 8));
+    assert.equal(gameBytes(s.env, s.gameState).phase, TURN_PHASE.BuyDecision);
+  });
+
+  it("a nearest-unowned move does nothing once the bank owns none of the type", () => {
+    const s = setup("unowned-sold-out", {
+      governanceCards: [
+        {
+          name: "Utility Airdrop",
+          effect: { moveToNearestUnowned: { spaceType: SPACE_TYPE_UTILITY, modifier: CARD_MODIFIER.HalfPricePurchase } },
+        },
+      ],
+      holdings: [{ space: 12 }, { space: 28 }],
+    });
+    const logs = resolveCard(s, 0, s.holdingStates);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(gameBytes(s.env, s.gameState).phase, TURN_PHASE.RollDice);
+    assert.equal(s.env.svm.getAccount(s.holderState)!.data[74], 2, "Still on Alpha Call");
+  });
+
+  it("resolves only the drawn card, once, during DrawCard", () => {
+    const s = setup("replay");
+    assert.include(resolveCard(s, null) ?? "", "NoCardDrawn");
+
+    assert.isNull(resolveCard(s, GOVERNANCE.TreasuryRelease, []));
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1700));
+    assert.include(resolveCard(s, null, []) ?? "", "WrongTurnPhase");
+
+    const early = setup("early", { turnPhase: TURN_PHASE.RollDice });
+    assert.include(resolveCard(early, GOVERNANCE.TreasuryRelease, []) ?? "", "WrongTurnPhase");
+  });
+
+  it("MEV Bot Attack charges the roll that landed on the card", () => {
+    const s = setup("mev");
+    assert.isNull(resolveCard(s, ALPHA_CALL.MevBotAttack, [], 0));
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1492), "(1 + 1) × 4 BPOLY");
+  });
+
+  it("Advance to Nearest Bridge on a board without bridges leaves the holder and ends the turn", () => {
+    const wormhole = BOARD.find((b) => b.index === 5)!;
+    const noBridge = { ...wormhole, spaceType: SPACE_TYPE_FREE_PARKING, group: GROUP_NONE, price: 0n, mortgageValue: 0n };
+    const s = setup("no-bridge", {
+      spaces: [5, 15, 25, 35].map((index) => ({ ...noBridge, index, name: `Empty ${index}` })),
+    });
+    const logs = resolveCard(s, ALPHA_CALL.AdvanceToNearestBridge, [], 0);
+    assert.isNull(logs, logs ?? "");
+    const game = gameBytes(s.env, s.gameState);
+    assert.equal(game.phase, TURN_PHASE.RollDice);
+    assert.equal(game.currentPlayer, 1);
+    assert.equal(s.env.svm.getAccount(s.holderState)!.data[74], 2, "Still on the card space");
+  });
+
+  it("Market Crash takes 20% of what the holder's ATA holds", () => {
+    const s = setup("crash");
+    // PlayerState still mirrors 1500
+    s.env.tokenAccount(s.holder.publicKey, BPOLY(1000));
+    assert.isNull(resolveCard(s, ALPHA_CALL.MarketCrash, [], 0));
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(800));
+  });
+
+  it("Infrastructure Levy counts LPs and Full Protocols from the holdings", () => {
+    const s = setup("levy", {
+      holdings: [{ space: 6, liquidityPools: 2 }, { space: 39, liquidityPools: 4, isFullProtocol: true }, { space: 1 }],
+    });
+    const logs = resolveCard(s, GOVERNANCE.InfrastructureLevy, s.holdingStates);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1500 - 2 * 40 - 115));
+  });
+
+  it("NFT Royalty Income pays per complete colour group owned", () => {
+    const s = setup("royalty", { holdings: [{ space: 1 }, { space: 3 }, { space: 6 }] });
+    assert.isNull(resolveCard(s, GOVERNANCE.NftRoyaltyIncome, []));
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1520), "Brown is complete, Light Blue is not");
+  });
+
+  it("resolves a custom deck's card from its definition", () => {
+    const s = setup("custom", {
+      governanceCards: [
+        { name: "Ecosystem Grant", effect: { collectFromBank: BPOLY(123) } },
+        { name: "Audit Fee", effect: { payBank: BPOLY(40) } },
+      ],
+    });
+    const logs = resolveCard(s, 0, []);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1623));
+  });
+
+  it("rejects a deck other than the game's, or a card the deck doesn't have", () => {
+    const s = setup("deck-mismatch", {
+      governanceCards: [{ name: "Audit Fee", effect: { payBank: BPOLY(40) } }],
+    });
+    assert.include(resolveCard({ ...s, deck: s.env.deck }, 0, []) ?? "", "InvalidCardDeck");
+    assert.include(resolveCard(s, 5, []) ?? "", "InvalidCardDeck");
+  });
+
+  // Governance Vote deck with Get Out of Rug Pull Free (3) held out of it
+  const withoutCard3 = [0, 1, 2, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+  it("Get Out of Rug Pull Free leaves its deck while held", () => {
+    const s = setup("gorpf-drawn", { decks: { governanceIndex: 4 } });
+    const logs = resolveCard(s, GOVERNANCE.GetOutOfRugPullFree, []);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(jailFreeCardOf(s, s.holderState), 2);
+
+    const decks = decksOf(s);
+    assert.deepEqual(decks.governance, withoutCard3);
+    assert.deepEqual(decks.heldCards, [[1, 3]]);
+    assert.equal(decks.governanceIndex, 3, "The next card to draw is unchanged");
+  });
+
+  it("a player already holding one leaves the drawn card in the deck", () => {
+    const s = setup("gorpf-second", { jailFreeCard: 1 });
+    assert.isNull(resolveCard(s, GOVERNANCE.GetOutOfRugPullFree, []));
+    assert.equal(jailFreeCardOf(s, s.holderState), 1);
+    assert.lengthOf(decksOf(s).governance, 16);
+    assert.isEmpty(decksOf(s).heldCards);
+  });
+
+  it("a used card goes back under its deck", () => {
+    const s = setup("gorpf-used", {
+      turnPhase: TURN_PHASE.RollDice,
+      jailFreeCard: 2,
+      rugpullTurns: 3,
+      decks: { governanceDeck: withoutCard3, governanceIndex: 5, heldCards: [[1, 3]] },
+    });
+    const logs = useJailFreeCard(s);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(jailFreeCardOf(s, s.holderState), 0);
+
+    const decks = decksOf(s);
+    assert.deepEqual(decks.governance.slice(4, 7), [5, 3, 6], "Drawn after every other card");
+    assert.equal(decks.governanceIndex, 6);
+    assert.isEmpty(decks.heldCards);
+  });
+
+  it("accepting a trade pays the recipient the BPOLY the proposer offered", () => {
+    const s = setup("trade-bpoly");
+    const logs = proposeTrade(s, BPOLY(100));
+    assert.isNull(logs, logs ?? "");
+    const acceptLogs = acceptTrade(s, tradeOfferPDA(s.env.gid, s.holder.publicKey)[0]);
+    assert.isNull(acceptLogs, acceptLogs ?? "");
+    assert.equal(s.env.tokenBalance(s.holderAta), BPOLY(1400));
+    assert.equal(s.env.tokenBalance(s.firstAta), BPOLY(1600));
+  });
+
+  it("accepting a trade hands each traded PropertyState to its new owner", () => {
+    const s = setup("trade-property", { holdings: [{ space: 6 }] });
+    const trade = s.env.tradeOffer({
+      proposer: s.holder.publicKey,
+      recipient: s.first.publicKey,
+      offeredProperties: [6],
+    });
+    assert.include(acceptTrade(s, trade) ?? "", "InvalidPropertyAccount");
+
+    const logs = acceptTrade(s, trade, s.holdingStates);
+    assert.isNull(logs, logs ?? "");
+    // PropertyState layout: disc(8) game(32) space_index(1) owner(32)
+    const owner = new PublicKey(s.env.svm.getAccount(s.holdingStates[0])!.data.subarray(41, 73));
+    assert.isTrue(owner.equals(s.first.publicKey));
+    assert.isEmpty(propertiesOwned(s, s.holderState));
+    assert.deepEqual(propertiesOwned(s, s.firstState), [6]);
+  });
+
+  it("a trade fails for a property the proposer no longer owns", () => {
+    const s = setup("trade-sold", { holdings: [{ space: 6 }] });
+    s.env.property({ space: 6, owner: s.second.publicKey });
+    const trade = s.env.tradeOffer({
+      proposer: s.holder.publicKey,
+      recipient: s.first.publicKey,
+      offeredProperties: [6],
+    });
+    assert.include(acceptTrade(s, trade, s.holdingStates) ?? "", "InvalidTradeOffer");
+  });
+
+  it("a traded card goes back under its deck when the new holder already has one", () => {
+    const s = setup("gorpf-traded", {
+      jailFreeCard: 2,
+      decks: { governanceDeck: withoutCard3, heldCards: [[1, 3]] },
+    });
+    s.env.player({ wallet: s.first.publicKey, playerIndex: 1, position: 0, balance: BPOLY(1500), jailFreeCard: 1 });
+    const trade = s.env.tradeOffer({
+      proposer: s.holder.publicKey,
+      recipient: s.first.publicKey,
+      offeredJailFree: true,
+    });
+    const logs = acceptTrade(s, trade);
+    assert.isNull(logs, logs ?? "");
+    assert.equal(jailFreeCardOf(s, s.holderState), 0);
+    assert.equal(jailFreeCardOf(s, s.firstState), 1, "Keeps their own card");
+
+    const decks = decksOf(s);
+    assert.deepEqual(decks.governance, [3, ...withoutCard3]);
+    assert.equal(decks.governanceIndex, 1);
+    assert.isEmpty(decks.heldCards);
+  });
+});